@@ -0,0 +1,1224 @@
+use std::fmt::Display;
+use std::io::Write;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+
+use crate::db::JiraDatabase;
+use crate::models::{normalize_labels, DBState, Epic, EpicPatch, IssueType, LinkType, Priority, Severity, SortKey, Status, Story, StoryPatch, User};
+use crate::output::{render_list, render_one, CommentRecord, EpicRecord, FilterRecord, LinkRecord, OutputFormat, StoryRecord, UserRecord};
+use crate::query::{Query, QueryError};
+
+/// Exit code for commands that were given invalid arguments, e.g. `delete` without `--yes`.
+/// This is the same exit code `clap` uses for usage errors.
+pub const EXIT_USAGE: u8 = 2;
+
+/// Exit code for commands referring to an epic, story or comment that does not exist.
+pub const EXIT_NOT_FOUND: u8 = 3;
+
+/// Command line arguments of the JIRA-like CLI application.
+///
+/// Without a subcommand, the application starts in interactive mode.
+#[derive(Parser, Debug)]
+#[command(version, about = "A JIRA-like issue tracker for the command line")]
+pub struct Cli {
+
+    /// Location of the database, e.g. `json:./data/db.json` or `sqlite:./data/db.sqlite`.
+    /// Overrides the `database` option of the config file.
+    #[arg(long, global = true)]
+    pub db: Option<String>,
+
+    /// Output format of the list and show subcommands.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Option<Command>
+}
+
+/// Subcommands of the JIRA-like CLI application.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+
+    /// Import an existing JSON database (e.g. ./data/db.json) into the database selected with --db
+    Import {
+        /// Path to the JSON database to import
+        path: String
+    },
+
+    /// List, create, update and delete epics
+    Epic {
+        #[command(subcommand)]
+        command: EpicCommand
+    },
+
+    /// List, create, update, move and delete stories
+    Story {
+        #[command(subcommand)]
+        command: StoryCommand
+    },
+
+    /// List and add users
+    User {
+        #[command(subcommand)]
+        command: UserCommand
+    },
+
+    /// List, add, edit and delete comments on epics and stories
+    Comment {
+        #[command(subcommand)]
+        command: CommentCommand
+    },
+
+    /// List, add and remove links between epics and stories
+    Link {
+        #[command(subcommand)]
+        command: LinkCommand
+    },
+
+    /// List the stories matching a query, e.g. 'status = "IN PROGRESS" AND points > 2 ORDER BY id DESC'
+    Query {
+        /// Conditions on id, epic, name, description, status, type, priority, severity, assignee,
+        /// label, points, sprint, due, created or updated, combined with AND, OR, NOT and
+        /// parentheses, optionally followed by ORDER BY
+        query: String
+    },
+
+    /// List, save and delete filters, i.e. queries saved under a name
+    Filter {
+        #[command(subcommand)]
+        command: FilterCommand
+    },
+
+    /// List the stories matching a saved filter, e.g. `view "my open bugs"`
+    View {
+        /// Name of the filter
+        name: String
+    }
+}
+
+/// Subcommands operating on epics.
+#[derive(Subcommand, Debug)]
+pub enum EpicCommand {
+
+    /// List all epics
+    List {
+        /// Order of the epics: id or priority
+        #[arg(long, default_value = "id")]
+        sort: SortKey,
+
+        /// Only list epics owned by the current user or containing stories assigned to them
+        #[arg(long)]
+        mine: bool,
+
+        /// Only list epics carrying this label
+        #[arg(long)]
+        label: Option<String>
+    },
+
+    /// Show a single epic
+    Show {
+        id: u32
+    },
+
+    /// Create an epic and print its id
+    Create {
+        #[arg(long)]
+        name: String,
+
+        #[arg(long, default_value = "")]
+        description: String,
+
+        /// highest, high, medium, low or lowest
+        #[arg(long, default_value = "medium")]
+        priority: Priority,
+
+        /// Comma separated labels, e.g. backend,api
+        #[arg(long, value_delimiter = ',')]
+        labels: Vec<String>,
+
+        /// Due date, e.g. 2024-12-31
+        #[arg(long)]
+        due: Option<NaiveDate>
+    },
+
+    /// Edit the fields of an epic; omitted options keep their value
+    Edit {
+        id: u32,
+
+        #[arg(long)]
+        name: Option<String>,
+
+        #[arg(long)]
+        description: Option<String>,
+
+        #[arg(long)]
+        priority: Option<Priority>,
+
+        /// Comma separated labels replacing the current ones, or "" to remove all labels
+        #[arg(long, value_delimiter = ',')]
+        labels: Option<Vec<String>>,
+
+        /// Due date, e.g. 2024-12-31
+        #[arg(long, conflicts_with = "no_due")]
+        due: Option<NaiveDate>,
+
+        /// Remove the due date
+        #[arg(long)]
+        no_due: bool
+    },
+
+    /// Set the status of an epic (open, in-progress, resolved, closed or a status of the workflow)
+    Status {
+        id: u32,
+        status: Status
+    },
+
+    /// Set the owner of an epic, or remove it if no user is given
+    Assign {
+        id: u32,
+        user: Option<String>
+    },
+
+    /// Delete an epic and all of its stories
+    Delete {
+        id: u32,
+
+        /// Confirm the deletion
+        #[arg(long)]
+        yes: bool
+    }
+}
+
+/// Subcommands operating on stories.
+#[derive(Subcommand, Debug)]
+pub enum StoryCommand {
+
+    /// List all stories, or only those of one epic
+    List {
+        #[arg(long)]
+        epic: Option<u32>,
+
+        /// Order of the stories: id (grouped by epic) or priority
+        #[arg(long, default_value = "id")]
+        sort: SortKey,
+
+        /// Only list stories assigned to this user
+        #[arg(long, conflicts_with = "mine")]
+        assignee: Option<String>,
+
+        /// Only list stories assigned to the current user
+        #[arg(long)]
+        mine: bool,
+
+        /// Only list stories carrying this label
+        #[arg(long)]
+        label: Option<String>
+    },
+
+    /// Show a single story
+    Show {
+        id: u32
+    },
+
+    /// Create a story in an epic and print its id
+    Create {
+        #[arg(long)]
+        epic: u32,
+
+        #[arg(long)]
+        name: String,
+
+        #[arg(long, default_value = "")]
+        description: String,
+
+        /// highest, high, medium, low or lowest
+        #[arg(long, default_value = "medium")]
+        priority: Priority,
+
+        /// Comma separated labels, e.g. backend,api
+        #[arg(long, value_delimiter = ',')]
+        labels: Vec<String>,
+
+        /// Due date, e.g. 2024-12-31
+        #[arg(long)]
+        due: Option<NaiveDate>,
+
+        /// Estimate in story points
+        #[arg(long)]
+        points: Option<u32>,
+
+        /// story, task, bug or spike
+        #[arg(long = "type", default_value = "story")]
+        issue_type: IssueType,
+
+        /// Steps to reproduce a bug
+        #[arg(long)]
+        steps: Option<String>,
+
+        /// Severity of a bug: blocker, critical, major, minor or trivial
+        #[arg(long)]
+        severity: Option<Severity>,
+
+        /// Version of the product a bug was found in
+        #[arg(long)]
+        affected_version: Option<String>
+    },
+
+    /// Edit the fields of a story; omitted options keep their value
+    Edit {
+        id: u32,
+
+        #[arg(long)]
+        name: Option<String>,
+
+        #[arg(long)]
+        description: Option<String>,
+
+        #[arg(long)]
+        priority: Option<Priority>,
+
+        /// Comma separated labels replacing the current ones, or "" to remove all labels
+        #[arg(long, value_delimiter = ',')]
+        labels: Option<Vec<String>>,
+
+        /// Due date, e.g. 2024-12-31
+        #[arg(long, conflicts_with = "no_due")]
+        due: Option<NaiveDate>,
+
+        /// Remove the due date
+        #[arg(long)]
+        no_due: bool,
+
+        /// Estimate in story points
+        #[arg(long, conflicts_with = "no_points")]
+        points: Option<u32>,
+
+        /// Remove the estimate
+        #[arg(long)]
+        no_points: bool,
+
+        /// story, task, bug or spike
+        #[arg(long = "type")]
+        issue_type: Option<IssueType>,
+
+        /// Steps to reproduce a bug
+        #[arg(long, conflicts_with = "no_steps")]
+        steps: Option<String>,
+
+        /// Remove the steps to reproduce
+        #[arg(long)]
+        no_steps: bool,
+
+        /// Severity of a bug: blocker, critical, major, minor or trivial
+        #[arg(long, conflicts_with = "no_severity")]
+        severity: Option<Severity>,
+
+        /// Remove the severity
+        #[arg(long)]
+        no_severity: bool,
+
+        /// Version of the product a bug was found in
+        #[arg(long, conflicts_with = "no_affected_version")]
+        affected_version: Option<String>,
+
+        /// Remove the affected version
+        #[arg(long)]
+        no_affected_version: bool
+    },
+
+    /// Set the status of a story (open, in-progress, resolved, closed or a status of the workflow)
+    Status {
+        id: u32,
+        status: Status
+    },
+
+    /// Assign a story to a user, or unassign it if no user is given
+    Assign {
+        id: u32,
+        user: Option<String>
+    },
+
+    /// Move a story to another epic
+    Move {
+        id: u32,
+
+        #[arg(long)]
+        to: u32
+    },
+
+    /// Delete a story
+    Delete {
+        id: u32,
+
+        /// Confirm the deletion
+        #[arg(long)]
+        yes: bool
+    }
+}
+
+/// Subcommands operating on users.
+#[derive(Subcommand, Debug)]
+pub enum UserCommand {
+
+    /// List all users
+    List,
+
+    /// Add a user, or change the name of an existing one
+    Add {
+        username: String,
+
+        /// Display name of the user, defaults to the username
+        #[arg(long)]
+        name: Option<String>
+    }
+}
+
+/// Subcommands operating on comments.
+#[derive(Subcommand, Debug)]
+pub enum CommentCommand {
+
+    /// List the comments on an epic or story, the oldest one first
+    List {
+        /// Id of the epic or story
+        item: u32
+    },
+
+    /// Comment on an epic or story and print the id of the comment
+    Add {
+        /// Id of the epic or story
+        item: u32,
+
+        body: String
+    },
+
+    /// Replace the text of a comment
+    Edit {
+        id: u32,
+        body: String
+    },
+
+    /// Delete a comment
+    Delete {
+        id: u32,
+
+        /// Confirm the deletion
+        #[arg(long)]
+        yes: bool
+    }
+}
+
+/// Subcommands operating on links between epics and stories.
+#[derive(Subcommand, Debug)]
+pub enum LinkCommand {
+
+    /// List the links of an epic or story
+    List {
+        /// Id of the epic or story
+        item: u32
+    },
+
+    /// Link an epic or story to another one, e.g. `link add 2 blocks 3`
+    Add {
+        /// Id of the epic or story
+        item: u32,
+
+        /// blocks, is-blocked-by, relates-to, duplicates or is-duplicated-by
+        link_type: LinkType,
+
+        /// Id of the linked epic or story
+        target: u32
+    },
+
+    /// Remove a link between two epics or stories
+    Remove {
+        /// Id of the epic or story
+        item: u32,
+
+        /// blocks, is-blocked-by, relates-to, duplicates or is-duplicated-by
+        link_type: LinkType,
+
+        /// Id of the linked epic or story
+        target: u32
+    }
+}
+
+/// Subcommands operating on saved filters.
+#[derive(Subcommand, Debug)]
+pub enum FilterCommand {
+
+    /// List all saved filters
+    List,
+
+    /// Save a query under a name, replacing the query of a filter with the same name
+    Save {
+        name: String,
+
+        /// The query, see the query subcommand
+        query: String
+    },
+
+    /// Delete a saved filter
+    Delete {
+        name: String,
+
+        /// Confirm the deletion
+        #[arg(long)]
+        yes: bool
+    }
+}
+
+/// An error carrying the exit code the process should end with.
+///
+/// Errors returned by `run_command` that are not a `CommandError` end the process with a
+/// generic failure.
+#[derive(Debug)]
+pub struct CommandError {
+    pub exit_code: u8,
+    pub message: String
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Runs a subcommand against the given database.
+///
+/// # Arguments
+///
+/// * `command` - The subcommand to run.
+/// * `db` - The database selected through the config file or `--db`.
+/// * `format` - The format of the output of list and show subcommands, selected with `--output`.
+/// * `out` - Where the output of the subcommand is written, usually stdout.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success if the subcommand completed, otherwise returns an
+/// `Err` containing an error. Errors with a specific exit code are `CommandError`s.
+pub fn run_command(command: Command, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        Command::Import { path } => {
+            let source = JiraDatabase::open(&path).with_context(|| anyhow!("Failed to open {}", path))?;
+            db.import(&source).with_context(|| anyhow!("Failed to import {}", path))?;
+
+            writeln!(out, "{} {} into {}", "Imported".green(), path, db.database.get_file_path())?;
+        }
+        Command::Epic { command } => run_epic_command(command, db, format, out)?,
+        Command::Story { command } => run_story_command(command, db, format, out)?,
+        Command::User { command } => run_user_command(command, db, format, out)?,
+        Command::Comment { command } => run_comment_command(command, db, format, out)?,
+        Command::Link { command } => run_link_command(command, db, format, out)?,
+        Command::Query { query } => run_query(&query, db, format, out)?,
+        Command::Filter { command } => run_filter_command(command, db, format, out)?,
+        Command::View { name } => {
+            let db_state = db.read_db()?;
+            let filter = db_state.filter(&name).ok_or_else(|| not_found("filter", &name))?;
+
+            run_query(&filter.query, db, format, out)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs an `epic` subcommand, see `run_command`.
+fn run_epic_command(command: EpicCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        EpicCommand::List { sort, mine, label } => {
+            let db_state = db.read_db()?;
+            let current_user = db.config.current_user();
+            let label = label.map(normalize_label).transpose()?;
+
+            let epics = db_state.epics.iter()
+                .filter(|(_, epic)| {
+                    !mine || epic.owner.as_ref() == Some(&current_user) || epic.stories.iter()
+                        .filter_map(|story_id| db_state.stories.get(story_id))
+                        .any(|story| story.assignee.as_ref() == Some(&current_user))
+                })
+                .filter(|(_, epic)| label.as_ref().is_none_or(|label| epic.labels.contains(label)));
+
+            let records: Vec<_> = sort.sort(epics, |epic| epic.priority).into_iter()
+                .map(|(id, epic)| EpicRecord::new(id, epic, &db_state.stories, &db.config.workflow))
+                .collect();
+
+            render_list(&records, format, out)?;
+        }
+        EpicCommand::Show { id } => {
+            let db_state = db.read_db()?;
+
+            render_one(&EpicRecord::new(id, find_epic(&db_state, id)?, &db_state.stories, &db.config.workflow), format, out)?;
+        }
+        EpicCommand::Create { name, description, priority, labels, due } => {
+            let epic_id = db.create_epic(Epic { priority, labels, due_date: due, ..Epic::new(name, description) })?;
+
+            writeln!(out, "{}", epic_id)?;
+        }
+        EpicCommand::Edit { id, name, description, priority, labels, due, no_due } => {
+            find_epic(&db.read_db()?, id)?;
+
+            db.update_epic(id, EpicPatch { name, description, priority, labels, due_date: optional_patch(due, no_due) })?;
+        }
+        EpicCommand::Status { id, status } => {
+            find_epic(&db.read_db()?, id)?;
+            check_status(db, &status)?;
+
+            db.update_epic_status(id, status)?;
+        }
+        EpicCommand::Assign { id, user } => {
+            find_epic(&db.read_db()?, id)?;
+
+            db.set_epic_owner(id, user.as_deref())?;
+        }
+        EpicCommand::Delete { id, yes } => {
+            find_epic(&db.read_db()?, id)?;
+            confirm(yes, "epic", id)?;
+
+            db.delete_epic(id)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a `story` subcommand, see `run_command`.
+fn run_story_command(command: StoryCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        StoryCommand::List { epic, sort, assignee, mine, label } => {
+            let db_state = db.read_db()?;
+            let assignee = if mine { Some(db.config.current_user()) } else { assignee };
+            let label = label.map(normalize_label).transpose()?;
+
+            let epics = match epic {
+                Some(epic_id) => vec![(epic_id, find_epic(&db_state, epic_id)?)],
+                None => SortKey::Id.sort(db_state.epics.iter(), |epic| epic.priority)
+            };
+
+            let mut records: Vec<_> = epics.into_iter()
+                .flat_map(|(epic_id, epic)| epic.stories.iter().map(move |story_id| (epic_id, story_id)))
+                .filter_map(|(epic_id, story_id)| db_state.stories.get(story_id).map(|story| (epic_id, story_id, story)))
+                .filter(|(_, _, story)| assignee.is_none() || story.assignee == assignee)
+                .filter(|(_, _, story)| label.as_ref().is_none_or(|label| story.labels.contains(label)))
+                .map(|(epic_id, story_id, story)| (StoryRecord::new(*story_id, epic_id, story), story.priority))
+                .collect();
+
+            if sort == SortKey::Priority {
+                records.sort_by_key(|(record, priority)| (*priority, record.id));
+            }
+
+            let records: Vec<_> = records.into_iter().map(|(record, _)| record).collect();
+
+            render_list(&records, format, out)?;
+        }
+        StoryCommand::Show { id } => {
+            let db_state = db.read_db()?;
+            let epic_id = find_story(&db_state, id)?;
+
+            render_one(&StoryRecord::new(id, epic_id, &db_state.stories[&id]), format, out)?;
+        }
+        StoryCommand::Create { epic, name, description, priority, labels, due, points, issue_type, steps, severity, affected_version } => {
+            find_epic(&db.read_db()?, epic)?;
+
+            let story = Story {
+                priority, labels, issue_type, severity, affected_version,
+                due_date: due,
+                story_points: points,
+                steps_to_reproduce: steps,
+                ..Story::new(name, description)
+            };
+            let story_id = db.create_story(story, epic)?;
+
+            writeln!(out, "{}", story_id)?;
+        }
+        StoryCommand::Edit { id, name, description, priority, labels, due, no_due, points, no_points, issue_type,
+                             steps, no_steps, severity, no_severity, affected_version, no_affected_version } => {
+            find_story(&db.read_db()?, id)?;
+
+            let patch = StoryPatch {
+                name, description, priority, labels, issue_type,
+                due_date: optional_patch(due, no_due),
+                story_points: optional_patch(points, no_points),
+                steps_to_reproduce: optional_patch(steps, no_steps),
+                severity: optional_patch(severity, no_severity),
+                affected_version: optional_patch(affected_version, no_affected_version)
+            };
+
+            db.update_story(id, patch)?;
+        }
+        StoryCommand::Status { id, status } => {
+            let db_state = db.read_db()?;
+            find_story(&db_state, id)?;
+            check_status(db, &status)?;
+
+            let blockers = db_state.open_blockers(id, &db.config.workflow);
+
+            if db.config.workflow.is_done(&status) && !blockers.is_empty() {
+                let blockers: Vec<String> = blockers.iter().map(u32::to_string).collect();
+
+                eprintln!("{} story {} is still blocked by {}", "Warning:".yellow(), id, blockers.join(", "));
+            }
+
+            db.update_story_status(id, status)?;
+        }
+        StoryCommand::Assign { id, user } => {
+            find_story(&db.read_db()?, id)?;
+
+            db.assign_story(id, user.as_deref())?;
+        }
+        StoryCommand::Move { id, to } => {
+            let db_state = db.read_db()?;
+            let epic_id = find_story(&db_state, id)?;
+            find_epic(&db_state, to)?;
+
+            db.move_story(id, epic_id, to)?;
+        }
+        StoryCommand::Delete { id, yes } => {
+            let epic_id = find_story(&db.read_db()?, id)?;
+            confirm(yes, "story", id)?;
+
+            db.delete_story(epic_id, id)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a `user` subcommand, see `run_command`.
+fn run_user_command(command: UserCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        UserCommand::List => {
+            let mut users: Vec<_> = db.read_db()?.users.into_iter().collect();
+            users.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let records: Vec<_> = users.iter().map(|(username, user)| UserRecord::new(username, user)).collect();
+
+            render_list(&records, format, out)?;
+        }
+        UserCommand::Add { username, name } => {
+            let name = name.unwrap_or_else(|| username.clone());
+
+            db.add_user(&username, User { name })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a `comment` subcommand, see `run_command`.
+fn run_comment_command(command: CommentCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        CommentCommand::List { item } => {
+            let db_state = db.read_db()?;
+            find_item(&db_state, item)?;
+
+            let records: Vec<_> = db_state.comments_on(item).into_iter()
+                .map(|(id, comment)| CommentRecord::new(id, comment))
+                .collect();
+
+            render_list(&records, format, out)?;
+        }
+        CommentCommand::Add { item, body } => {
+            find_item(&db.read_db()?, item)?;
+
+            let comment_id = db.add_comment(item, &body)?;
+
+            writeln!(out, "{}", comment_id)?;
+        }
+        CommentCommand::Edit { id, body } => {
+            find_comment(&db.read_db()?, id)?;
+
+            db.edit_comment(id, &body)?;
+        }
+        CommentCommand::Delete { id, yes } => {
+            find_comment(&db.read_db()?, id)?;
+            confirm(yes, "comment", id)?;
+
+            db.delete_comment(id)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a `link` subcommand, see `run_command`.
+fn run_link_command(command: LinkCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        LinkCommand::List { item } => {
+            let db_state = db.read_db()?;
+            find_item(&db_state, item)?;
+
+            let records: Vec<_> = db_state.links_of(item).into_iter().flatten()
+                .map(|link| LinkRecord::new(item, link, &db_state))
+                .collect();
+
+            render_list(&records, format, out)?;
+        }
+        LinkCommand::Add { item, link_type, target } => {
+            let db_state = db.read_db()?;
+            find_item(&db_state, item)?;
+            find_item(&db_state, target)?;
+
+            db.add_link(item, link_type, target)?;
+        }
+        LinkCommand::Remove { item, link_type, target } => {
+            find_item(&db.read_db()?, item)?;
+
+            db.remove_link(item, link_type, target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the stories matching a query, see `Query`. An invalid query fails with `EXIT_USAGE`.
+fn run_query(query: &str, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    let query = Query::parse(query, &db.config.workflow).map_err(|error: QueryError| CommandError { exit_code: EXIT_USAGE, message: error.to_string() })?;
+    let db_state = db.read_db()?;
+
+    let records: Vec<_> = query.run(&db_state).into_iter()
+        .filter_map(|(story_id, story)| db_state.epic_of(story_id).map(|epic_id| StoryRecord::new(story_id, epic_id, story)))
+        .collect();
+
+    render_list(&records, format, out)
+}
+
+/// Runs a `filter` subcommand, see `run_command`.
+fn run_filter_command(command: FilterCommand, db: &JiraDatabase, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match command {
+        FilterCommand::List => {
+            let db_state = db.read_db()?;
+
+            let records: Vec<_> = db_state.sorted_filters().into_iter()
+                .map(|(name, filter)| FilterRecord::new(name, filter))
+                .collect();
+
+            render_list(&records, format, out)?;
+        }
+        FilterCommand::Save { name, query } => {
+            if let Err(error) = Query::parse(&query, &db.config.workflow) {
+                return Err(CommandError { exit_code: EXIT_USAGE, message: error.to_string() }.into());
+            }
+
+            db.save_filter(&name, &query)?;
+        }
+        FilterCommand::Delete { name, yes } => {
+            if db.read_db()?.filter(&name).is_none() {
+                return Err(not_found("filter", &name));
+            }
+
+            confirm(yes, "filter", &name)?;
+
+            db.delete_filter(&name)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the epic with the given id, or a `CommandError` with `EXIT_NOT_FOUND`.
+fn find_epic(db_state: &DBState, epic_id: u32) -> Result<&Epic> {
+    db_state.epics.get(&epic_id).ok_or_else(|| not_found("epic", epic_id))
+}
+
+/// Returns the id of the epic containing the story with the given id, or a `CommandError`
+/// with `EXIT_NOT_FOUND` if there is no such story.
+fn find_story(db_state: &DBState, story_id: u32) -> Result<u32> {
+    db_state.epic_of(story_id).ok_or_else(|| not_found("story", story_id))
+}
+
+/// Fails with `EXIT_NOT_FOUND` unless there is an epic or story with the given id.
+fn find_item(db_state: &DBState, item_id: u32) -> Result<()> {
+    if db_state.epics.contains_key(&item_id) || db_state.stories.contains_key(&item_id) {
+        return Ok(());
+    }
+
+    Err(not_found("epic or story", item_id))
+}
+
+/// Fails with `EXIT_NOT_FOUND` unless there is a comment with the given id.
+fn find_comment(db_state: &DBState, comment_id: u32) -> Result<()> {
+    if db_state.comments.contains_key(&comment_id) {
+        return Ok(());
+    }
+
+    Err(not_found("comment", comment_id))
+}
+
+/// Normalizes the label given to `--label`, the same way labels of epics and stories are.
+fn normalize_label(label: String) -> Result<String> {
+    normalize_labels(vec![label])?.pop().ok_or_else(|| CommandError { exit_code: EXIT_USAGE, message: "Label cannot be empty".to_owned() }.into())
+}
+
+/// Fails with `EXIT_USAGE` if the status is not a status of the configured workflow.
+fn check_status(db: &JiraDatabase, status: &Status) -> Result<()> {
+    db.config.workflow.check_status(status).map_err(|error| CommandError { exit_code: EXIT_USAGE, message: error.to_string() }.into())
+}
+
+/// Returns the change of an optional field requested with e.g. `--due` or `--no-due`, if any.
+fn optional_patch<T>(value: Option<T>, remove: bool) -> Option<Option<T>> {
+    if remove {
+        return Some(None);
+    }
+
+    value.map(Some)
+}
+
+/// Fails with `EXIT_USAGE` unless a deletion was confirmed with `--yes`.
+fn confirm(yes: bool, kind: &str, id: impl Display) -> Result<()> {
+    if yes {
+        return Ok(());
+    }
+
+    Err(CommandError { exit_code: EXIT_USAGE, message: format!("Refusing to delete {} {} without --yes", kind, id) }.into())
+}
+
+/// Builds the error for an epic, story, comment or filter that does not exist.
+fn not_found(kind: &str, id: impl Display) -> anyhow::Error {
+    CommandError { exit_code: EXIT_NOT_FOUND, message: format!("Could not find {} {}", kind, id) }.into()
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::db::test_utils::MockDB;
+
+    fn run(db: &JiraDatabase, args: &[&str]) -> Result<String> {
+        let cli = Cli::try_parse_from(["jira-cli"].iter().chain(args))?;
+        let mut out = vec![];
+
+        run_command(cli.command.unwrap(), db, cli.output, &mut out)?;
+
+        Ok(String::from_utf8(out)?)
+    }
+
+    fn exit_code(error: &anyhow::Error) -> Option<u8> {
+        error.downcast_ref::<CommandError>().map(|error| error.exit_code)
+    }
+
+    #[test]
+    fn epic_commands_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let epic_id = run(&db, &["epic", "create", "--name", "epic", "--description", "description"]).unwrap();
+        assert_eq!(epic_id, "1\n".to_owned());
+
+        run(&db, &["epic", "status", "1", "in-progress"]).unwrap();
+        run(&db, &["epic", "edit", "1", "--name", "renamed"]).unwrap();
+
+        let epic = db.read_db().unwrap().epics.remove(&1).unwrap();
+        assert_eq!(epic.name, "renamed".to_owned());
+        assert_eq!(epic.description, "description".to_owned());
+        assert_eq!(epic.status, Status::InProgress);
+
+        assert!(run(&db, &["epic", "list"]).unwrap().contains("IN PROGRESS  MEDIUM   renamed"));
+
+        run(&db, &["epic", "delete", "1", "--yes"]).unwrap();
+        assert!(db.read_db().unwrap().epics.is_empty());
+    }
+
+    #[test]
+    fn story_commands_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        let story_id = run(&db, &["story", "create", "--epic", "1", "--name", "story"]).unwrap();
+        assert_eq!(story_id, "3\n".to_owned());
+
+        run(&db, &["story", "status", "3", "resolved"]).unwrap();
+        run(&db, &["story", "move", "3", "--to", "2"]).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.stories.get(&3).unwrap().status, Status::Resolved);
+        assert_eq!(db_state.epics.get(&other_epic_id).unwrap().stories, vec![3]);
+
+        assert_eq!(run(&db, &["story", "list", "--epic", "1"]).unwrap(), "".to_owned());
+        assert!(run(&db, &["story", "list"]).unwrap().contains("RESOLVED     MEDIUM   story"));
+
+        run(&db, &["story", "delete", "3", "--yes"]).unwrap();
+        assert!(db.read_db().unwrap().stories.is_empty());
+        assert!(db.read_db().unwrap().epics.contains_key(&epic_id));
+    }
+
+    #[test]
+    fn commands_should_fail_with_not_found_exit_code() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        for args in [&["epic", "status", "9", "closed"][..], &["story", "create", "--epic", "9", "--name", "story"], &["story", "delete", "9", "--yes"]] {
+            let error = run(&db, args).unwrap_err();
+            assert_eq!(exit_code(&error), Some(EXIT_NOT_FOUND));
+        }
+    }
+
+    #[test]
+    fn list_and_show_should_support_output_formats() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("epic".to_owned(), "a description that is much longer than any column".to_owned())).unwrap();
+        db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let epics: serde_json::Value = serde_json::from_str(&run(&db, &["epic", "list", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(epics[0]["description"], "a description that is much longer than any column");
+        assert_eq!(epics[0]["stories"], serde_json::json!([2]));
+
+        let epic: serde_json::Value = serde_json::from_str(&run(&db, &["--output", "json", "epic", "show", "1"]).unwrap()).unwrap();
+        assert_eq!(epic["id"], 1);
+
+        let stories = run(&db, &["story", "list", "--output", "ndjson"]).unwrap();
+        let story: serde_json::Value = serde_json::from_str(stories.strip_suffix('\n').unwrap()).unwrap();
+        assert_eq!(story["id"], 2);
+        assert_eq!(story["epic_id"], 1);
+        assert_eq!(story["due_date"], serde_json::Value::Null);
+        assert!(story["created_at"].is_string());
+
+        let story = run(&db, &["story", "show", "2", "--output", "tsv"]).unwrap();
+        assert!(story.starts_with("id\tepic_id\tname\tdescription\tstatus\tpriority\tassignee\tlabels\tcreated_at\tupdated_at\tdue_date\tstory_points\tsubtasks\t\
+                                   issue_type\tsteps_to_reproduce\tseverity\taffected_version\n\
+                                   2\t1\tstory\t\tOPEN\tMEDIUM\t\t\t"));
+        assert!(story.ends_with("\t\t\t\tSTORY\t\t\t\n"));
+
+        assert_eq!(exit_code(&run(&db, &["story", "show", "9"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+    }
+
+    #[test]
+    fn list_should_sort_by_priority() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["story", "create", "--epic", epic_id.trim(), "--name", "low", "--priority", "low"]).unwrap();
+        run(&db, &["story", "create", "--epic", epic_id.trim(), "--name", "highest", "--priority", "highest"]).unwrap();
+        run(&db, &["story", "create", "--epic", epic_id.trim(), "--name", "medium"]).unwrap();
+
+        let names = |output: String| output.lines().map(|line| line.rsplit(' ').next().unwrap().to_owned()).collect::<Vec<_>>();
+
+        assert_eq!(names(run(&db, &["story", "list"]).unwrap()), vec!["low", "highest", "medium"]);
+        assert_eq!(names(run(&db, &["story", "list", "--sort", "priority"]).unwrap()), vec!["highest", "medium", "low"]);
+    }
+
+    #[test]
+    fn assign_commands_should_work() {
+        let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+        let db = JiraDatabase { database: Box::new(MockDB::new()), config };
+        let epic_id = run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["epic", "create", "--name", "other"]).unwrap();
+        run(&db, &["story", "create", "--epic", epic_id.trim(), "--name", "mine"]).unwrap();
+        run(&db, &["story", "create", "--epic", epic_id.trim(), "--name", "theirs"]).unwrap();
+
+        run(&db, &["user", "add", "bob", "--name", "Bob Smith"]).unwrap();
+        run(&db, &["story", "assign", "3", "alice"]).unwrap();
+        run(&db, &["story", "assign", "4", "bob"]).unwrap();
+        run(&db, &["epic", "assign", "2", "bob"]).unwrap();
+
+        let names = |output: String| output.lines().map(|line| line.rsplit(' ').next().unwrap().to_owned()).collect::<Vec<_>>();
+
+        assert_eq!(names(run(&db, &["story", "list", "--mine"]).unwrap()), vec!["mine"]);
+        assert_eq!(names(run(&db, &["story", "list", "--assignee", "bob"]).unwrap()), vec!["theirs"]);
+        assert_eq!(names(run(&db, &["epic", "list", "--mine"]).unwrap()), vec!["epic"]);
+        assert_eq!(run(&db, &["user", "list", "--output", "tsv"]).unwrap(), "username\tname\nalice\talice\nbob\tBob Smith\n".to_owned());
+
+        run(&db, &["story", "assign", "3"]).unwrap();
+        assert_eq!(run(&db, &["story", "list", "--mine"]).unwrap(), "".to_owned());
+
+        assert_eq!(exit_code(&run(&db, &["epic", "assign", "9", "bob"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+    }
+
+    #[test]
+    fn labels_should_be_set_and_filtered_on() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        run(&db, &["epic", "create", "--name", "backend", "--labels", "Backend,api"]).unwrap();
+        run(&db, &["epic", "create", "--name", "frontend", "--labels", "ui"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "endpoint", "--labels", "api"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "schema"]).unwrap();
+
+        let names = |output: String| output.lines().map(|line| line.rsplit(' ').next().unwrap().to_owned()).collect::<Vec<_>>();
+
+        assert_eq!(names(run(&db, &["epic", "list", "--label", "#backend"]).unwrap()), vec!["backend"]);
+        assert_eq!(names(run(&db, &["story", "list", "--label", "api"]).unwrap()), vec!["endpoint"]);
+
+        run(&db, &["story", "edit", "3", "--labels", ""]).unwrap();
+        assert_eq!(run(&db, &["story", "list", "--label", "api"]).unwrap(), "".to_owned());
+
+        let epic: serde_json::Value = serde_json::from_str(&run(&db, &["epic", "show", "1", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(epic["labels"], serde_json::json!(["api", "backend"]));
+    }
+
+    #[test]
+    fn due_dates_should_be_set_and_removed() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        run(&db, &["epic", "create", "--name", "epic", "--due", "2024-12-31"]).unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&1).unwrap().due_date, NaiveDate::from_ymd_opt(2024, 12, 31));
+
+        run(&db, &["epic", "edit", "1", "--no-due"]).unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&1).unwrap().due_date, None);
+
+        assert!(run(&db, &["epic", "edit", "1", "--due", "31/12/2024"]).is_err());
+        assert!(run(&db, &["epic", "edit", "1", "--due", "2024-12-31", "--no-due"]).is_err());
+    }
+
+    #[test]
+    fn story_points_should_roll_up_into_epics() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "done", "--points", "3"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "todo", "--points", "5"]).unwrap();
+        run(&db, &["story", "status", "2", "closed"]).unwrap();
+
+        let epic: serde_json::Value = serde_json::from_str(&run(&db, &["epic", "show", "1", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(epic["total_points"], 8);
+        assert_eq!(epic["completed_points"], 3);
+
+        run(&db, &["story", "edit", "3", "--no-points"]).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&3).unwrap().story_points, None);
+    }
+
+    #[test]
+    fn issue_types_should_be_set_and_edited() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "crash", "--type", "bug", "--severity", "critical",
+                   "--steps", "open the app", "--affected-version", "1.0.0"]).unwrap();
+
+        let story: serde_json::Value = serde_json::from_str(&run(&db, &["story", "show", "2", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(story["issue_type"], "BUG");
+        assert_eq!(story["severity"], "CRITICAL");
+        assert_eq!(story["steps_to_reproduce"], "open the app");
+        assert_eq!(story["affected_version"], "1.0.0");
+
+        run(&db, &["story", "edit", "2", "--type", "task", "--no-severity"]).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        let story = db_state.stories.get(&2).unwrap();
+        assert_eq!(story.issue_type, IssueType::Task);
+        assert_eq!(story.severity, None);
+
+        assert!(run(&db, &["story", "create", "--epic", "1", "--name", "epic", "--type", "epic"]).is_err());
+    }
+
+    #[test]
+    fn link_commands_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "blocker"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "blocked"]).unwrap();
+
+        run(&db, &["link", "add", "2", "blocks", "3"]).unwrap();
+        run(&db, &["link", "add", "3", "relates-to", "1"]).unwrap();
+
+        let links: serde_json::Value = serde_json::from_str(&run(&db, &["link", "list", "3", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(links[0]["link_type"], "is blocked by");
+        assert_eq!(links[0]["target_id"], 2);
+        assert_eq!(links[0]["target_name"], "blocker");
+        assert_eq!(links[1]["link_type"], "relates to");
+
+        run(&db, &["story", "status", "3", "resolved"]).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&3).unwrap().status, Status::Resolved);
+
+        run(&db, &["link", "remove", "3", "is-blocked-by", "2"]).unwrap();
+        assert!(db.read_db().unwrap().stories.get(&2).unwrap().links.is_empty());
+
+        assert_eq!(exit_code(&run(&db, &["link", "add", "2", "blocks", "9"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+        assert!(run(&db, &["link", "add", "2", "clones", "3"]).is_err());
+    }
+
+    #[test]
+    fn comment_commands_should_work() {
+        let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+        let db = JiraDatabase { database: Box::new(MockDB::new()), config };
+        run(&db, &["epic", "create", "--name", "epic"]).unwrap();
+        run(&db, &["story", "create", "--epic", "1", "--name", "story"]).unwrap();
+
+        assert_eq!(run(&db, &["comment", "add", "2", "first"]).unwrap(), "1\n".to_owned());
+        assert_eq!(run(&db, &["comment", "add", "2", "second"]).unwrap(), "2\n".to_owned());
+        run(&db, &["comment", "edit", "1", "first, edited"]).unwrap();
+
+        let comments: serde_json::Value = serde_json::from_str(&run(&db, &["comment", "list", "2", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(comments[0]["body"], "first, edited");
+        assert_eq!(comments[0]["author"], "alice");
+        assert!(comments[0]["updated_at"].is_string());
+        assert_eq!(comments[1]["body"], "second");
+
+        assert_eq!(exit_code(&run(&db, &["comment", "delete", "2"]).unwrap_err()), Some(EXIT_USAGE));
+        run(&db, &["comment", "delete", "2", "--yes"]).unwrap();
+        assert_eq!(db.read_db().unwrap().comments.len(), 1);
+
+        assert_eq!(exit_code(&run(&db, &["comment", "add", "9", "comment"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+        assert_eq!(exit_code(&run(&db, &["comment", "edit", "9", "comment"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+    }
+
+    #[test]
+    fn delete_should_require_confirmation() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        let error = run(&db, &["epic", "delete", &epic_id.to_string()]).unwrap_err();
+
+        assert_eq!(exit_code(&error), Some(EXIT_USAGE));
+        assert!(db.read_db().unwrap().epics.contains_key(&epic_id));
+    }
+
+    #[test]
+    fn invalid_status_should_be_a_usage_error() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        assert!(Cli::try_parse_from(["jira-cli", "story", "status", "7", " "]).is_err());
+        let error = run(&db, &["story", "status", &story_id.to_string(), "done"]).unwrap_err();
+        assert_eq!(exit_code(&error), Some(EXIT_USAGE));
+        assert_eq!(error.to_string(), "DONE is not a status of the workflow, use one of OPEN, IN PROGRESS, RESOLVED, CLOSED");
+        assert_eq!(exit_code(&run(&db, &["query", "status = done"]).unwrap_err()), Some(EXIT_USAGE));
+
+        run(&db, &["story", "status", &story_id.to_string(), "resolved"]).unwrap();
+        assert_eq!(db.read_db().unwrap().stories[&story_id].status, Status::Resolved);
+    }
+
+    #[test]
+    fn query_should_list_matching_stories() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        db.create_story(Story { story_points: Some(3), ..Story::new("small".to_owned(), "".to_owned()) }, epic_id).unwrap();
+        db.create_story(Story { story_points: Some(8), ..Story::new("big".to_owned(), "".to_owned()) }, epic_id).unwrap();
+        db.create_story(Story::new("unestimated".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let stories: serde_json::Value = serde_json::from_str(&run(&db, &["query", "points > 2 ORDER BY points DESC", "--output", "json"]).unwrap()).unwrap();
+        let names: Vec<&str> = stories.as_array().unwrap().iter().map(|story| story["name"].as_str().unwrap()).collect();
+
+        assert_eq!(names, vec!["big", "small"]);
+        assert_eq!(stories[0]["epic_id"], epic_id);
+
+        let error = run(&db, &["query", "points > big"]).unwrap_err();
+        assert_eq!(exit_code(&error), Some(EXIT_USAGE));
+        assert!(error.to_string().contains("column 10"));
+    }
+
+    #[test]
+    fn filter_commands_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        db.create_story(Story { issue_type: IssueType::Bug, ..Story::new("crash".to_owned(), "".to_owned()) }, epic_id).unwrap();
+        db.create_story(Story::new("feature".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        run(&db, &["filter", "save", "my open bugs", "type = bug AND status = open"]).unwrap();
+        assert_eq!(exit_code(&run(&db, &["filter", "save", "broken", "type ="]).unwrap_err()), Some(EXIT_USAGE));
+
+        let filters: serde_json::Value = serde_json::from_str(&run(&db, &["filter", "list", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(filters, serde_json::json!([{ "name": "my open bugs", "query": "type = bug AND status = open" }]));
+
+        let stories: serde_json::Value = serde_json::from_str(&run(&db, &["view", "my open bugs", "--output", "json"]).unwrap()).unwrap();
+        assert_eq!(stories.as_array().unwrap().len(), 1);
+        assert_eq!(stories[0]["name"], "crash");
+
+        assert_eq!(exit_code(&run(&db, &["view", "other"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+        assert_eq!(exit_code(&run(&db, &["filter", "delete", "my open bugs"]).unwrap_err()), Some(EXIT_USAGE));
+        assert_eq!(exit_code(&run(&db, &["filter", "delete", "other", "--yes"]).unwrap_err()), Some(EXIT_NOT_FOUND));
+
+        run(&db, &["filter", "delete", " my open bugs ", "--yes"]).unwrap();
+        assert!(db.read_db().unwrap().filters.is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Status;
+
+/// Path to the configuration file of the JIRA-like CLI application.
+pub const CONFIG_FILE_PATH: &str = "./data/config.json";
+
+/// Represents the configuration of the JIRA-like CLI application.
+///
+/// The configuration is read from `CONFIG_FILE_PATH`. Every option has a default, so the file
+/// may be missing entirely or only contain the options that differ from the defaults.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "database": "sqlite:./data/db.sqlite",
+///     "user": "alice",
+///     "workflow": {
+///         "statuses": ["Open", "In Progress", "Code Review", "QA", "Resolved", "Closed"],
+///         "transitions": {
+///             "Open": ["In Progress", "Closed"],
+///             "In Progress": ["Open", "Code Review"],
+///             "Code Review": ["In Progress", "QA"],
+///             "QA": ["Code Review", "Resolved"]
+///         },
+///         "done": ["Resolved", "Closed"]
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Config {
+
+    /// Location of the database, either `json:<path>`, `sqlite:<path>` or a plain JSON file path.
+    pub database: String,
+
+    /// Name of the current user, recorded as the author of changes. Defaults to the login name.
+    pub user: Option<String>,
+
+    /// Statuses of epics, stories and sub-tasks, and the transitions allowed between them.
+    pub workflow: Workflow
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            database: "json:./data/db.json".to_owned(),
+            user: None,
+            workflow: Workflow::default()
+        }
+    }
+}
+
+/// Represents the statuses epics, stories and sub-tasks can be in, and the transitions allowed
+/// between them.
+///
+/// Statuses are written the same way as on the command line, e.g. "In Progress" or
+/// "code-review". New items start as "OPEN", which every workflow must therefore contain.
+/// Items in one of the `done` statuses count as finished, and every workflow needs at least one.
+/// The default workflow contains the four built-in statuses and allows any transition.
+/// Its done statuses are "RESOLVED" and "CLOSED". A configured workflow that does not list any
+/// done statuses defaults to those of the two it contains.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Workflow {
+
+    /// Every status of the workflow, in the order they are offered and shown.
+    pub statuses: Vec<String>,
+
+    /// The statuses each status can move to. A status without an entry can move to any status
+    /// of the workflow.
+    pub transitions: HashMap<String, Vec<String>>,
+
+    /// The statuses in which the work is done, e.g. to count the story points of an epic as
+    /// completed or to stop highlighting overdue items. Left empty when missing from the config
+    /// file, see `default_done`.
+    #[serde(default)]
+    pub done: Vec<String>
+}
+
+impl Default for Workflow {
+    fn default() -> Self {
+        Self {
+            statuses: [Status::Open, Status::InProgress, Status::Resolved, Status::Closed].iter().map(Status::to_string).collect(),
+            transitions: HashMap::new(),
+            done: [Status::Resolved, Status::Closed].iter().map(Status::to_string).collect()
+        }
+    }
+}
+
+impl Workflow {
+
+    /// Returns every status of the workflow, in the order they are offered and shown.
+    ///
+    /// Statuses that do not parse are skipped, although `Config::load` already rejects them.
+    pub fn statuses(&self) -> Vec<Status> {
+        self.statuses.iter().filter_map(|status| status.parse().ok()).collect()
+    }
+
+    /// Returns the statuses an item in the `from` status can move to, in workflow order.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current status of the item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::config::Workflow;
+    /// use crate::models::Status;
+    ///
+    /// let next_statuses = Workflow::default().next_statuses(&Status::Open);
+    /// assert_eq!(next_statuses, vec![Status::InProgress, Status::Resolved, Status::Closed]);
+    /// ```
+    pub fn next_statuses(&self, from: &Status) -> Vec<Status> {
+        let transitions = self.transitions.iter()
+            .find(|(status, _)| status.parse::<Status>().ok().as_ref() == Some(from))
+            .map(|(_, next_statuses)| next_statuses.iter().filter_map(|status| status.parse().ok()).collect::<Vec<Status>>());
+
+        self.statuses().into_iter()
+            .filter(|status| status != from)
+            .filter(|status| transitions.as_ref().is_none_or(|transitions| transitions.contains(status)))
+            .collect()
+    }
+
+    /// Returns `true` if the work on an item in the given status is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::config::Workflow;
+    /// use crate::models::Status;
+    ///
+    /// assert!(Workflow::default().is_done(&Status::Closed));
+    /// assert!(!Workflow::default().is_done(&Status::InProgress));
+    /// ```
+    pub fn is_done(&self, status: &Status) -> bool {
+        self.done.iter().any(|done| done.parse::<Status>().ok().as_ref() == Some(status))
+    }
+
+    /// Parses a status, e.g. "code-review", and checks that it is a status of the workflow.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error listing the statuses of the workflow if `text` is not one
+    /// of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::config::Workflow;
+    /// use crate::models::Status;
+    ///
+    /// assert_eq!(Workflow::default().parse_status("in-progress")?, Status::InProgress);
+    /// assert!(Workflow::default().parse_status("reslved").is_err());
+    /// ```
+    pub fn parse_status(&self, text: &str) -> Result<Status> {
+        let status = text.parse::<Status>()?;
+        self.check_status(&status)?;
+
+        Ok(status)
+    }
+
+    /// Checks that the given status is a status of the workflow.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error listing the statuses of the workflow if `status` is not one
+    /// of them.
+    pub fn check_status(&self, status: &Status) -> Result<()> {
+        let statuses = self.statuses();
+
+        if statuses.contains(status) {
+            return Ok(());
+        }
+
+        let statuses: Vec<String> = statuses.iter().map(Status::to_string).collect();
+
+        Err(anyhow!("{} is not a status of the workflow, use one of {}", status, statuses.join(", ")))
+    }
+
+    /// Checks that an item in the `from` status can move to the `to` status.
+    ///
+    /// Staying in the same status is always allowed.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if `to` is not a status of the workflow, or if the workflow
+    /// does not allow moving from `from` to `to`.
+    pub fn check_transition(&self, from: &Status, to: &Status) -> Result<()> {
+        if from == to {
+            return Ok(());
+        }
+
+        if !self.statuses().contains(to) {
+            return Err(anyhow!(format!("{} is not a status of the workflow!", to).red()));
+        }
+
+        if !self.next_statuses(from).contains(to) {
+            return Err(anyhow!(format!("Cannot move from {} to {}!", from, to).red()));
+        }
+
+        Ok(())
+    }
+
+    /// Defaults the done statuses of a workflow that does not list any to the statuses of the
+    /// workflow among "RESOLVED" and "CLOSED".
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the workflow contains neither of them.
+    fn default_done(&mut self) -> Result<()> {
+        if !self.done.is_empty() {
+            return Ok(());
+        }
+
+        self.done = self.statuses.iter()
+            .filter(|status| matches!(status.parse(), Ok(Status::Resolved | Status::Closed)))
+            .cloned()
+            .collect();
+
+        if self.done.is_empty() {
+            return Err(anyhow!("workflow.done must list the finished statuses"));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the workflow is usable: every status is valid and appears once, "OPEN" is one
+    /// of them, at least one of them is done, and transitions and done statuses only refer to
+    /// valid statuses of the workflow. The transitions of a status may only be listed once, even
+    /// under names that differ in case or spelling, e.g. "In Progress" and "in-progress".
+    fn validate(&self) -> Result<()> {
+        let mut statuses: Vec<Status> = vec![];
+
+        for status in &self.statuses {
+            let status = status.parse::<Status>()?;
+
+            if statuses.contains(&status) {
+                return Err(anyhow!("Status {} appears twice in the workflow", status));
+            }
+
+            statuses.push(status);
+        }
+
+        if !statuses.contains(&Status::Open) {
+            return Err(anyhow!("The workflow must contain the {} status", Status::Open));
+        }
+
+        let mut sources: Vec<Status> = vec![];
+
+        for (from, next_statuses) in &self.transitions {
+            let source = from.parse::<Status>()?;
+
+            if sources.contains(&source) {
+                return Err(anyhow!("Transitions from {} are listed twice in the workflow", source));
+            }
+
+            sources.push(source);
+
+            for status in next_statuses.iter().chain([from]) {
+                if !statuses.contains(&status.parse()?) {
+                    return Err(anyhow!("Transition refers to {}, which is not a status of the workflow", status));
+                }
+            }
+        }
+
+        for status in &self.done {
+            if !statuses.contains(&status.parse()?) {
+                return Err(anyhow!("Done status {} is not a status of the workflow", status));
+            }
+        }
+
+        if self.done.is_empty() {
+            return Err(anyhow!("The workflow must have at least one done status"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Config {
+
+    /// Loads the configuration from the JSON file at `file_path`.
+    ///
+    /// If the file does not exist, the default configuration is returned. A workflow without
+    /// done statuses gets the default ones, see `Workflow::default_done`.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if the file exists but cannot be read or parsed, or if its
+    /// workflow is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::config::{Config, CONFIG_FILE_PATH};
+    ///
+    /// let config = Config::load(CONFIG_FILE_PATH)?;
+    /// ```
+    pub fn load(file_path: &str) -> Result<Self> {
+        if !Path::new(file_path).exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(file_path)?;
+
+        let mut config: Self = serde_json::from_str(&content).with_context(|| format!("Invalid config file {}", file_path))?;
+        config.workflow.default_done().with_context(|| format!("Invalid workflow in config file {}", file_path))?;
+        config.workflow.validate().with_context(|| format!("Invalid workflow in config file {}", file_path))?;
+
+        Ok(config)
+    }
+
+    /// Returns the name of the current user.
+    ///
+    /// This is the configured `user` if there is one, otherwise the login name taken from the
+    /// `USER` or `USERNAME` environment variables, or "unknown" if neither is set.
+    pub fn current_user(&self) -> String {
+        self.user.clone()
+            .or_else(|| env::var("USER").ok())
+            .or_else(|| env::var("USERNAME").ok())
+            .unwrap_or_else(|| "unknown".to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_should_return_default_if_file_is_missing() {
+        assert_eq!(Config::load("INVALID_PATH").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn load_should_fill_in_missing_options() {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+        fs::write(tmpfile.path(), r#"{ "database": "sqlite:./db.sqlite" }"#).unwrap();
+
+        let config = Config::load(tmpfile.path().to_str().unwrap()).unwrap();
+
+        assert_eq!(config.database, "sqlite:./db.sqlite".to_owned());
+        assert_eq!(config.user, None);
+    }
+
+    #[test]
+    fn current_user_should_prefer_configured_user() {
+        let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+
+        assert_eq!(config.current_user(), "alice".to_owned());
+    }
+
+    #[test]
+    fn load_should_validate_workflow() {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+        fs::write(tmpfile.path(), r#"{ "workflow": { "statuses": ["open", "qa"], "transitions": { "open": ["qa"] }, "done": ["qa"] } }"#).unwrap();
+        let config = Config::load(tmpfile.path().to_str().unwrap()).unwrap();
+        assert_eq!(config.workflow.statuses(), vec![Status::Open, Status::Custom("QA".to_owned())]);
+
+        for workflow in [r#"{ "statuses": ["in progress", "done"], "done": ["done"] }"#, r#"{ "statuses": ["open", "OPEN", "done"], "done": ["done"] }"#,
+                         r#"{ "statuses": ["open", "qa"], "transitions": { "open": ["done"] }, "done": ["qa"] }"#,
+                         r#"{ "statuses": ["open", "qa"], "done": ["resolved"] }"#,
+                         r#"{ "statuses": ["open", " ", "done"], "done": ["done"] }"#,
+                         r#"{ "statuses": ["open", "done"], "transitions": { "open": ["done", ""] }, "done": ["done"] }"#,
+                         r#"{ "statuses": ["open", "in progress", "done"], "transitions": { "In Progress": ["done"], "in-progress": ["open"] }, "done": ["done"] }"#] {
+            fs::write(tmpfile.path(), format!(r#"{{ "workflow": {} }}"#, workflow)).unwrap();
+            assert!(Config::load(tmpfile.path().to_str().unwrap()).is_err());
+        }
+    }
+
+    #[test]
+    fn load_should_default_done_statuses_to_those_of_the_workflow() {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+        fs::write(tmpfile.path(), r#"{ "workflow": { "statuses": ["open", "doing", "closed"] } }"#).unwrap();
+        let config = Config::load(tmpfile.path().to_str().unwrap()).unwrap();
+        assert_eq!(config.workflow.done, vec!["closed".to_owned()]);
+        assert!(config.workflow.is_done(&Status::Closed));
+
+        fs::write(tmpfile.path(), r#"{ "workflow": { "statuses": ["open", "in progress", "resolved", "closed"], "done": [] } }"#).unwrap();
+        let config = Config::load(tmpfile.path().to_str().unwrap()).unwrap();
+        assert_eq!(config.workflow, Workflow { statuses: config.workflow.statuses.clone(), done: vec!["resolved".to_owned(), "closed".to_owned()], ..Workflow::default() });
+
+        for workflow in [r#"{ "statuses": ["open", "doing", "done"] }"#, r#"{ "statuses": ["open", "done"], "done": [] }"#] {
+            fs::write(tmpfile.path(), format!(r#"{{ "workflow": {} }}"#, workflow)).unwrap();
+            let error = Config::load(tmpfile.path().to_str().unwrap()).unwrap_err();
+            assert_eq!(error.root_cause().to_string(), "workflow.done must list the finished statuses");
+        }
+    }
+
+    #[test]
+    fn workflow_should_use_configured_done_statuses() {
+        let workflow = Workflow {
+            statuses: vec!["Open".to_owned(), "Doing".to_owned(), "Done".to_owned(), "Won't Fix".to_owned()],
+            done: vec!["done".to_owned(), "won't fix".to_owned()],
+            ..Workflow::default()
+        };
+
+        assert!(workflow.validate().is_ok());
+        assert!(workflow.is_done(&Status::Custom("DONE".to_owned())));
+        assert!(workflow.is_done(&Status::Custom("WON'T FIX".to_owned())));
+        assert!(!workflow.is_done(&Status::Custom("DOING".to_owned())));
+        assert!(!workflow.is_done(&Status::Closed));
+    }
+
+    #[test]
+    fn workflow_should_only_allow_configured_transitions() {
+        let workflow = Workflow {
+            statuses: vec!["Open".to_owned(), "In Progress".to_owned(), "Code Review".to_owned(), "Resolved".to_owned()],
+            transitions: HashMap::from([("in-progress".to_owned(), vec!["code review".to_owned(), "open".to_owned()])]),
+            ..Workflow::default()
+        };
+        let code_review = Status::Custom("CODE REVIEW".to_owned());
+
+        assert_eq!(workflow.next_statuses(&Status::InProgress), vec![Status::Open, code_review.clone()]);
+        assert_eq!(workflow.next_statuses(&Status::Open), vec![Status::InProgress, code_review.clone(), Status::Resolved]);
+
+        assert!(workflow.check_transition(&Status::InProgress, &code_review).is_ok());
+        assert!(workflow.check_transition(&Status::InProgress, &Status::InProgress).is_ok());
+        assert!(workflow.check_transition(&Status::InProgress, &Status::Resolved).is_err());
+        assert!(workflow.check_transition(&Status::Open, &Status::Closed).is_err());
+    }
+
+    #[test]
+    fn load_should_fail_with_invalid_json() {
+        let tmpfile = tempfile::NamedTempFile::new().unwrap();
+        fs::write(tmpfile.path(), r#"{ "database": }"#).unwrap();
+
+        assert!(Config::load(tmpfile.path().to_str().unwrap()).is_err());
+    }
+}
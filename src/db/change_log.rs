@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::models::{ChangeEvent, EntryChange, Revision};
+
+/// Maximum number of events kept in the change log, as the whole log is read and written along
+/// with the rest of the database state.
+pub const CHANGE_LOG_LIMIT: usize = 1000;
+
+/// Fields left out of the change log, as every event already records when it happened.
+const UNLOGGED_FIELDS: [&str; 2] = ["created_at", "updated_at"];
+
+impl ChangeEvent {
+
+    /// Expands a revision into the change log events describing it.
+    ///
+    /// Created and deleted entries become a single `created` or `deleted` event, while changed
+    /// entries become one event per field whose value differs, except for the timestamps in
+    /// `UNLOGGED_FIELDS`.
+    ///
+    /// # Arguments
+    ///
+    /// * `revision` - The revision to describe.
+    /// * `actor` - Who made the change.
+    /// * `timestamp` - When the change was made.
+    pub fn from_revision(revision: &Revision, actor: &str, timestamp: DateTime<Utc>) -> Vec<Self> {
+        let mut events = vec![];
+
+        for change in &revision.changes {
+            let event = |field: &str, old_value: Option<&Value>, new_value: Option<&Value>| ChangeEvent {
+                timestamp,
+                actor: actor.to_owned(),
+                collection: change.collection.clone(),
+                id: change.id.clone(),
+                field: field.to_owned(),
+                old_value: old_value.cloned(),
+                new_value: new_value.cloned()
+            };
+
+            match (&change.before, &change.after) {
+                (None, Some(after)) => events.push(event("created", None, Some(summary(after)))),
+                (Some(before), None) => events.push(event("deleted", Some(summary(before)), None)),
+                (Some(Value::Object(before)), Some(Value::Object(after))) => {
+                    for (field, new_value) in after.iter().filter(|(field, _)| !UNLOGGED_FIELDS.contains(&field.as_str())) {
+                        if before.get(field) != Some(new_value) {
+                            events.push(event(field, before.get(field), Some(new_value)));
+                        }
+                    }
+
+                    for (field, old_value) in before.iter().filter(|(field, _)| !after.contains_key(*field)) {
+                        events.push(event(field, Some(old_value), None));
+                    }
+                }
+                (before, after) => events.push(event("value", before.as_ref(), after.as_ref()))
+            }
+        }
+
+        events
+    }
+}
+
+impl Revision {
+
+    /// Returns the revision undoing this one.
+    pub fn inverted(&self) -> Self {
+        Self {
+            changes: self.changes.iter().map(|change| EntryChange {
+                collection: change.collection.clone(),
+                id: change.id.clone(),
+                before: change.after.clone(),
+                after: change.before.clone()
+            }).collect()
+        }
+    }
+}
+
+/// Returns the value summarizing a created or deleted entry, i.e. its name if it has one.
+fn summary(entry: &Value) -> &Value {
+    entry.get("name").unwrap_or(entry)
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn from_revision_should_describe_every_change() {
+        let revision = Revision {
+            changes: vec![
+                EntryChange { collection: "epics".to_owned(), id: "1".to_owned(),
+                              before: Some(json!({ "name": "epic", "status": "Open", "stories": [], "updated_at": null })),
+                              after: Some(json!({ "name": "epic", "status": "Open", "stories": [2], "updated_at": "2024-01-01T00:00:00Z" })) },
+                EntryChange { collection: "stories".to_owned(), id: "2".to_owned(),
+                              before: None,
+                              after: Some(json!({ "name": "story", "status": "Open" })) },
+                EntryChange { collection: "stories".to_owned(), id: "3".to_owned(),
+                              before: Some(json!({ "name": "old story", "status": "Closed" })),
+                              after: None }
+            ]
+        };
+
+        let timestamp = Utc::now();
+        let events = ChangeEvent::from_revision(&revision, "alice", timestamp);
+
+        let described: Vec<_> = events.iter()
+            .map(|event| (event.id.as_str(), event.field.as_str(), event.old_value.clone(), event.new_value.clone()))
+            .collect();
+
+        assert_eq!(described, vec![
+            ("1", "stories", Some(json!([])), Some(json!([2]))),
+            ("2", "created", None, Some(json!("story"))),
+            ("3", "deleted", Some(json!("old story")), None)
+        ]);
+        assert!(events.iter().all(|event| event.actor == "alice" && event.timestamp == timestamp));
+    }
+
+    #[test]
+    fn inverted_should_swap_before_and_after() {
+        let revision = Revision {
+            changes: vec![EntryChange { collection: "stories".to_owned(), id: "2".to_owned(), before: None, after: Some(json!({})) }]
+        };
+
+        let inverted = revision.inverted();
+
+        assert_eq!(inverted.changes[0].before, Some(json!({})));
+        assert_eq!(inverted.changes[0].after, None);
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+use crate::models::{DBState, EntryChange, History, Revision};
+
+/// Maximum number of revisions kept on the undo stack.
+pub const HISTORY_LIMIT: usize = 50;
+
+/// Fields of `DBState` that are not collections of entries, and are therefore left out of revisions.
+/// Every other field of `DBState` must serialize to a JSON object.
+const UNTRACKED_FIELDS: [&str; 6] = ["schema_version", "last_item_id", "last_comment_id", "last_sprint_id", "history", "change_log"];
+
+impl Revision {
+
+    /// Computes the revision turning the `before` state into the `after` state.
+    ///
+    /// Every entry of every collection of `DBState` that was created, changed or deleted is
+    /// recorded as an `EntryChange`. Ids are never reused, so `last_item_id`, `last_comment_id` and
+    /// `last_sprint_id` are not recorded.
+    pub fn between(before: &DBState, after: &DBState) -> Result<Self> {
+        let before = to_object(before)?;
+        let after = to_object(after)?;
+
+        let empty = Map::new();
+        let mut changes = vec![];
+
+        for (collection, after_entries) in after.iter().filter(|(field, _)| !UNTRACKED_FIELDS.contains(&field.as_str())) {
+            let before_entries = before.get(collection).and_then(Value::as_object).unwrap_or(&empty);
+            let after_entries = after_entries.as_object().ok_or_else(|| anyhow!("{} is not a collection", collection))?;
+
+            let ids: BTreeSet<&String> = before_entries.keys().chain(after_entries.keys()).collect();
+
+            for id in ids {
+                let before_entry = before_entries.get(id);
+                let after_entry = after_entries.get(id);
+
+                if before_entry != after_entry {
+                    changes.push(EntryChange {
+                        collection: collection.clone(),
+                        id: id.clone(),
+                        before: before_entry.cloned(),
+                        after: after_entry.cloned()
+                    });
+                }
+            }
+        }
+
+        Ok(Self { changes })
+    }
+
+    /// Returns `true` if the revision does not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Reverts the revision, restoring every entry it touched to its value before the change.
+    ///
+    /// Only the fields the revision changed are restored, so changes made to the other fields of
+    /// an entry since then are kept.
+    pub fn revert(&self, db_state: &mut DBState) -> Result<()> {
+        restore(db_state, self.changes.iter().map(|change| (change, &change.after, &change.before)))
+    }
+
+    /// Re-applies the revision, restoring every entry it touched to its value after the change.
+    ///
+    /// As with `revert`, only the fields the revision changed are restored.
+    pub fn apply(&self, db_state: &mut DBState) -> Result<()> {
+        restore(db_state, self.changes.iter().map(|change| (change, &change.before, &change.after)))
+    }
+}
+
+impl History {
+
+    /// Pushes a new revision onto the undo stack.
+    ///
+    /// The redo stack is cleared, as its revisions were made on top of a state that no longer
+    /// exists, and the oldest revision is dropped once there are more than `HISTORY_LIMIT`.
+    pub fn record(&mut self, revision: Revision) {
+        self.undo.push(revision);
+        self.redo.clear();
+
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+}
+
+/// Serializes the state into a JSON object.
+fn to_object(db_state: &DBState) -> Result<Map<String, Value>> {
+    match serde_json::to_value(db_state)? {
+        Value::Object(object) => Ok(object),
+        _ => Err(anyhow!("Database state is not a JSON object"))
+    }
+}
+
+/// Moves every entry of `changes` from the first associated value to the second one.
+///
+/// An entry going from one value to another only has the fields that differ between them set,
+/// and fails if it no longer exists. Otherwise, the entry is created, or deleted if the second
+/// value is `None`.
+fn restore<'a>(db_state: &mut DBState, changes: impl Iterator<Item = (&'a EntryChange, &'a Option<Value>, &'a Option<Value>)>) -> Result<()> {
+    let mut object = to_object(db_state)?;
+
+    for (change, from, to) in changes {
+        let entries = object.entry(change.collection.clone()).or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut().ok_or_else(|| anyhow!("{} is not a collection", change.collection))?;
+
+        match (from, to) {
+            (Some(Value::Object(from)), Some(Value::Object(to))) => {
+                let entry = entries.get_mut(&change.id).and_then(Value::as_object_mut)
+                    .ok_or_else(|| anyhow!("Entry {} of {} no longer exists", change.id, change.collection))?;
+
+                for field in from.keys().chain(to.keys()) {
+                    match to.get(field) {
+                        Some(value) if from.get(field) != Some(value) => { entry.insert(field.clone(), value.clone()); }
+                        None => { entry.remove(field); }
+                        _ => ()
+                    }
+                }
+            }
+            (_, Some(value)) => { entries.insert(change.id.clone(), value.clone()); }
+            (_, None) => { entries.remove(&change.id); }
+        }
+    }
+
+    *db_state = serde_json::from_value(Value::Object(object))?;
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Epic, Status, Story};
+
+    #[test]
+    fn between_should_record_created_changed_and_deleted_entries() {
+        let mut before = DBState::default();
+        before.epics.insert(1, Epic::new("epic".to_owned(), "".to_owned()));
+        before.stories.insert(2, Story::new("story".to_owned(), "".to_owned()));
+
+        let mut after = before.clone();
+        after.epics.get_mut(&1).unwrap().status = Status::Closed;
+        after.stories.remove(&2);
+        after.stories.insert(3, Story::new("other story".to_owned(), "".to_owned()));
+
+        let revision = Revision::between(&before, &after).unwrap();
+
+        let changed: Vec<_> = revision.changes.iter()
+            .map(|change| (change.collection.as_str(), change.id.as_str(), change.before.is_some(), change.after.is_some()))
+            .collect();
+
+        assert_eq!(changed, vec![("epics", "1", true, true), ("stories", "2", true, false), ("stories", "3", false, true)]);
+    }
+
+    #[test]
+    fn between_should_be_empty_for_identical_states() {
+        let mut db_state = DBState::default();
+        db_state.epics.insert(1, Epic::new("epic".to_owned(), "".to_owned()));
+
+        assert!(Revision::between(&db_state, &db_state.clone()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn revert_and_apply_should_restore_states() {
+        let mut before = DBState::default();
+        before.epics.insert(1, Epic::new("epic".to_owned(), "".to_owned()));
+
+        let mut after = before.clone();
+        after.last_item_id = 2;
+        after.stories.insert(2, Story::new("story".to_owned(), "".to_owned()));
+        after.epics.get_mut(&1).unwrap().stories.push(2);
+
+        let revision = Revision::between(&before, &after).unwrap();
+
+        let mut db_state = after.clone();
+        revision.revert(&mut db_state).unwrap();
+
+        assert_eq!(db_state.epics, before.epics);
+        assert_eq!(db_state.stories, before.stories);
+        assert_eq!(db_state.last_item_id, 2);
+
+        revision.apply(&mut db_state).unwrap();
+
+        assert_eq!(db_state, after);
+    }
+
+    #[test]
+    fn revert_should_keep_later_changes_to_other_fields() {
+        let mut before = DBState::default();
+        before.epics.insert(1, Epic::new("epic".to_owned(), "".to_owned()));
+
+        let mut after = before.clone();
+        after.epics.get_mut(&1).unwrap().status = Status::Closed;
+
+        let revision = Revision::between(&before, &after).unwrap();
+
+        let mut db_state = after.clone();
+        db_state.epics.get_mut(&1).unwrap().name = "renamed epic".to_owned();
+        revision.revert(&mut db_state).unwrap();
+
+        let epic = db_state.epics.get(&1).unwrap();
+        assert_eq!(epic.status, Status::Open);
+        assert_eq!(epic.name, "renamed epic");
+
+        db_state.epics.remove(&1);
+        assert!(revision.apply(&mut db_state).is_err());
+    }
+
+    #[test]
+    fn record_should_clear_redo_and_limit_undo() {
+        let mut history = History::default();
+        history.redo.push(Revision { changes: vec![] });
+
+        for _ in 0..HISTORY_LIMIT + 1 {
+            history.record(Revision { changes: vec![] });
+        }
+
+        assert_eq!(history.undo.len(), HISTORY_LIMIT);
+        assert!(history.redo.is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Map, Value};
+
+use crate::models::DBState;
+
+/// Version of the persisted database format written by this version of the CLI.
+///
+/// Bump this whenever a change to `DBState` or the models it contains would prevent an older
+/// document from deserializing, and add the matching upgrade function to `MIGRATIONS`. Purely
+/// additive fields that have a `#[serde(default)]` do not need a new version.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// A function upgrading a document by a single schema version.
+type Migration = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// The chain of migrations, where `MIGRATIONS[n]` upgrades a document from version `n` to `n + 1`.
+const MIGRATIONS: [Migration; CURRENT_SCHEMA_VERSION as usize] = [
+    migrate_v0_to_v1
+];
+
+/// Deserializes a database document, upgrading it to `CURRENT_SCHEMA_VERSION` first.
+///
+/// The version of the document is read from its `schema_version` field, documents without one
+/// being version 0. Every migration from that version onwards is then applied in order.
+///
+/// # Arguments
+///
+/// * `document` - The raw JSON document, as read from the database file.
+///
+/// # Errors
+///
+/// This function can return an error if:
+/// * The document is not a JSON object.
+/// * The document was written by a newer version of the CLI.
+/// * A migration fails, or the migrated document still cannot be deserialized into a `DBState`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::migrations::migrate;
+///
+/// let document = serde_json::from_str(r#"{ "last_item_id": 0, "epics": {}, "stories": {} }"#)?;
+/// let db_state = migrate(document)?;
+/// ```
+pub fn migrate(document: Value) -> Result<DBState> {
+    let Value::Object(mut document) = document else {
+        return Err(anyhow!("Database is not a JSON object"));
+    };
+
+    let version = match document.get("schema_version") {
+        None => 0,
+        Some(version) => version.as_u64().and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| anyhow!("Invalid schema_version: {}", version))?
+    };
+
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(anyhow!("Database has schema version {}, but this version of jira-cli only supports up to {}. Please upgrade jira-cli",
+                           version, CURRENT_SCHEMA_VERSION));
+    }
+
+    for (from_version, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        migration(&mut document).with_context(|| format!("Failed to migrate database from schema version {}", from_version))?;
+        document.insert("schema_version".to_owned(), json!(from_version + 1));
+    }
+
+    serde_json::from_value(Value::Object(document)).context("Failed to parse database")
+}
+
+/// Upgrades an unversioned document to version 1.
+///
+/// Version 0 documents predate schema versioning and may have been edited by hand, so any
+/// missing collection or epic story list is filled in, and a missing `last_item_id` is
+/// recomputed from the highest id in use.
+fn migrate_v0_to_v1(document: &mut Map<String, Value>) -> Result<()> {
+    for collection in ["epics", "stories"] {
+        document.entry(collection).or_insert_with(|| json!({}));
+    }
+
+    if let Some(Value::Object(epics)) = document.get_mut("epics") {
+        for epic in epics.values_mut() {
+            if let Value::Object(epic) = epic {
+                epic.entry("stories").or_insert_with(|| json!([]));
+            }
+        }
+    }
+
+    if !document.contains_key("last_item_id") {
+        let last_item_id = ["epics", "stories"].iter()
+            .filter_map(|collection| document[*collection].as_object())
+            .flat_map(|items| items.keys())
+            .filter_map(|id| id.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0);
+
+        document.insert("last_item_id".to_owned(), json!(last_item_id));
+    }
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Status;
+
+    fn parse(document: &str) -> Value {
+        serde_json::from_str(document).unwrap()
+    }
+
+    #[test]
+    fn migrate_should_upgrade_v0_fixture() {
+        let db_state = migrate(parse(include_str!("fixtures/db_v0.json"))).unwrap();
+
+        assert_eq!(db_state.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(db_state.last_item_id, 6);
+        assert_eq!(db_state.epics.len(), 2);
+        assert_eq!(db_state.epics.get(&1).unwrap().stories, vec![2, 3, 4]);
+        assert_eq!(db_state.epics.get(&1).unwrap().status, Status::InProgress);
+        assert_eq!(db_state.stories.len(), 3);
+        assert_eq!(db_state.stories.get(&4).unwrap().status, Status::Resolved);
+    }
+
+    #[test]
+    fn migrate_should_fill_in_incomplete_v0_fixture() {
+        let db_state = migrate(parse(include_str!("fixtures/db_v0_incomplete.json"))).unwrap();
+
+        assert_eq!(db_state.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(db_state.last_item_id, 5);
+        assert_eq!(db_state.epics.get(&5).unwrap().stories, Vec::<u32>::new());
+        assert!(db_state.stories.is_empty());
+    }
+
+    #[test]
+    fn migrate_should_not_change_current_version() {
+        let db_state = DBState::default();
+        let document = serde_json::to_value(&db_state).unwrap();
+
+        assert_eq!(migrate(document).unwrap(), db_state);
+    }
+
+    #[test]
+    fn migrate_should_fail_for_newer_version() {
+        let document = parse(&format!(r#"{{ "schema_version": {}, "last_item_id": 0, "epics": {{}}, "stories": {{}} }}"#, CURRENT_SCHEMA_VERSION + 1));
+
+        assert!(migrate(document).is_err());
+    }
+
+    #[test]
+    fn migrate_should_fail_for_invalid_documents() {
+        assert!(migrate(parse(r#"[]"#)).is_err());
+        assert!(migrate(parse(r#"{ "schema_version": "one" }"#)).is_err());
+        assert!(migrate(parse(r#"{ "epics": { "1": { "name": "epic" } } }"#)).is_err());
+    }
+}
@@ -0,0 +1,3102 @@
+use std::cell::Cell;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Ok, Result};
+use chrono::{DateTime, Utc};
+use fs2::{lock_contended_error, FileExt};
+use serde_json::Value;
+use crate::config::Config;
+use crate::query::Query;
+use crate::models::{normalize_labels, ChangeEvent, Comment, DBState, Epic, EpicPatch, Link, LinkType, Revision, SavedFilter, Sprint, SprintState, Story, StoryPatch, Status, Subtask, User};
+use colored::Colorize;
+
+mod change_log;
+mod history;
+mod migrations;
+mod sqlite;
+use change_log::CHANGE_LOG_LIMIT;
+pub use migrations::CURRENT_SCHEMA_VERSION;
+pub use sqlite::SQLiteDatabase;
+
+/// Trait for interacting with the database in the JIRA-like CLI tool.
+///
+/// The `Database` trait defines methods for reading from and writing to the database.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::Database;
+/// use crate::models::DBState;
+/// use anyhow::Result;
+///
+/// struct MyDatabase;
+///
+/// impl Database for MyDatabase {
+///     fn read_db(&self) -> Result<DBState> {
+///         // Implementation for reading from the database
+///         unimplemented!()
+///     }
+///
+///     fn write_db(&self, db_state: &DBState) -> Result<()> {
+///         // Implementation for writing to the database
+///         unimplemented!()
+///     }
+/// }
+/// ```
+pub trait Database {
+
+    /// Returns the path to the file of the data in the JSON Database
+    fn get_file_path(&self) -> &str;
+
+    /// Reads the database state.
+    ///
+    /// This method reads the state of the database and returns it as a `DBState` instance.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `DBState` instance if the read operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    fn read_db(&self) -> Result<DBState>;
+
+    /// Writes the database state.
+    ///
+    /// This method writes the provided database state to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_state` - The database state to be written.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success or failure of the write operation.
+    fn write_db(&self, db_state: &DBState) -> Result<()>;
+
+    /// Writes the changes made to the database state since it was read.
+    ///
+    /// `JiraDatabase` passes the state it read at the start of each read-modify-write cycle,
+    /// which is still the stored one, along with the modified `db_state`. Databases storing every
+    /// entry separately can therefore write only the entries that changed without reading the
+    /// state again. The default implementation writes the whole new state with `write_db`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success or failure of the write operation.
+    fn write_changes(&self, _current: &DBState, db_state: &DBState) -> Result<()> {
+        self.write_db(db_state)
+    }
+
+    /// Acquires an exclusive lock on the database.
+    ///
+    /// `JiraDatabase` holds this lock for the whole read-modify-write cycle of every change, so
+    /// two instances of the CLI sharing the same database cannot overwrite each other's edits.
+    /// The lock is released once the returned `DBLock` is dropped. Databases that are never
+    /// shared between processes can rely on the default implementation, which locks nothing.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the guard of the lock if it could be acquired, otherwise
+    /// returns an `Err` containing an error.
+    fn lock(&self) -> Result<DBLock> {
+        Ok(DBLock { file: None })
+    }
+}
+
+/// Guard of an exclusive lock on a database, released when dropped.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::Database;
+///
+/// let my_database = // instantiate your database implementation;
+/// {
+///     let _lock = my_database.lock()?;
+///     // No other instance can modify the database here
+/// }
+/// ```
+pub struct DBLock {
+
+    /// The lock file holding the advisory lock, if the database is locked through one.
+    file: Option<File>
+}
+
+impl Drop for DBLock {
+    fn drop(&mut self) {
+        if let Some(file) = &self.file {
+            let _ = file.unlock();
+        }
+    }
+}
+
+/// Represents the JIRA-like database in the CLI tool.
+///
+/// The `JiraDatabase` struct represents the database used in the JIRA-like CLI tool.
+/// It contains a field `database` which is a boxed trait object implementing the `Database` trait,
+/// and the `config` of the application.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::JiraDatabase;
+/// use crate::db::Database;
+///
+/// let my_database: Box<dyn Database> = // instantiate your database implementation;
+/// let jira_database = JiraDatabase::from_database(my_database);
+/// ```
+pub struct JiraDatabase {
+
+    /// The database instance implementing the `Database` trait.
+    pub database: Box<dyn Database>,
+
+    /// The configuration of the application, e.g. who is recorded as the author of changes.
+    pub config: Config
+}
+
+impl JiraDatabase {
+
+    /// Constructs a new `JiraDatabase` instance.
+    ///
+    /// This method creates a new `JiraDatabase` instance with the provided file path.
+    /// It initializes the `database` field with a boxed instance of `JSONFileDatabase`, which
+    /// implements the `Database` trait and operates on a JSON file located at the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the JSON file storing the database state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let file_path = "database.json".to_string();
+    /// let jira_database = JiraDatabase::new(file_path);
+    /// ```
+    pub fn new(file_path: String) -> Self {
+        Self::from_database(Box::new(JSONFileDatabase::new(file_path)))
+    }
+
+    /// Constructs a new `JiraDatabase` instance on top of the given database, with the default
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database instance implementing the `Database` trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let my_database: Box<dyn Database> = // instantiate your database implementation;
+    /// let jira_database = JiraDatabase::from_database(my_database);
+    /// ```
+    pub fn from_database(database: Box<dyn Database>) -> Self {
+        Self {
+            database,
+            config: Config::default()
+        }
+    }
+
+    /// Opens the database at the given location.
+    ///
+    /// The location is either `json:<path>` for a `JSONFileDatabase`, `sqlite:<path>` for an
+    /// `SQLiteDatabase`, or a plain path, which is treated as a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - The location of the database, e.g. `sqlite:./data/db.sqlite`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `JiraDatabase` if the database could be opened,
+    /// otherwise returns an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = JiraDatabase::open("sqlite:./data/db.sqlite")?;
+    /// ```
+    pub fn open(location: &str) -> Result<Self> {
+        if let Some(file_path) = location.strip_prefix("sqlite:") {
+            return Ok(Self::from_database(Box::new(SQLiteDatabase::open(file_path.to_owned())?)));
+        }
+
+        let file_path = location.strip_prefix("json:").unwrap_or(location);
+
+        Ok(Self::new(file_path.to_owned()))
+    }
+
+    /// Reads the database state.
+    ///
+    /// This method delegates the task of reading the database state to the underlying database
+    /// implementation stored in the `database` field. It invokes the `read_db` method on the
+    /// database instance and returns the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `DBState` instance if the read operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// match jira_database.read_db() {
+    ///     Ok(db_state) => {
+    ///         // Handle the retrieved database state
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn read_db(&self) -> Result<DBState> {
+        self.database.read_db()
+    }
+    
+    /// Imports the whole state of another database into this one.
+    ///
+    /// This is a one-shot migration between storage backends, e.g. from an existing `db.json`
+    /// into a new SQLite database. Ids are preserved, so the imported epics and stories keep
+    /// their numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The database to import the state from.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if this database already contains epics or stories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = JiraDatabase::open("sqlite:./data/db.sqlite")?;
+    /// jira_database.import(&JiraDatabase::new("./data/db.json".to_owned()))?;
+    /// ```
+    pub fn import(&self, source: &JiraDatabase) -> Result<()> {
+        let source_db = source.read_db()?;
+
+        self.update_db(|_, parsed_db| {
+            if !parsed_db.epics.is_empty() || !parsed_db.stories.is_empty() {
+                return Err(anyhow!("Can not import into a database which already contains epics or stories!".red()));
+            }
+
+            *parsed_db = source_db;
+
+            Ok(())
+        })
+    }
+
+    /// Creates a new Epic in the database.
+    ///
+    /// This method creates a new Epic in the database by inserting the provided Epic instance
+    /// with an automatically generated ID. It retrieves the current database state, increments
+    /// the last item ID, inserts the new Epic into the database with the generated ID, and then
+    /// writes the updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic` - The Epic instance to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created Epic if the operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Epic;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let new_epic = Epic::new("New Epic Name".to_string(), "New Epic Description".to_string());
+    /// match jira_database.create_epic(new_epic) {
+    ///     Ok(epic_id) => {
+    ///         // Handle the ID of the newly created Epic
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn create_epic(&self, mut epic: Epic) -> Result<u32> {
+        epic.labels = normalize_labels(epic.labels)?;
+
+        self.modify_db(|parsed_db| {
+            let new_id = parsed_db.last_item_id + 1;
+
+            parsed_db.last_item_id = new_id;
+            parsed_db.epics.insert(new_id, epic);
+
+            Ok(new_id)
+        })
+    }
+    
+    /// Creates a new Story in the database and associates it with an Epic.
+    ///
+    /// This method creates a new Story in the database by inserting the provided Story instance
+    /// with an automatically generated ID. It also associates the newly created Story with the
+    /// specified Epic by adding its ID to the list of stories in the Epic. It retrieves the current
+    /// database state, increments the last item ID, inserts the new Story into the database with
+    /// the generated ID, updates the list of stories for the specified Epic, and then writes the
+    /// updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `story` - The Story instance to be created.
+    /// * `epic_id` - The ID of the Epic to associate the Story with.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created Story if the operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::{Story, Status};
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let new_story = Story::new("New Story Name".to_string(), "New Story Description".to_string());
+    /// let epic_id = 1; // ID of the associated Epic
+    /// match jira_database.create_story(new_story, epic_id) {
+    ///     Ok(story_id) => {
+    ///         // Handle the ID of the newly created Story
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn create_story(&self, mut story: Story, epic_id: u32) -> Result<u32> {
+        story.labels = normalize_labels(story.labels)?;
+
+        self.modify_db(|parsed_db| {
+            let new_id = parsed_db.last_item_id + 1;
+
+            parsed_db.last_item_id = new_id;
+            parsed_db.stories.insert(new_id, story);
+
+            parsed_db.epics.get_mut(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?.stories.push(new_id);
+
+            Ok(new_id)
+        })
+    }
+    
+
+    /// Deletes an Epic and its associated Stories from the database.
+    ///
+    /// This method deletes an Epic and its associated Stories from the database by removing
+    /// them from the database state. It retrieves the current database state, removes all
+    /// Stories associated with the specified Epic and their Sub-tasks, removes the Epic itself
+    /// along with the comments on all of them and the links to all of them, takes the Stories out
+    /// of their Sprints, and then writes the updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic_id` - The ID of the Epic to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let epic_id = 1; // ID of the Epic to delete
+    /// match jira_database.delete_epic(epic_id) {
+    ///     Ok(()) => {
+    ///         // Handle successful deletion
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn delete_epic(&self, epic_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let epic = parsed_db.epics.remove(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            for story_id in &epic.stories {
+                if let Some(story) = parsed_db.stories.remove(story_id) {
+                    for subtask_id in &story.subtasks {
+                        parsed_db.subtasks.remove(subtask_id);
+                    }
+                }
+            }
+
+            parsed_db.comments.retain(|_, comment| comment.item_id != epic_id && !epic.stories.contains(&comment.item_id));
+
+            remove_from_sprints(parsed_db, &epic.stories);
+
+            let mut deleted_ids = epic.stories.clone();
+            deleted_ids.push(epic_id);
+            remove_links_to(parsed_db, &deleted_ids);
+
+            Ok(())
+        })
+    }
+    
+    /// Deletes a Story from the database.
+    ///
+    /// This method deletes a Story from the database by removing it from the database state
+    /// and removing its association with the specified Epic. It retrieves the current database
+    /// state, finds the specified Epic, removes the Story from its list of associated Stories,
+    /// removes the Story itself, its Sub-tasks and the comments on it, takes it out of its Sprints,
+    /// and then writes the updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic_id` - The ID of the Epic that the Story belongs to.
+    /// * `story_id` - The ID of the Story to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let epic_id = 1; // ID of the Epic that the Story belongs to
+    /// let story_id = 1; // ID of the Story to delete
+    /// match jira_database.delete_story(epic_id, story_id) {
+    ///     Ok(()) => {
+    ///         // Handle successful deletion
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn delete_story(&self,epic_id: u32, story_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let epic = parsed_db.epics.get_mut(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            let story_index = epic.stories.iter().position(|id| id == &story_id).ok_or_else(|| anyhow!("Story id not found in epic stories vector".red()))?;
+
+            epic.stories.remove(story_index);
+
+            if let Some(story) = parsed_db.stories.remove(&story_id) {
+                for subtask_id in &story.subtasks {
+                    parsed_db.subtasks.remove(subtask_id);
+                }
+            }
+
+            parsed_db.comments.retain(|_, comment| comment.item_id != story_id);
+
+            remove_links_to(parsed_db, &[story_id]);
+            remove_from_sprints(parsed_db, &[story_id]);
+
+            Ok(())
+        })
+    }
+
+    /// Moves a Story from one Epic to another.
+    ///
+    /// The Story is removed from the stories of `from_epic` and appended to the stories of
+    /// `to_epic` in a single write, so the two Epics never disagree about who owns the Story.
+    ///
+    /// # Arguments
+    ///
+    /// * `story_id` - The ID of the Story to move.
+    /// * `from_epic` - The ID of the Epic the Story currently belongs to.
+    /// * `to_epic` - The ID of the Epic to move the Story to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if either Epic does not exist, the Story does not
+    /// belong to `from_epic`, or both Epics are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.move_story(2, 1, 5)?;
+    /// ```
+    pub fn move_story(&self, story_id: u32, from_epic: u32, to_epic: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            if from_epic == to_epic {
+                return Err(anyhow!("Story is already in this epic!".red()));
+            }
+
+            let epic = parsed_db.epics.get_mut(&from_epic).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            let story_index = epic.stories.iter().position(|id| id == &story_id).ok_or_else(|| anyhow!("Story id not found in epic stories vector".red()))?;
+
+            epic.stories.remove(story_index);
+
+            let target_epic = parsed_db.epics.get_mut(&to_epic).ok_or_else(|| anyhow!("Could not find target epic in the database!".red()))?;
+
+            target_epic.stories.push(story_id);
+
+            Ok(())
+        })
+    }
+    
+    /// Updates the status of an Epic in the database.
+    ///
+    /// This method updates the status of an Epic in the database to the specified status.
+    /// It retrieves the current database state, finds the specified Epic, updates its status,
+    /// and then writes the updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic_id` - The ID of the Epic to update.
+    /// * `status` - The new status to assign to the Epic.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the workflow does not allow moving the Epic to
+    /// `status`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Status;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let epic_id = 1; // ID of the Epic to update
+    /// let new_status = Status::InProgress; // New status to assign to the Epic
+    /// match jira_database.update_epic_status(epic_id, new_status) {
+    ///     Ok(()) => {
+    ///         // Handle successful status update
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn update_epic_status(&self, epic_id: u32, status: Status) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let epic = parsed_db.epics.get_mut(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            self.config.workflow.check_transition(&epic.status, &status)?;
+            epic.status = status;
+
+            Ok(())
+        })
+    }
+    
+    /// Updates the status of a Story in the database.
+    ///
+    /// This method updates the status of a Story in the database to the specified status.
+    /// It retrieves the current database state, finds the specified Story, updates its status,
+    /// and then writes the updated state back to the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `story_id` - The ID of the Story to update.
+    /// * `status` - The new status to assign to the Story.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the workflow does not allow moving the Story to
+    /// `status`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Status;
+    /// use anyhow::Result;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let story_id = 1; // ID of the Story to update
+    /// let new_status = Status::InProgress; // New status to assign to the Story
+    /// match jira_database.update_story_status(story_id, new_status) {
+    ///     Ok(()) => {
+    ///         // Handle successful status update
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    pub fn update_story_status(&self, story_id: u32, status: Status) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let story = parsed_db.stories.get_mut(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!".red()))?;
+
+            self.config.workflow.check_transition(&story.status, &status)?;
+            story.status = status;
+
+            Ok(())
+        })
+    }
+
+    /// Updates the name, description, priority, labels and/or due date of an Epic in the database.
+    ///
+    /// Only the fields set in `patch` are changed, so the Epic keeps its id, status and stories.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic_id` - The ID of the Epic to update.
+    /// * `patch` - The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Epic does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::EpicPatch;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let patch = EpicPatch { name: Some("Fixed Name".to_string()), ..EpicPatch::default() };
+    /// jira_database.update_epic(1, patch)?;
+    /// ```
+    pub fn update_epic(&self, epic_id: u32, patch: EpicPatch) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let epic = parsed_db.epics.get_mut(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            if let Some(name) = patch.name {
+                epic.name = name;
+            }
+
+            if let Some(description) = patch.description {
+                epic.description = description;
+            }
+
+            if let Some(priority) = patch.priority {
+                epic.priority = priority;
+            }
+
+            if let Some(labels) = patch.labels {
+                epic.labels = normalize_labels(labels)?;
+            }
+
+            if let Some(due_date) = patch.due_date {
+                epic.due_date = due_date;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Updates the name, description, priority, labels, due date and/or estimate of a Story in the
+    /// database.
+    ///
+    /// Only the fields set in `patch` are changed, so the Story keeps its id and status.
+    ///
+    /// # Arguments
+    ///
+    /// * `story_id` - The ID of the Story to update.
+    /// * `patch` - The fields to change.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Story does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::StoryPatch;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let patch = StoryPatch { description: Some("More details".to_string()), ..StoryPatch::default() };
+    /// jira_database.update_story(2, patch)?;
+    /// ```
+    pub fn update_story(&self, story_id: u32, patch: StoryPatch) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let story = parsed_db.stories.get_mut(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!".red()))?;
+
+            if let Some(name) = patch.name {
+                story.name = name;
+            }
+
+            if let Some(description) = patch.description {
+                story.description = description;
+            }
+
+            if let Some(priority) = patch.priority {
+                story.priority = priority;
+            }
+
+            if let Some(labels) = patch.labels {
+                story.labels = normalize_labels(labels)?;
+            }
+
+            if let Some(due_date) = patch.due_date {
+                story.due_date = due_date;
+            }
+
+            if let Some(story_points) = patch.story_points {
+                story.story_points = story_points;
+            }
+
+            if let Some(issue_type) = patch.issue_type {
+                story.issue_type = issue_type;
+            }
+
+            if let Some(steps_to_reproduce) = patch.steps_to_reproduce {
+                story.steps_to_reproduce = steps_to_reproduce;
+            }
+
+            if let Some(severity) = patch.severity {
+                story.severity = severity;
+            }
+
+            if let Some(affected_version) = patch.affected_version {
+                story.affected_version = affected_version;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Creates a new Sub-task in the database and associates it with a Story.
+    ///
+    /// Sub-tasks get their IDs from the same sequence as epics and stories.
+    ///
+    /// # Arguments
+    ///
+    /// * `subtask` - The Sub-task instance to be created.
+    /// * `story_id` - The ID of the Story to associate the Sub-task with.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created Sub-task if the operation is
+    /// successful, otherwise returns an `Err` containing an error, e.g. if the Story does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Subtask;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let subtask_id = jira_database.create_subtask(Subtask::new("Write the migration".to_string()), 2)?;
+    /// ```
+    pub fn create_subtask(&self, subtask: Subtask, story_id: u32) -> Result<u32> {
+        self.modify_db(|parsed_db| {
+            let story = parsed_db.stories.get_mut(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!".red()))?;
+
+            let new_id = parsed_db.last_item_id + 1;
+
+            story.subtasks.push(new_id);
+            parsed_db.last_item_id = new_id;
+            parsed_db.subtasks.insert(new_id, subtask);
+
+            Ok(new_id)
+        })
+    }
+
+    /// Updates the status of a Sub-task in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `subtask_id` - The ID of the Sub-task to update.
+    /// * `status` - The new status to assign to the Sub-task.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Sub-task does not exist or the workflow does
+    /// not allow moving it to `status`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Status;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.update_subtask_status(3, Status::Closed)?;
+    /// ```
+    pub fn update_subtask_status(&self, subtask_id: u32, status: Status) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let subtask = parsed_db.subtasks.get_mut(&subtask_id).ok_or_else(|| anyhow!("Could not find sub-task in the database!".red()))?;
+
+            self.config.workflow.check_transition(&subtask.status, &status)?;
+            subtask.status = status;
+
+            Ok(())
+        })
+    }
+
+    /// Deletes a Sub-task from the database and removes it from the sub-tasks of its Story.
+    ///
+    /// # Arguments
+    ///
+    /// * `story_id` - The ID of the Story that the Sub-task belongs to.
+    /// * `subtask_id` - The ID of the Sub-task to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Sub-task does not belong to the Story.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.delete_subtask(2, 3)?;
+    /// ```
+    pub fn delete_subtask(&self, story_id: u32, subtask_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let story = parsed_db.stories.get_mut(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!".red()))?;
+
+            let subtask_index = story.subtasks.iter().position(|id| id == &subtask_id).ok_or_else(|| anyhow!("Sub-task id not found in story sub-tasks vector".red()))?;
+
+            story.subtasks.remove(subtask_index);
+
+            parsed_db.subtasks.remove(&subtask_id);
+
+            Ok(())
+        })
+    }
+
+    /// Adds a user to the users registry, or renames them if they already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The username identifying the user.
+    /// * `user` - The details of the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the username is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::User;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.add_user("alice", User { name: "Alice Smith".to_string() })?;
+    /// ```
+    pub fn add_user(&self, username: &str, user: User) -> Result<()> {
+        let username = validate_username(username)?;
+
+        self.modify_db(|parsed_db| {
+            parsed_db.users.insert(username.to_owned(), user);
+
+            Ok(())
+        })
+    }
+
+    /// Assigns a Story to a user, or unassigns it.
+    ///
+    /// Users that are not in the users registry yet are added to it, with their username as name.
+    ///
+    /// # Arguments
+    ///
+    /// * `story_id` - The ID of the Story to assign.
+    /// * `assignee` - The username of the new assignee, or `None` to unassign the Story.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Story does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.assign_story(2, Some("alice"))?;
+    /// ```
+    pub fn assign_story(&self, story_id: u32, assignee: Option<&str>) -> Result<()> {
+        let assignee = assignee.map(validate_username).transpose()?;
+
+        self.modify_db(|parsed_db| {
+            let story = parsed_db.stories.get_mut(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!".red()))?;
+
+            story.assignee = assignee.map(str::to_owned);
+
+            register_user(parsed_db, assignee);
+
+            Ok(())
+        })
+    }
+
+    /// Sets the owner of an Epic, or removes it.
+    ///
+    /// Users that are not in the users registry yet are added to it, with their username as name.
+    ///
+    /// # Arguments
+    ///
+    /// * `epic_id` - The ID of the Epic.
+    /// * `owner` - The username of the new owner, or `None` to remove the owner.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Epic does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.set_epic_owner(1, Some("bob"))?;
+    /// ```
+    pub fn set_epic_owner(&self, epic_id: u32, owner: Option<&str>) -> Result<()> {
+        let owner = owner.map(validate_username).transpose()?;
+
+        self.modify_db(|parsed_db| {
+            let epic = parsed_db.epics.get_mut(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!".red()))?;
+
+            epic.owner = owner.map(str::to_owned);
+
+            register_user(parsed_db, owner);
+
+            Ok(())
+        })
+    }
+
+    /// Adds a comment to an Epic or a Story, on behalf of the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the Epic or Story to comment on.
+    /// * `body` - The text of the comment.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the new comment if the operation is successful,
+    /// otherwise returns an `Err` containing an error, e.g. if the comment is empty or there is
+    /// no such Epic or Story.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let comment_id = jira_database.add_comment(2, "Blocked by the API review")?;
+    /// ```
+    pub fn add_comment(&self, item_id: u32, body: &str) -> Result<u32> {
+        let body = validate_comment(body)?;
+        let author = self.config.current_user();
+
+        self.modify_db(|parsed_db| {
+            if !parsed_db.epics.contains_key(&item_id) && !parsed_db.stories.contains_key(&item_id) {
+                return Err(anyhow!("Could not find epic or story in the database!".red()));
+            }
+
+            let new_id = parsed_db.last_comment_id + 1;
+
+            parsed_db.last_comment_id = new_id;
+            parsed_db.comments.insert(new_id, Comment { item_id, author, created_at: Utc::now(), updated_at: None, body: body.to_owned() });
+
+            Ok(new_id)
+        })
+    }
+
+    /// Replaces the text of a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The ID of the comment to edit.
+    /// * `body` - The new text of the comment.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the comment is empty or does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.edit_comment(1, "No longer blocked")?;
+    /// ```
+    pub fn edit_comment(&self, comment_id: u32, body: &str) -> Result<()> {
+        let body = validate_comment(body)?;
+
+        self.modify_db(|parsed_db| {
+            let comment = parsed_db.comments.get_mut(&comment_id).ok_or_else(|| anyhow!("Could not find comment in the database!".red()))?;
+
+            if comment.body != body {
+                comment.body = body.to_owned();
+                comment.updated_at = Some(Utc::now());
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Deletes a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The ID of the comment to delete.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the comment does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.delete_comment(1)?;
+    /// ```
+    pub fn delete_comment(&self, comment_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            parsed_db.comments.remove(&comment_id).ok_or_else(|| anyhow!("Could not find comment in the database!".red()))?;
+
+            Ok(())
+        })
+    }
+
+    /// Links two items, storing the link on both of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the Epic or Story the link is read from.
+    /// * `link_type` - How `item_id` relates to `target_id`, e.g. `LinkType::Blocks`.
+    /// * `target_id` - The ID of the Epic or Story on the other side of the link.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if either item does not exist, both are the same item
+    /// or they are already linked that way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::LinkType;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.add_link(2, LinkType::Blocks, 3)?;
+    /// ```
+    pub fn add_link(&self, item_id: u32, link_type: LinkType, target_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            if item_id == target_id {
+                return Err(anyhow!("An item cannot be linked to itself!".red()));
+            }
+
+            if parsed_db.links_of(target_id).is_none() {
+                return Err(anyhow!("Could not find epic or story to link to in the database!".red()));
+            }
+
+            let link = Link { link_type, item_id: target_id };
+            let links = parsed_db.links_of_mut(item_id).ok_or_else(|| anyhow!("Could not find epic or story in the database!".red()))?;
+
+            if links.contains(&link) {
+                return Err(anyhow!("Items are already linked!".red()));
+            }
+
+            links.push(link);
+
+            if let Some(target_links) = parsed_db.links_of_mut(target_id) {
+                target_links.push(Link { link_type: link_type.inverse(), item_id });
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Removes a link between two items from both of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the Epic or Story the link is read from.
+    /// * `link_type` - How `item_id` relates to `target_id`.
+    /// * `target_id` - The ID of the Epic or Story on the other side of the link.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if there is no such link.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::LinkType;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.remove_link(2, LinkType::Blocks, 3)?;
+    /// ```
+    pub fn remove_link(&self, item_id: u32, link_type: LinkType, target_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let link = Link { link_type, item_id: target_id };
+            let links = parsed_db.links_of_mut(item_id).ok_or_else(|| anyhow!("Could not find epic or story in the database!".red()))?;
+            let link_index = links.iter().position(|other| other == &link).ok_or_else(|| anyhow!("Could not find link in the database!".red()))?;
+
+            links.remove(link_index);
+
+            let inverse_link = Link { link_type: link_type.inverse(), item_id };
+
+            if let Some(target_links) = parsed_db.links_of_mut(target_id) {
+                target_links.retain(|other| other != &inverse_link);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Creates a new planned Sprint in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprint` - The Sprint instance to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the new Sprint if the operation is successful,
+    /// otherwise returns an `Err` containing an error, e.g. if the Sprint has no name or ends
+    /// before it starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    /// use crate::models::Sprint;
+    /// use chrono::NaiveDate;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let sprint = Sprint::new("Sprint 1".to_string(), "Ship the login page".to_string(),
+    ///                          NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(), NaiveDate::from_ymd_opt(2024, 6, 14).unwrap());
+    /// let sprint_id = jira_database.create_sprint(sprint)?;
+    /// ```
+    pub fn create_sprint(&self, mut sprint: Sprint) -> Result<u32> {
+        sprint.name = sprint.name.trim().to_owned();
+
+        if sprint.name.is_empty() {
+            return Err(anyhow!("Sprint name cannot be empty!".red()));
+        }
+
+        if sprint.end_date < sprint.start_date {
+            return Err(anyhow!("A sprint cannot end before it starts!".red()));
+        }
+
+        self.modify_db(|parsed_db| {
+            let new_id = parsed_db.last_sprint_id + 1;
+
+            parsed_db.last_sprint_id = new_id;
+            parsed_db.sprints.insert(new_id, Sprint { state: SprintState::Planned, stories: vec![], ..sprint });
+
+            Ok(new_id)
+        })
+    }
+
+    /// Adds a Story to a Sprint that is not completed yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprint_id` - The ID of the Sprint.
+    /// * `story_id` - The ID of the Story to add.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Sprint is completed or the Story is already in
+    /// a Sprint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.add_story_to_sprint(1, 2)?;
+    /// ```
+    pub fn add_story_to_sprint(&self, sprint_id: u32, story_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            if !parsed_db.stories.contains_key(&story_id) {
+                return Err(anyhow!("Could not find story in the database!".red()));
+            }
+
+            if let Some(other_sprint_id) = parsed_db.sprint_of(story_id) {
+                return Err(anyhow!(format!("Story is already in sprint {}!", other_sprint_id).red()));
+            }
+
+            let sprint = open_sprint(parsed_db, sprint_id)?;
+
+            sprint.stories.push(story_id);
+
+            Ok(())
+        })
+    }
+
+    /// Removes a Story from a Sprint that is not completed yet, putting it back in the backlog.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprint_id` - The ID of the Sprint.
+    /// * `story_id` - The ID of the Story to remove.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Sprint is completed or does not contain the Story.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.remove_story_from_sprint(1, 2)?;
+    /// ```
+    pub fn remove_story_from_sprint(&self, sprint_id: u32, story_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let sprint = open_sprint(parsed_db, sprint_id)?;
+
+            let story_index = sprint.stories.iter().position(|id| id == &story_id).ok_or_else(|| anyhow!("Story id not found in sprint stories vector".red()))?;
+
+            sprint.stories.remove(story_index);
+
+            Ok(())
+        })
+    }
+
+    /// Starts a planned Sprint.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprint_id` - The ID of the Sprint to start.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the Sprint is not planned or another Sprint is still
+    /// active.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.start_sprint(1)?;
+    /// ```
+    pub fn start_sprint(&self, sprint_id: u32) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            let active_sprint = parsed_db.sprints.iter().find(|(_, sprint)| sprint.state == SprintState::Active);
+
+            if let Some((active_sprint_id, _)) = active_sprint {
+                return Err(anyhow!(format!("Sprint {} is still active!", active_sprint_id).red()));
+            }
+
+            let sprint = parsed_db.sprints.get_mut(&sprint_id).ok_or_else(|| anyhow!("Could not find sprint in the database!".red()))?;
+
+            if sprint.state != SprintState::Planned {
+                return Err(anyhow!("Only planned sprints can be started!".red()));
+            }
+
+            sprint.state = SprintState::Active;
+
+            Ok(())
+        })
+    }
+
+    /// Completes the active Sprint.
+    ///
+    /// The stories of the Sprint that are not resolved or closed roll into the next planned
+    /// Sprint, see `DBState::next_planned_sprint`, or go back to the backlog if there is none.
+    /// Only the completed stories stay in the completed Sprint.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprint_id` - The ID of the Sprint to complete.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the Sprint the incomplete stories rolled into, if
+    /// any, when the operation is successful, otherwise returns an `Err` containing an error,
+    /// e.g. if the Sprint is not active.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// let next_sprint_id = jira_database.complete_sprint(1)?;
+    /// ```
+    pub fn complete_sprint(&self, sprint_id: u32) -> Result<Option<u32>> {
+        self.modify_db(|parsed_db| {
+            let next_sprint_id = parsed_db.next_planned_sprint();
+            let stories = &parsed_db.stories;
+            let sprint = parsed_db.sprints.get_mut(&sprint_id).ok_or_else(|| anyhow!("Could not find sprint in the database!".red()))?;
+
+            if sprint.state != SprintState::Active {
+                return Err(anyhow!("Only active sprints can be completed!".red()));
+            }
+
+            let (done, incomplete): (Vec<u32>, Vec<u32>) = sprint.stories.iter()
+                .partition(|story_id| stories.get(story_id).is_some_and(|story| self.config.workflow.is_done(&story.status)));
+
+            sprint.stories = done;
+            sprint.state = SprintState::Completed;
+
+            if let Some(next_sprint) = next_sprint_id.and_then(|next_sprint_id| parsed_db.sprints.get_mut(&next_sprint_id)) {
+                next_sprint.stories.extend(incomplete);
+            }
+
+            Ok(next_sprint_id)
+        })
+    }
+
+    /// Saves a query under a name, replacing the query of a saved filter with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the filter, e.g. "my open bugs".
+    /// * `query` - The text of the query, see `Query`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if the name is empty or the query is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.save_filter("my open bugs", "type = bug AND assignee = alice AND status != closed")?;
+    /// ```
+    pub fn save_filter(&self, name: &str, query: &str) -> Result<()> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(anyhow!("Filter name cannot be empty!".red()));
+        }
+
+        Query::parse(query, &self.config.workflow).map_err(|error| anyhow!(error.to_string().red()))?;
+
+        self.modify_db(|parsed_db| {
+            parsed_db.filters.insert(name.to_owned(), SavedFilter { query: query.trim().to_owned() });
+
+            Ok(())
+        })
+    }
+
+    /// Deletes a saved filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the filter. Surrounding whitespace is ignored, as in `save_filter`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if there is no filter with that name.
+    pub fn delete_filter(&self, name: &str) -> Result<()> {
+        self.modify_db(|parsed_db| {
+            parsed_db.filters.remove(name.trim()).ok_or_else(|| anyhow!("Could not find filter in the database!".red()))?;
+
+            Ok(())
+        })
+    }
+
+    /// Undoes the last change made to the database.
+    ///
+    /// This method pops the most recent revision off the undo stack, restores every epic and
+    /// story it touched to its previous value, and pushes it onto the redo stack.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if there is nothing to undo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.delete_epic(1)?;
+    /// jira_database.undo()?; // Epic 1 and its stories are back
+    /// ```
+    pub fn undo(&self) -> Result<()> {
+        self.update_db(|_, parsed_db| {
+            let revision = parsed_db.history.undo.pop().ok_or_else(|| anyhow!("Nothing to undo!".red()))?;
+
+            revision.revert(parsed_db)?;
+            self.log_changes(parsed_db, &revision.inverted());
+            parsed_db.history.redo.push(revision);
+
+            Ok(())
+        })
+    }
+
+    /// Redoes the last change that was undone.
+    ///
+    /// This method pops the most recent revision off the redo stack, re-applies it, and pushes
+    /// it back onto the undo stack.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating success if the operation is successful, otherwise returns
+    /// an `Err` containing an error, e.g. if there is nothing to redo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JiraDatabase;
+    ///
+    /// let jira_database = // instantiate your JiraDatabase instance;
+    /// jira_database.undo()?;
+    /// jira_database.redo()?;
+    /// ```
+    pub fn redo(&self) -> Result<()> {
+        self.update_db(|_, parsed_db| {
+            let revision = parsed_db.history.redo.pop().ok_or_else(|| anyhow!("Nothing to redo!".red()))?;
+
+            revision.apply(parsed_db)?;
+            self.log_changes(parsed_db, &revision);
+            parsed_db.history.undo.push(revision);
+
+            Ok(())
+        })
+    }
+
+    /// Applies a change to the database and records it in the undo history and the change log.
+    ///
+    /// Every mutation of `JiraDatabase` goes through this method, so that each one of them can
+    /// be undone and audited, and so that the timestamps of the epics and stories it touches are
+    /// kept up to date. See `update_db` for how the change is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `modify` - A closure applying the changes to the current database state.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the value returned by `modify` if the operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    fn modify_db<T>(&self, modify: impl FnOnce(&mut DBState) -> Result<T>) -> Result<T> {
+        self.update_db(|before, parsed_db| {
+            let result = modify(parsed_db)?;
+
+            touch_changed_items(before, parsed_db, Utc::now());
+
+            let revision = Revision::between(before, parsed_db)?;
+
+            if !revision.is_empty() {
+                self.log_changes(parsed_db, &revision);
+                parsed_db.history.record(revision);
+            }
+
+            Ok(result)
+        })
+    }
+
+    /// Appends the events describing a revision to the change log, on behalf of the current user.
+    ///
+    /// The oldest events are dropped once there are more than `CHANGE_LOG_LIMIT`.
+    fn log_changes(&self, parsed_db: &mut DBState, revision: &Revision) {
+        let events = ChangeEvent::from_revision(revision, &self.config.current_user(), Utc::now());
+
+        parsed_db.change_log.extend(events);
+
+        let overflow = parsed_db.change_log.len().saturating_sub(CHANGE_LOG_LIMIT);
+        parsed_db.change_log.drain(..overflow);
+    }
+
+    /// Runs a read-modify-write cycle against the database while holding its lock.
+    ///
+    /// The database lock is acquired before the state is read and released after the modified
+    /// state has been written back, so no other instance of the CLI can interleave its own
+    /// changes. If `update` fails, nothing is written. The state as it was read is kept, so
+    /// the database only has to write what changed, see `Database::write_changes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - A closure applying the changes to a copy of the current database state,
+    ///   which is passed along with it.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the value returned by `update` if the operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    fn update_db<T>(&self, update: impl FnOnce(&DBState, &mut DBState) -> Result<T>) -> Result<T> {
+        let _lock = self.database.lock()?;
+
+        let current = self.database.read_db()?;
+        let mut parsed_db = current.clone();
+
+        let result = update(&current, &mut parsed_db)?;
+
+        self.database.write_changes(&current, &parsed_db)?;
+
+        Ok(result)
+    }
+}
+
+/// Sets the `created_at` and `updated_at` timestamps of the epics, stories and sub-tasks that
+/// were created or changed between the `before` and `after` states.
+///
+/// Items that already have a creation time, e.g. restored ones, keep it.
+fn touch_changed_items(before: &DBState, after: &mut DBState, now: DateTime<Utc>) {
+    for (id, epic) in after.epics.iter_mut() {
+        if before.epics.get(id) != Some(epic) {
+            epic.created_at.get_or_insert(now);
+            epic.updated_at = Some(now);
+        }
+    }
+
+    for (id, story) in after.stories.iter_mut() {
+        if before.stories.get(id) != Some(story) {
+            story.created_at.get_or_insert(now);
+            story.updated_at = Some(now);
+        }
+    }
+
+    for (id, subtask) in after.subtasks.iter_mut() {
+        if before.subtasks.get(id) != Some(subtask) {
+            subtask.created_at.get_or_insert(now);
+            subtask.updated_at = Some(now);
+        }
+    }
+}
+
+/// Removes the links to any of the given deleted items from the remaining epics and stories.
+fn remove_links_to(parsed_db: &mut DBState, deleted_ids: &[u32]) {
+    let links = parsed_db.epics.values_mut().map(|epic| &mut epic.links)
+        .chain(parsed_db.stories.values_mut().map(|story| &mut story.links));
+
+    for links in links {
+        links.retain(|link| !deleted_ids.contains(&link.item_id));
+    }
+}
+
+/// Removes the given deleted stories from the sprints containing them.
+fn remove_from_sprints(parsed_db: &mut DBState, deleted_ids: &[u32]) {
+    for sprint in parsed_db.sprints.values_mut() {
+        sprint.stories.retain(|story_id| !deleted_ids.contains(story_id));
+    }
+}
+
+/// Returns the Sprint with the given ID for modification, failing if it is completed.
+fn open_sprint(parsed_db: &mut DBState, sprint_id: u32) -> Result<&mut Sprint> {
+    let sprint = parsed_db.sprints.get_mut(&sprint_id).ok_or_else(|| anyhow!("Could not find sprint in the database!".red()))?;
+
+    if sprint.state == SprintState::Completed {
+        return Err(anyhow!("Cannot change a completed sprint!".red()));
+    }
+
+    Ok(sprint)
+}
+
+/// Trims a username, failing if it is empty.
+fn validate_username(username: &str) -> Result<&str> {
+    let username = username.trim();
+
+    if username.is_empty() {
+        return Err(anyhow!("Username cannot be empty!".red()));
+    }
+
+    Ok(username)
+}
+
+/// Trims the text of a comment, failing if it is empty.
+fn validate_comment(body: &str) -> Result<&str> {
+    let body = body.trim();
+
+    if body.is_empty() {
+        return Err(anyhow!("Comment cannot be empty!".red()));
+    }
+
+    Ok(body)
+}
+
+/// Adds a user to the users registry if they are not in it yet, using their username as name.
+fn register_user(parsed_db: &mut DBState, username: Option<&str>) {
+    if let Some(username) = username {
+        parsed_db.users.entry(username.to_owned()).or_insert_with(|| User { name: username.to_owned() });
+    }
+}
+
+/// How long to wait for another instance of the CLI to release the database lock.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait between two attempts at acquiring the database lock.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Acquires an exclusive advisory lock on the file at `lock_path`, creating it if needed.
+///
+/// If another process holds the lock, this function keeps retrying until `timeout` has elapsed.
+///
+/// # Errors
+///
+/// This function can return an error if:
+/// * The lock file cannot be opened.
+/// * The lock is still held by another process after `timeout`.
+fn lock_file(lock_path: &str, timeout: Duration) -> Result<DBLock> {
+    let file = OpenOptions::new().create(true).truncate(false).write(true).open(lock_path)?;
+    let started = Instant::now();
+
+    loop {
+        let error = match file.try_lock_exclusive() {
+            Err(error) => error,
+            _ => return Ok(DBLock { file: Some(file) })
+        };
+
+        if error.raw_os_error() != lock_contended_error().raw_os_error() {
+            return Err(error.into());
+        }
+
+        if started.elapsed() >= timeout {
+            return Err(anyhow!(format!("Database {} is locked by another jira-cli instance, try again once it has finished!", lock_path.trim_end_matches(".lock")).red()));
+        }
+
+        thread::sleep(LOCK_RETRY_INTERVAL);
+    }
+}
+
+/// JSONFileDatabase represents a database stored in a JSON file.
+///
+/// This struct stores the file path to the JSON file where the database is stored.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::JSONFileDatabase;
+///
+/// let file_path = "/path/to/database.json".to_string();
+/// let json_file_db = JSONFileDatabase::new(file_path);
+/// ```
+struct JSONFileDatabase {
+    pub file_path: String,
+
+    /// Whether the last `read_db` had to recover the state from the backup, i.e. whether the
+    /// file is corrupt and must not replace the backup on the next write.
+    read_from_backup: Cell<bool>
+}
+
+impl Database for JSONFileDatabase {
+
+    /// Returns the path to the file of the data in the JSON Database
+    fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    /// Reads the database state from the JSON file.
+    ///
+    /// This method reads the database state from the JSON file specified by `file_path`.
+    /// It reads the file content, migrates it from older schema versions if needed,
+    /// deserializes it into a `DBState` struct, and returns it. If the file is missing or is not
+    /// valid JSON, the state is recovered from the `.bak` backup written alongside it by `write_db`.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if:
+    /// * Neither the file nor its backup can be read.
+    /// * Neither the file content nor the backup can be deserialized into a `DBState` struct.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the deserialized `DBState` if the operation is successful,
+    /// otherwise returns an `Err` containing an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JSONFileDatabase;
+    /// use anyhow::Result;
+    ///
+    /// let file_path = "/path/to/database.json".to_string();
+    /// let json_file_db = JSONFileDatabase::new(file_path);
+    /// match json_file_db.read_db() {
+    ///     Ok(db_state) => {
+    ///         // Use the retrieved database state
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    fn read_db(&self) -> Result<DBState> {
+        self.read_from_backup.set(false);
+
+        let document = Self::read_document(&self.file_path).or_else(|error| {
+            let backup = Self::read_document(&self.backup_path()).map_err(|_| error)?;
+            self.read_from_backup.set(true);
+
+            Ok(backup)
+        })?;
+
+        migrations::migrate(document)
+    }
+
+    /// Writes the database state to the JSON file.
+    ///
+    /// This method writes the provided database state to the JSON file specified by `file_path`.
+    /// It serializes the `DBState` struct into JSON format and writes it to a temporary file in
+    /// the same directory, which is synced to disk before being renamed over the original. The
+    /// previous contents of the file are kept as a `.bak` backup, so a crash or a full disk at
+    /// any point leaves either the old or the new state intact. If the last `read_db` had to
+    /// recover from the backup because the file is corrupt, the existing backup is kept instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_state` - A reference to the `DBState` struct containing the database state to be written.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if:
+    /// * The file cannot be written.
+    /// * The database state cannot be serialized into JSON format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::db::JSONFileDatabase;
+    /// use crate::models::DBState;
+    /// use anyhow::Result;
+    ///
+    /// let file_path = "/path/to/database.json".to_string();
+    /// let json_file_db = JSONFileDatabase::new(file_path);
+    /// let db_state = DBState { /* Populate DBState fields */ };
+    /// match json_file_db.write_db(&db_state) {
+    ///     Ok(()) => {
+    ///         // Database state successfully written to the file
+    ///     }
+    ///     Err(err) => {
+    ///         // Handle the error
+    ///     }
+    /// }
+    /// ```
+    fn write_db(&self, db_state: &DBState) -> Result<()> {
+        let temp_path = self.temp_path();
+
+        let mut temp_file = File::create(&temp_path)?;
+        temp_file.write_all(&serde_json::to_vec(db_state)?)?;
+        temp_file.sync_all()?;
+        drop(temp_file);
+
+        // A corrupt file must not replace the last good backup, so it is simply overwritten
+        if !self.read_from_backup.get() && Path::new(&self.file_path).exists() {
+            fs::rename(&self.file_path, self.backup_path())?;
+        }
+
+        fs::rename(&temp_path, &self.file_path)?;
+        self.sync_parent_dir()?;
+
+        Ok(())
+    }
+
+    /// Acquires an exclusive lock on the JSON file.
+    ///
+    /// The lock is an advisory lock on a `.lock` file next to the database file, as the database
+    /// file itself is replaced on every write. If another instance holds the lock, this method
+    /// keeps retrying for up to `LOCK_TIMEOUT` before giving up.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if:
+    /// * The lock file cannot be opened.
+    /// * Another instance of the CLI still holds the lock after `LOCK_TIMEOUT`.
+    fn lock(&self) -> Result<DBLock> {
+        self.lock_with_timeout(LOCK_TIMEOUT)
+    }
+}
+
+impl JSONFileDatabase {
+
+    /// Creates a `JSONFileDatabase` storing its data in the JSON file at `file_path`.
+    fn new(file_path: String) -> Self {
+        Self { file_path, read_from_backup: Cell::new(false) }
+    }
+
+    /// Acquires an exclusive lock on the JSON file, retrying until `timeout` has elapsed.
+    fn lock_with_timeout(&self, timeout: Duration) -> Result<DBLock> {
+        lock_file(&self.lock_path(), timeout)
+    }
+
+    /// Returns the path of the file used to lock the database.
+    fn lock_path(&self) -> String {
+        format!("{}.lock", self.file_path)
+    }
+
+    /// Reads the raw JSON document stored at `path`.
+    ///
+    /// The document is only migrated into a `DBState` afterwards, so that a document written by a
+    /// newer version of the CLI is reported as such instead of silently falling back to the backup.
+    fn read_document(path: &str) -> Result<Value> {
+        let db_content = fs::read_to_string(path)?;
+        let document = serde_json::from_str(&db_content)?;
+        Ok(document)
+    }
+
+    /// Returns the path of the rolling backup holding the state prior to the last write.
+    fn backup_path(&self) -> String {
+        format!("{}.bak", self.file_path)
+    }
+
+    /// Returns the path of the temporary file new states are written to before being renamed
+    /// over the database file. It lives in the same directory so the rename stays atomic.
+    fn temp_path(&self) -> String {
+        format!("{}.tmp", self.file_path)
+    }
+
+    /// Flushes the directory entry of the database file so the renames survive a crash.
+    #[cfg(unix)]
+    fn sync_parent_dir(&self) -> Result<()> {
+        let parent = match Path::new(&self.file_path).parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new(".")
+        };
+
+        File::open(parent)?.sync_all()?;
+        Ok(())
+    }
+
+    /// Directories cannot be opened for syncing on this platform, so this is a no-op.
+    #[cfg(not(unix))]
+    fn sync_parent_dir(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+// UNIT TESTING UTILS ------------------------------------------------------------------------------------
+
+#[cfg(test)]
+pub mod test_utils {
+    use std::cell::RefCell;
+
+    use super::*;
+    
+    pub struct MockDB {
+        last_written_state: RefCell<DBState>
+    }
+
+    impl MockDB {
+        pub fn new() -> Self {
+            Self { last_written_state: RefCell::new(DBState::default()) }
+        }    
+    }
+
+    impl Database for MockDB {
+        fn get_file_path(&self) -> &str {
+            ""
+        }
+
+        fn read_db(&self) -> Result<DBState> {
+            let state = self.last_written_state.borrow().clone();
+            Ok(state)
+        }
+
+        fn write_db(&self, db_state: &DBState) -> Result<()> {
+            let latest_state = &self.last_written_state;
+            *latest_state.borrow_mut() = db_state.clone();
+            Ok(())
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use super::test_utils::MockDB;
+    use crate::config::Workflow;
+    use crate::models::{IssueType, Priority, Severity};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn create_epic_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic.clone());
+        
+        assert_eq!(result.is_ok(), true);
+
+        let id = result.unwrap();
+        let db_state = db.read_db().unwrap();
+
+        let expected_id = 1;
+
+        assert_eq!(id, expected_id);
+        assert_eq!(db_state.last_item_id, expected_id);
+
+        let created = db_state.epics.get(&id).unwrap();
+        assert!(created.created_at.is_some());
+        assert_eq!(created.updated_at, created.created_at);
+        assert_eq!(created, &Epic { created_at: created.created_at, updated_at: created.updated_at, ..epic });
+    }
+
+    #[test]
+    fn create_story_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let non_existent_epic_id = 999;
+
+        let result = db.create_story(story, non_existent_epic_id);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn create_story_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story.clone(), epic_id);
+        assert_eq!(result.is_ok(), true);
+
+        let id = result.unwrap();
+        let db_state = db.read_db().unwrap();
+
+        let expected_id = 2;
+
+        assert_eq!(id, expected_id);
+        assert_eq!(db_state.last_item_id, expected_id);
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories.contains(&id), true);
+
+        let created = db_state.stories.get(&id).unwrap();
+        assert!(created.created_at.is_some());
+        assert_eq!(created, &Story { created_at: created.created_at, updated_at: created.updated_at, ..story });
+    }
+
+    #[test]
+    fn delete_epic_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_epic_id = 999;
+
+        let result = db.delete_epic(non_existent_epic_id);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn delete_epic_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story, epic_id);
+        assert_eq!(result.is_ok(), true);
+
+        let story_id = result.unwrap();
+
+        let result = db.delete_epic(epic_id);
+        assert_eq!(result.is_ok(), true);
+
+        let db_state = db.read_db().unwrap();
+
+        let expected_last_id = 2;
+
+        assert_eq!(db_state.last_item_id, expected_last_id);
+        assert_eq!(db_state.epics.get(&epic_id), None);
+        assert_eq!(db_state.stories.get(&story_id), None);
+    }
+
+    #[test]
+    fn delete_story_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story, epic_id);
+        assert_eq!(result.is_ok(), true);
+        
+        let story_id = result.unwrap();
+
+        let non_existent_epic_id = 999;
+        
+        let result = db.delete_story(non_existent_epic_id, story_id);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn delete_story_should_error_if_story_not_found_in_epic() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story, epic_id);
+        assert_eq!(result.is_ok(), true);
+
+        let non_existent_story_id = 999;
+        
+        let result = db.delete_story(epic_id, non_existent_story_id);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn delete_story_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story, epic_id);
+        assert_eq!(result.is_ok(), true);
+
+        let story_id = result.unwrap();
+
+        let result = db.delete_story(epic_id, story_id);
+        assert_eq!(result.is_ok(), true);
+
+        let db_state = db.read_db().unwrap();
+
+        let expected_last_id = 2;
+
+        assert_eq!(db_state.last_item_id, expected_last_id);
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories.contains(&story_id), false);
+        assert_eq!(db_state.stories.get(&story_id), None);
+    }
+
+    #[test]
+    fn update_epic_status_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_epic_id = 999;
+
+        let result = db.update_epic_status(non_existent_epic_id, Status::Closed);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn update_epic_status_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+        
+        assert_eq!(result.is_ok(), true);
+
+        let epic_id = result.unwrap();
+
+        let result = db.update_epic_status(epic_id, Status::Closed);
+
+        assert_eq!(result.is_ok(), true);
+
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().status, Status::Closed);
+    }
+
+    #[test]
+    fn update_story_status_should_error_if_invalid_story_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_story_id = 999;
+
+        let result = db.update_story_status(non_existent_story_id, Status::Closed);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn update_story_status_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic::new("".to_owned(), "".to_owned());
+        let story = Story::new("".to_owned(), "".to_owned());
+
+        let result = db.create_epic(epic);
+
+        let epic_id = result.unwrap();
+
+        let result = db.create_story(story, epic_id);
+
+        let story_id = result.unwrap();
+
+        let result = db.update_story_status(story_id, Status::Closed);
+
+        assert_eq!(result.is_ok(), true);
+
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state.stories.get(&story_id).unwrap().status, Status::Closed);
+    }
+
+    #[test]
+    fn update_status_should_follow_workflow() {
+        let workflow = Workflow {
+            statuses: vec!["open".to_owned(), "in progress".to_owned(), "code review".to_owned(), "closed".to_owned()],
+            transitions: HashMap::from([("open".to_owned(), vec!["in progress".to_owned()]),
+                                        ("in progress".to_owned(), vec!["code review".to_owned()])]),
+            ..Workflow::default()
+        };
+        let db = JiraDatabase { database: Box::new(MockDB::new()), config: Config { workflow, ..Config::default() } };
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let subtask_id = db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+        let code_review = Status::Custom("CODE REVIEW".to_owned());
+
+        assert!(db.update_story_status(story_id, Status::Closed).is_err());
+        assert!(db.update_story_status(story_id, Status::Resolved).is_err());
+        assert!(db.update_epic_status(epic_id, code_review.clone()).is_err());
+        assert!(db.update_subtask_status(subtask_id, Status::Closed).is_err());
+
+        db.update_story_status(story_id, Status::InProgress).unwrap();
+        db.update_story_status(story_id, code_review.clone()).unwrap();
+        db.update_story_status(story_id, Status::Closed).unwrap();
+
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().status, Status::Closed);
+    }
+
+    #[test]
+    fn move_story_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let non_existent_epic_id = 999;
+
+        assert!(db.move_story(story_id, epic_id, non_existent_epic_id).is_err());
+        assert!(db.move_story(story_id, non_existent_epic_id, epic_id).is_err());
+        assert!(db.move_story(story_id, epic_id, epic_id).is_err());
+    }
+
+    #[test]
+    fn move_story_should_error_if_story_not_found_in_epic() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let result = db.move_story(story_id, other_epic_id, epic_id);
+        assert!(result.is_err());
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories, vec![story_id]);
+    }
+
+    #[test]
+    fn move_story_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+
+        let result = db.move_story(story_id, epic_id, other_epic_id);
+        assert!(result.is_ok());
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories, Vec::<u32>::new());
+        assert_eq!(db_state.epics.get(&other_epic_id).unwrap().stories, vec![other_story_id, story_id]);
+        assert!(db_state.stories.contains_key(&story_id));
+    }
+
+    #[test]
+    fn add_user_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.add_user(" ", User { name: "".to_owned() }).is_err());
+
+        db.add_user("alice", User { name: "Alice".to_owned() }).unwrap();
+        db.add_user("alice", User { name: "Alice Smith".to_owned() }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.users.len(), 1);
+        assert_eq!(db_state.users.get("alice").unwrap().name, "Alice Smith".to_owned());
+    }
+
+    #[test]
+    fn assign_story_should_error_if_invalid_story_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_story_id = 999;
+
+        assert!(db.assign_story(non_existent_story_id, Some("alice")).is_err());
+        assert!(db.read_db().unwrap().users.is_empty());
+    }
+
+    #[test]
+    fn assign_story_should_register_new_users() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        db.add_user("alice", User { name: "Alice Smith".to_owned() }).unwrap();
+
+        db.assign_story(story_id, Some("alice")).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().assignee, Some("alice".to_owned()));
+
+        db.assign_story(story_id, Some("bob")).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.stories.get(&story_id).unwrap().assignee, Some("bob".to_owned()));
+        assert_eq!(db_state.users.get("alice").unwrap().name, "Alice Smith".to_owned());
+        assert_eq!(db_state.users.get("bob").unwrap().name, "bob".to_owned());
+
+        db.assign_story(story_id, None).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().assignee, None);
+    }
+
+    #[test]
+    fn set_epic_owner_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        assert!(db.set_epic_owner(999, Some("alice")).is_err());
+
+        db.set_epic_owner(epic_id, Some("alice")).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().owner, Some("alice".to_owned()));
+        assert!(db_state.users.contains_key("alice"));
+    }
+
+    #[test]
+    fn create_subtask_should_error_if_invalid_story_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.create_subtask(Subtask::new("".to_owned()), 999).is_err());
+        assert_eq!(db.read_db().unwrap().last_item_id, 0);
+    }
+
+    #[test]
+    fn create_subtask_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let subtask_id = db.create_subtask(Subtask::new("subtask".to_owned()), story_id).unwrap();
+        let other_subtask_id = db.create_subtask(Subtask::new("other".to_owned()), story_id).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.last_item_id, other_subtask_id);
+        assert_eq!(db_state.stories.get(&story_id).unwrap().subtasks, vec![subtask_id, other_subtask_id]);
+
+        let subtask = db_state.subtasks.get(&subtask_id).unwrap();
+        assert_eq!(subtask.name, "subtask".to_owned());
+        assert!(subtask.created_at.is_some());
+    }
+
+    #[test]
+    fn update_subtask_status_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let subtask_id = db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+
+        assert!(db.update_subtask_status(999, Status::Closed).is_err());
+
+        db.update_subtask_status(subtask_id, Status::Closed).unwrap();
+
+        assert_eq!(db.read_db().unwrap().subtasks.get(&subtask_id).unwrap().status, Status::Closed);
+    }
+
+    #[test]
+    fn delete_subtask_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let subtask_id = db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+
+        assert!(db.delete_subtask(other_story_id, subtask_id).is_err());
+
+        db.delete_subtask(story_id, subtask_id).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert!(db_state.subtasks.is_empty());
+        assert_eq!(db_state.stories.get(&story_id).unwrap().subtasks, Vec::<u32>::new());
+    }
+
+    #[test]
+    fn deleting_stories_and_epics_should_delete_their_subtasks() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+        db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+        db.create_subtask(Subtask::new("".to_owned()), other_story_id).unwrap();
+
+        db.delete_story(epic_id, story_id).unwrap();
+        assert_eq!(db.read_db().unwrap().subtasks.len(), 1);
+
+        db.delete_epic(other_epic_id).unwrap();
+        assert!(db.read_db().unwrap().subtasks.is_empty());
+
+        db.undo().unwrap();
+        assert_eq!(db.read_db().unwrap().subtasks.len(), 1);
+    }
+
+    #[test]
+    fn add_comment_should_error_if_invalid_item_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.add_comment(999, "comment").is_err());
+    }
+
+    #[test]
+    fn add_comment_should_error_if_empty() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        assert!(db.add_comment(epic_id, "  ").is_err());
+        assert!(db.read_db().unwrap().comments.is_empty());
+    }
+
+    #[test]
+    fn add_comment_should_work() {
+        let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+        let db = JiraDatabase { database: Box::new(MockDB::new()), config };
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let first_id = db.add_comment(story_id, " first ").unwrap();
+        let second_id = db.add_comment(story_id, "second").unwrap();
+        let epic_comment_id = db.add_comment(epic_id, "on the epic").unwrap();
+
+        assert_eq!((first_id, second_id, epic_comment_id), (1, 2, 3));
+
+        let db_state = db.read_db().unwrap();
+        let comments = db_state.comments_on(story_id);
+
+        assert_eq!(comments.iter().map(|(_, comment)| comment.body.as_str()).collect::<Vec<_>>(), vec!["first", "second"]);
+        assert!(comments.iter().all(|(_, comment)| comment.author == "alice" && comment.updated_at.is_none()));
+        assert_eq!(db_state.last_item_id, story_id);
+    }
+
+    #[test]
+    fn edit_comment_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let comment_id = db.add_comment(epic_id, "typo").unwrap();
+
+        assert!(db.edit_comment(999, "fixed").is_err());
+        assert!(db.edit_comment(comment_id, "").is_err());
+
+        db.edit_comment(comment_id, "fixed").unwrap();
+
+        let comment = db.read_db().unwrap().comments.remove(&comment_id).unwrap();
+        assert_eq!(comment.body, "fixed".to_owned());
+        assert!(comment.updated_at.is_some());
+    }
+
+    #[test]
+    fn delete_comment_should_work() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let comment_id = db.add_comment(epic_id, "comment").unwrap();
+
+        db.delete_comment(comment_id).unwrap();
+
+        assert!(db.read_db().unwrap().comments.is_empty());
+        assert!(db.delete_comment(comment_id).is_err());
+
+        db.undo().unwrap();
+        assert!(db.read_db().unwrap().comments.contains_key(&comment_id));
+    }
+
+    #[test]
+    fn add_link_should_store_both_sides() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        db.add_link(story_id, LinkType::Blocks, other_story_id).unwrap();
+        db.add_link(story_id, LinkType::RelatesTo, epic_id).unwrap();
+
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state.stories.get(&story_id).unwrap().links, vec![Link { link_type: LinkType::Blocks, item_id: other_story_id },
+                                                                       Link { link_type: LinkType::RelatesTo, item_id: epic_id }]);
+        assert_eq!(db_state.stories.get(&other_story_id).unwrap().links, vec![Link { link_type: LinkType::IsBlockedBy, item_id: story_id }]);
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().links, vec![Link { link_type: LinkType::RelatesTo, item_id: story_id }]);
+
+        assert!(db.add_link(story_id, LinkType::Blocks, other_story_id).is_err());
+        assert!(db.add_link(story_id, LinkType::Blocks, story_id).is_err());
+        assert!(db.add_link(story_id, LinkType::Blocks, 999).is_err());
+        assert!(db.add_link(999, LinkType::Blocks, story_id).is_err());
+    }
+
+    #[test]
+    fn remove_link_should_remove_both_sides() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        db.add_link(story_id, LinkType::Duplicates, other_story_id).unwrap();
+
+        assert!(db.remove_link(story_id, LinkType::Blocks, other_story_id).is_err());
+
+        db.remove_link(other_story_id, LinkType::IsDuplicatedBy, story_id).unwrap();
+
+        let db_state = db.read_db().unwrap();
+
+        assert!(db_state.stories.get(&story_id).unwrap().links.is_empty());
+        assert!(db_state.stories.get(&other_story_id).unwrap().links.is_empty());
+    }
+
+    #[test]
+    fn deleting_items_should_delete_links_to_them() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+        let blocked_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+
+        db.add_link(story_id, LinkType::Blocks, blocked_story_id).unwrap();
+        db.add_link(epic_id, LinkType::RelatesTo, other_epic_id).unwrap();
+        db.add_link(other_story_id, LinkType::Blocks, blocked_story_id).unwrap();
+
+        db.delete_epic(epic_id).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert!(db_state.epics.get(&other_epic_id).unwrap().links.is_empty());
+        assert_eq!(db_state.stories.get(&blocked_story_id).unwrap().links, vec![Link { link_type: LinkType::IsBlockedBy, item_id: other_story_id }]);
+
+        db.delete_story(other_epic_id, other_story_id).unwrap();
+
+        assert!(db.read_db().unwrap().stories.get(&blocked_story_id).unwrap().links.is_empty());
+    }
+
+    #[test]
+    fn create_sprint_should_validate_name_and_dates() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let start = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+
+        assert!(db.create_sprint(Sprint::new(" ".to_owned(), "".to_owned(), start, end)).is_err());
+        assert!(db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), end, start)).is_err());
+
+        let sprint_id = db.create_sprint(Sprint { state: SprintState::Active, ..Sprint::new(" Sprint 1 ".to_owned(), "".to_owned(), start, end) }).unwrap();
+        let sprint = db.read_db().unwrap().sprints.remove(&sprint_id).unwrap();
+
+        assert_eq!(sprint.name, "Sprint 1".to_owned());
+        assert_eq!(sprint.state, SprintState::Planned);
+        assert_eq!(db.read_db().unwrap().last_sprint_id, sprint_id);
+    }
+
+    #[test]
+    fn filters_should_be_saved_replaced_and_deleted() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.save_filter(" ", "status = open").is_err());
+        assert!(db.save_filter("my open bugs", "type = bug AND").is_err());
+        assert!(db.read_db().unwrap().filters.is_empty());
+
+        db.save_filter(" my open bugs ", "type = bug").unwrap();
+        db.save_filter("my open bugs", "type = bug AND status = open").unwrap();
+
+        let filters = db.read_db().unwrap().filters;
+        assert_eq!(filters.len(), 1);
+        assert_eq!(filters["my open bugs"].query, "type = bug AND status = open".to_owned());
+
+        assert!(db.delete_filter("other").is_err());
+        db.delete_filter(" my open bugs ").unwrap();
+        assert!(db.read_db().unwrap().filters.is_empty());
+
+        db.undo().unwrap();
+        assert_eq!(db.read_db().unwrap().filters.len(), 1);
+    }
+
+    #[test]
+    fn sprint_stories_should_be_added_and_removed() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let sprint_id = db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), date, date)).unwrap();
+        let other_sprint_id = db.create_sprint(Sprint::new("Sprint 2".to_owned(), "".to_owned(), date, date)).unwrap();
+
+        db.add_story_to_sprint(sprint_id, story_id).unwrap();
+
+        assert!(db.add_story_to_sprint(sprint_id, story_id).is_err());
+        assert!(db.add_story_to_sprint(other_sprint_id, story_id).is_err());
+        assert!(db.add_story_to_sprint(sprint_id, 999).is_err());
+        assert!(db.add_story_to_sprint(999, story_id).is_err());
+        assert_eq!(db.read_db().unwrap().sprints.get(&sprint_id).unwrap().stories, vec![story_id]);
+
+        assert!(db.remove_story_from_sprint(other_sprint_id, story_id).is_err());
+        db.remove_story_from_sprint(sprint_id, story_id).unwrap();
+        db.add_story_to_sprint(other_sprint_id, story_id).unwrap();
+
+        db.delete_story(epic_id, story_id).unwrap();
+
+        assert!(db.read_db().unwrap().sprints.values().all(|sprint| sprint.stories.is_empty()));
+    }
+
+    #[test]
+    fn completing_sprint_should_roll_incomplete_stories_over() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let date = |day| NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let done_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let open_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let sprint_id = db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), date(3), date(14))).unwrap();
+        let later_sprint_id = db.create_sprint(Sprint::new("Sprint 3".to_owned(), "".to_owned(), date(28), date(30))).unwrap();
+        let next_sprint_id = db.create_sprint(Sprint::new("Sprint 2".to_owned(), "".to_owned(), date(17), date(27))).unwrap();
+
+        db.add_story_to_sprint(sprint_id, done_story_id).unwrap();
+        db.add_story_to_sprint(sprint_id, open_story_id).unwrap();
+        db.update_story_status(done_story_id, Status::Resolved).unwrap();
+
+        assert!(db.complete_sprint(sprint_id).is_err());
+
+        db.start_sprint(sprint_id).unwrap();
+
+        assert!(db.start_sprint(next_sprint_id).is_err());
+        assert!(db.start_sprint(sprint_id).is_err());
+        assert!(db.add_story_to_sprint(sprint_id, 999).is_err());
+
+        assert_eq!(db.complete_sprint(sprint_id).unwrap(), Some(next_sprint_id));
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.sprints.get(&sprint_id).unwrap().state, SprintState::Completed);
+        assert_eq!(db_state.sprints.get(&sprint_id).unwrap().stories, vec![done_story_id]);
+        assert_eq!(db_state.sprints.get(&next_sprint_id).unwrap().stories, vec![open_story_id]);
+        assert!(db_state.sprints.get(&later_sprint_id).unwrap().stories.is_empty());
+
+        assert!(db.complete_sprint(sprint_id).is_err());
+        assert!(db.remove_story_from_sprint(sprint_id, done_story_id).is_err());
+
+        db.start_sprint(next_sprint_id).unwrap();
+        db.start_sprint(later_sprint_id).unwrap_err();
+        db.complete_sprint(next_sprint_id).unwrap();
+        db.start_sprint(later_sprint_id).unwrap();
+
+        assert_eq!(db.complete_sprint(later_sprint_id).unwrap(), None);
+        assert_eq!(db.read_db().unwrap().sprint_of(open_story_id), None);
+    }
+
+    #[test]
+    fn deleting_items_should_delete_their_comments() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+
+        db.add_comment(epic_id, "epic").unwrap();
+        db.add_comment(story_id, "story").unwrap();
+        db.add_comment(other_story_id, "other story").unwrap();
+        let kept_id = db.add_comment(other_epic_id, "other epic").unwrap();
+
+        db.delete_story(other_epic_id, other_story_id).unwrap();
+        db.delete_epic(epic_id).unwrap();
+
+        let comments = db.read_db().unwrap().comments;
+        assert_eq!(comments.keys().collect::<Vec<_>>(), vec![&kept_id]);
+    }
+
+    #[test]
+    fn update_epic_should_error_if_invalid_epic_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_epic_id = 999;
+
+        let result = db.update_epic(non_existent_epic_id, EpicPatch::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn update_epic_should_only_change_patched_fields() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("name".to_owned(), "description".to_owned())).unwrap();
+        db.update_epic_status(epic_id, Status::InProgress).unwrap();
+
+        let result = db.update_epic(epic_id, EpicPatch { name: Some("new name".to_owned()), ..EpicPatch::default() });
+        assert!(result.is_ok());
+
+        let db_state = db.read_db().unwrap();
+        let epic = db_state.epics.get(&epic_id).unwrap();
+
+        assert_eq!(epic.name, "new name".to_owned());
+        assert_eq!(epic.description, "description".to_owned());
+        assert_eq!(epic.status, Status::InProgress);
+    }
+
+    #[test]
+    fn labels_should_be_normalized() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic = Epic { labels: vec!["#Backend".to_owned(), "api".to_owned()], ..Epic::new("".to_owned(), "".to_owned()) };
+        let epic_id = db.create_epic(epic).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().labels, vec!["api".to_owned(), "backend".to_owned()]);
+
+        db.update_story(story_id, StoryPatch { labels: Some(vec!["UI".to_owned(), "ui".to_owned()]), ..StoryPatch::default() }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().labels, vec!["ui".to_owned()]);
+
+        assert!(db.update_epic(epic_id, EpicPatch { labels: Some(vec!["not valid".to_owned()]), ..EpicPatch::default() }).is_err());
+        assert!(db.create_epic(Epic { labels: vec!["not valid".to_owned()], ..Epic::new("".to_owned(), "".to_owned()) }).is_err());
+    }
+
+    #[test]
+    fn mutations_should_update_timestamps() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let before = db.read_db().unwrap();
+
+        db.update_story(story_id, StoryPatch { due_date: Some(NaiveDate::from_ymd_opt(2024, 12, 31)), ..StoryPatch::default() }).unwrap();
+
+        let after = db.read_db().unwrap();
+        let story = after.stories.get(&story_id).unwrap();
+
+        assert_eq!(story.due_date, NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(story.created_at, before.stories.get(&story_id).unwrap().created_at);
+        assert!(story.updated_at > before.stories.get(&story_id).unwrap().updated_at);
+        assert_eq!(after.stories.get(&other_story_id), before.stories.get(&other_story_id));
+        assert_eq!(after.epics.get(&epic_id), before.epics.get(&epic_id));
+        assert!(after.change_log.iter().all(|event| event.field != "updated_at" && event.field != "created_at"));
+    }
+
+    #[test]
+    fn update_story_should_error_if_invalid_story_id() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let non_existent_story_id = 999;
+
+        let result = db.update_story(non_existent_story_id, StoryPatch::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn update_story_should_only_change_patched_fields() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("name".to_owned(), "description".to_owned()), epic_id).unwrap();
+
+        let result = db.update_story(story_id, StoryPatch { description: Some("new description".to_owned()), priority: Some(Priority::High), ..StoryPatch::default() });
+        assert!(result.is_ok());
+
+        let db_state = db.read_db().unwrap();
+        let story = db_state.stories.get(&story_id).unwrap();
+
+        assert_eq!(story.name, "name".to_owned());
+        assert_eq!(story.description, "new description".to_owned());
+        assert_eq!(story.priority, Priority::High);
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories, vec![story_id]);
+    }
+
+    #[test]
+    fn update_story_should_change_issue_type_and_bug_fields() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story = Story { severity: Some(Severity::Minor), ..Story::new("name".to_owned(), "".to_owned()) };
+        let story_id = db.create_story(story, epic_id).unwrap();
+
+        let patch = StoryPatch {
+            issue_type: Some(IssueType::Bug),
+            steps_to_reproduce: Some(Some("click twice".to_owned())),
+            severity: Some(None),
+            ..StoryPatch::default()
+        };
+        db.update_story(story_id, patch).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        let story = db_state.stories.get(&story_id).unwrap();
+
+        assert_eq!(story.issue_type, IssueType::Bug);
+        assert_eq!(story.steps_to_reproduce, Some("click twice".to_owned()));
+        assert_eq!(story.severity, None);
+        assert_eq!(story.affected_version, None);
+    }
+
+    #[test]
+    fn undo_should_error_if_nothing_to_undo() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.undo().is_err());
+        assert!(db.redo().is_err());
+    }
+
+    #[test]
+    fn undo_should_restore_deleted_epic_and_stories() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let db_state_before_delete = db.read_db().unwrap();
+
+        db.delete_epic(epic_id).unwrap();
+
+        let result = db.undo();
+        assert!(result.is_ok());
+
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state.epics, db_state_before_delete.epics);
+        assert_eq!(db_state.stories, db_state_before_delete.stories);
+        assert!(db_state.epics.get(&epic_id).unwrap().stories.contains(&story_id));
+    }
+
+    #[test]
+    fn undo_and_redo_should_walk_through_history() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        db.update_epic_status(epic_id, Status::InProgress).unwrap();
+        db.update_epic_status(epic_id, Status::Closed).unwrap();
+
+        db.undo().unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().status, Status::InProgress);
+
+        db.undo().unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().status, Status::Open);
+
+        db.undo().unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id), None);
+        assert!(db.undo().is_err());
+
+        db.redo().unwrap();
+        db.redo().unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().status, Status::InProgress);
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.history.undo.len(), 2);
+        assert_eq!(db_state.history.redo.len(), 1);
+        assert_eq!(db_state.last_item_id, epic_id);
+    }
+
+    #[test]
+    fn new_changes_should_clear_redo() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        db.update_epic_status(epic_id, Status::InProgress).unwrap();
+        db.undo().unwrap();
+
+        db.update_epic_status(epic_id, Status::Closed).unwrap();
+
+        assert!(db.redo().is_err());
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().status, Status::Closed);
+    }
+
+    #[test]
+    fn changes_should_be_logged_with_actor() {
+        let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+        let db = JiraDatabase { database: Box::new(MockDB::new()), config };
+
+        let epic_id = db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        db.update_epic_status(epic_id, Status::Closed).unwrap();
+        db.undo().unwrap();
+
+        let change_log = db.read_db().unwrap().change_log;
+
+        let logged: Vec<_> = change_log.iter()
+            .map(|event| (event.field.as_str(), event.old_value.clone(), event.new_value.clone()))
+            .collect();
+
+        assert_eq!(logged, vec![
+            ("created", None, Some(Value::from("epic"))),
+            ("status", Some(Value::from("Open")), Some(Value::from("Closed"))),
+            ("status", Some(Value::from("Closed")), Some(Value::from("Open")))
+        ]);
+        assert!(change_log.iter().all(|event| event.actor == "alice" && event.id == epic_id.to_string()));
+    }
+
+    #[test]
+    fn change_log_should_be_capped() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        let old_event = ChangeEvent { timestamp: Utc::now(), actor: "bob".to_owned(), collection: "epics".to_owned(), id: "0".to_owned(),
+                                      field: "name".to_owned(), old_value: None, new_value: None };
+        db.database.write_db(&DBState { change_log: vec![old_event; CHANGE_LOG_LIMIT], ..DBState::default() }).unwrap();
+
+        db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+
+        let change_log = db.read_db().unwrap().change_log;
+
+        assert_eq!(change_log.len(), CHANGE_LOG_LIMIT);
+        assert_eq!(change_log.last().unwrap().field, "created");
+    }
+
+    #[test]
+    fn failed_changes_should_not_be_recorded() {
+        let db = JiraDatabase::from_database(Box::new(MockDB::new()));
+
+        assert!(db.delete_epic(999).is_err());
+        assert!(db.read_db().unwrap().history.undo.is_empty());
+    }
+
+    mod database {
+        use std::collections::HashMap;
+        use std::io::Write;
+
+        use super::*;
+
+        #[test]
+        fn read_db_should_fail_with_invalid_path() {
+            let db = JSONFileDatabase::new("INVALID_PATH".to_owned());
+            assert_eq!(db.read_db().is_err(), true);
+        }
+
+        #[test]
+        fn read_db_should_fail_with_invalid_json() {
+            let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+            let file_contents = r#"{ "last_item_id": 0 epics: {} stories {} }"#;
+            write!(tmpfile, "{}", file_contents).unwrap();
+
+            let db = JSONFileDatabase::new(tmpfile.path().to_str()
+                .expect("failed to convert tmpfile path to str").to_string());
+
+            let result = db.read_db();
+
+            assert_eq!(result.is_err(), true);
+        }
+
+        #[test]
+        fn read_db_should_parse_json_file() {
+            let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+            let file_contents = r#"{ "last_item_id": 0, "epics": {}, "stories": {} }"#;
+            write!(tmpfile, "{}", file_contents).unwrap();
+
+            let db = JSONFileDatabase::new(tmpfile.path().to_str()
+                .expect("failed to convert tmpfile path to str").to_string());
+
+            let result = db.read_db();
+
+            assert_eq!(result.is_ok(), true);
+        }
+
+        #[test]
+        fn write_db_should_work() {
+            let mut tmpfile = tempfile::NamedTempFile::new().unwrap();
+
+            let file_contents = r#"{ "last_item_id": 0, "epics": {}, "stories": {} }"#;
+            write!(tmpfile, "{}", file_contents).unwrap();
+
+            let db = JSONFileDatabase::new(tmpfile.path().to_str()
+                .expect("failed to convert tmpfile path to str").to_string());
+
+            let story = Story::new("epic 1".to_owned(), "epic 1".to_owned());
+            let epic = Epic { stories: vec![2], ..Epic::new("epic 1".to_owned(), "epic 1".to_owned()) };
+
+            let mut stories = HashMap::new();
+            stories.insert(2, story);
+
+            let mut epics = HashMap::new();
+            epics.insert(1, epic);
+
+            let state = DBState { last_item_id: 2, epics, stories, ..DBState::default() };
+
+            let write_result = db.write_db(&state);
+            let read_result = db.read_db().unwrap();
+
+            assert_eq!(write_result.is_ok(), true);
+            assert_eq!(read_result, state);
+        }
+
+        #[test]
+        fn write_db_should_keep_previous_state_as_backup() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let db = JSONFileDatabase::new(file_path.clone());
+
+            let first_state = DBState::default();
+            let second_state = DBState { last_item_id: 1, ..DBState::default() };
+
+            db.write_db(&first_state).unwrap();
+            db.write_db(&second_state).unwrap();
+
+            let backup = migrations::migrate(JSONFileDatabase::read_document(&db.backup_path()).unwrap()).unwrap();
+
+            assert_eq!(db.read_db().unwrap(), second_state);
+            assert_eq!(backup, first_state);
+            assert!(!Path::new(&db.temp_path()).exists());
+        }
+
+        #[test]
+        fn read_db_should_recover_from_backup_if_file_is_corrupt() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let db = JSONFileDatabase::new(file_path.clone());
+
+            let first_state = DBState::default();
+            let second_state = DBState { last_item_id: 1, ..DBState::default() };
+
+            db.write_db(&first_state).unwrap();
+            db.write_db(&second_state).unwrap();
+
+            // Simulate a write that was cut short
+            fs::write(&file_path, r#"{ "last_item_id": 1, "epi"#).unwrap();
+
+            assert_eq!(db.read_db().unwrap(), first_state);
+        }
+
+        #[test]
+        fn write_db_should_not_replace_backup_with_corrupt_file() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let db = JSONFileDatabase::new(file_path.clone());
+
+            let first_state = DBState::default();
+            let second_state = DBState { last_item_id: 1, ..DBState::default() };
+            let third_state = DBState { last_item_id: 2, ..DBState::default() };
+
+            db.write_db(&first_state).unwrap();
+            db.write_db(&second_state).unwrap();
+
+            fs::write(&file_path, r#"{ "last_item_id": 1, "epi"#).unwrap();
+            assert_eq!(db.read_db().unwrap(), first_state);
+            db.write_db(&third_state).unwrap();
+
+            let backup = migrations::migrate(JSONFileDatabase::read_document(&db.backup_path()).unwrap()).unwrap();
+
+            assert_eq!(db.read_db().unwrap(), third_state);
+            assert_eq!(backup, first_state);
+        }
+
+        #[test]
+        fn read_db_should_recover_from_backup_if_file_is_missing() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let db = JSONFileDatabase::new(file_path.clone());
+
+            let first_state = DBState::default();
+            let second_state = DBState { last_item_id: 1, ..DBState::default() };
+
+            db.write_db(&first_state).unwrap();
+            db.write_db(&second_state).unwrap();
+
+            // Simulate a crash between rotating the backup and renaming the new file into place
+            fs::rename(&file_path, db.backup_path()).unwrap();
+
+            assert_eq!(db.read_db().unwrap(), second_state);
+        }
+
+        #[test]
+        fn history_should_survive_restart() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            JSONFileDatabase::new(file_path.clone()).write_db(&DBState::default()).unwrap();
+
+            let db = JiraDatabase::new(file_path.clone());
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            drop(db);
+
+            let db = JiraDatabase::new(file_path);
+            db.undo().unwrap();
+
+            assert_eq!(db.read_db().unwrap().epics.get(&epic_id), None);
+        }
+
+        #[test]
+        fn lock_should_fail_while_held_by_another_instance() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let db = JSONFileDatabase::new(file_path.clone());
+            let other_db = JSONFileDatabase::new(file_path.clone());
+
+            let lock = db.lock().unwrap();
+            assert!(other_db.lock_with_timeout(Duration::from_millis(50)).is_err());
+
+            drop(lock);
+            assert!(other_db.lock_with_timeout(Duration::from_millis(50)).is_ok());
+        }
+
+        #[test]
+        fn concurrent_writers_should_not_lose_changes() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let file_path = tmpdir.path().join("db.json").to_str().unwrap().to_owned();
+
+            let state = DBState::default();
+            JSONFileDatabase::new(file_path.clone()).write_db(&state).unwrap();
+
+            let writers = 4;
+            let epics_per_writer = 10;
+
+            let handles: Vec<_> = (0..writers).map(|_| {
+                let file_path = file_path.clone();
+
+                thread::spawn(move || {
+                    let db = JiraDatabase::new(file_path);
+
+                    for _ in 0..epics_per_writer {
+                        db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+                    }
+                })
+            }).collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            let db_state = JiraDatabase::new(file_path).read_db().unwrap();
+
+            assert_eq!(db_state.last_item_id, writers * epics_per_writer);
+            assert_eq!(db_state.epics.len(), (writers * epics_per_writer) as usize);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1069 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context, Ok, Result};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde_json::Value;
+
+use crate::models::{ChangeEvent, Comment, DBState, Epic, History, Link, Revision, SavedFilter, Sprint, Story, Subtask, User};
+use super::{lock_file, Database, DBLock, CHANGE_LOG_LIMIT, LOCK_TIMEOUT};
+
+/// Version of the tables created by this version of the CLI, tracked in the `user_version` pragma.
+const SQL_SCHEMA_VERSION: u32 = 14;
+
+/// The chain of schema migrations, where `SQL_MIGRATIONS[n]` upgrades the tables from version
+/// `n` to `n + 1`. The first one keeps `IF NOT EXISTS`, as the tables of the first SQLite
+/// backend were created without setting `user_version`, so such databases are still at version 0.
+const SQL_MIGRATIONS: [&str; SQL_SCHEMA_VERSION as usize] = [
+    "
+    CREATE TABLE IF NOT EXISTS meta (
+        key TEXT PRIMARY KEY,
+        value INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS epics (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        description TEXT NOT NULL,
+        status TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS stories (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        description TEXT NOT NULL,
+        status TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS epic_stories (
+        epic_id INTEGER NOT NULL REFERENCES epics(id) ON DELETE CASCADE,
+        story_id INTEGER NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        PRIMARY KEY (epic_id, story_id)
+    );
+    ",
+    "
+    CREATE TABLE revisions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        stack TEXT NOT NULL CHECK (stack IN ('undo', 'redo')),
+        changes TEXT NOT NULL
+    );
+    ",
+    "
+    CREATE TABLE change_log (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        timestamp TEXT NOT NULL,
+        actor TEXT NOT NULL,
+        collection TEXT NOT NULL,
+        entity_id TEXT NOT NULL,
+        field TEXT NOT NULL,
+        old_value TEXT,
+        new_value TEXT
+    );
+    ",
+    "
+    ALTER TABLE epics ADD COLUMN priority TEXT NOT NULL DEFAULT 'MEDIUM';
+    ALTER TABLE stories ADD COLUMN priority TEXT NOT NULL DEFAULT 'MEDIUM';
+    ",
+    "
+    CREATE TABLE users (
+        username TEXT PRIMARY KEY,
+        name TEXT NOT NULL
+    );
+
+    ALTER TABLE epics ADD COLUMN owner TEXT;
+    ALTER TABLE stories ADD COLUMN assignee TEXT;
+    ",
+    "
+    CREATE TABLE epic_labels (
+        epic_id INTEGER NOT NULL REFERENCES epics(id) ON DELETE CASCADE,
+        label TEXT NOT NULL,
+        PRIMARY KEY (epic_id, label)
+    );
+
+    CREATE TABLE story_labels (
+        story_id INTEGER NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+        label TEXT NOT NULL,
+        PRIMARY KEY (story_id, label)
+    );
+    ",
+    "
+    ALTER TABLE epics ADD COLUMN created_at TEXT;
+    ALTER TABLE epics ADD COLUMN updated_at TEXT;
+    ALTER TABLE epics ADD COLUMN due_date TEXT;
+    ALTER TABLE stories ADD COLUMN created_at TEXT;
+    ALTER TABLE stories ADD COLUMN updated_at TEXT;
+    ALTER TABLE stories ADD COLUMN due_date TEXT;
+    ",
+    "
+    ALTER TABLE stories ADD COLUMN story_points INTEGER;
+    ",
+    "
+    CREATE TABLE comments (
+        id INTEGER PRIMARY KEY,
+        item_id INTEGER NOT NULL,
+        author TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT,
+        body TEXT NOT NULL
+    );
+    ",
+    "
+    CREATE TABLE subtasks (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        status TEXT NOT NULL,
+        created_at TEXT,
+        updated_at TEXT
+    );
+
+    CREATE TABLE story_subtasks (
+        story_id INTEGER NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+        subtask_id INTEGER NOT NULL REFERENCES subtasks(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        PRIMARY KEY (story_id, subtask_id)
+    );
+    ",
+    "
+    ALTER TABLE stories ADD COLUMN issue_type TEXT NOT NULL DEFAULT 'STORY';
+    ALTER TABLE stories ADD COLUMN steps_to_reproduce TEXT;
+    ALTER TABLE stories ADD COLUMN severity TEXT;
+    ALTER TABLE stories ADD COLUMN affected_version TEXT;
+    ",
+    "
+    CREATE TABLE item_links (
+        item_id INTEGER NOT NULL,
+        link_type TEXT NOT NULL,
+        target_id INTEGER NOT NULL,
+        position INTEGER NOT NULL,
+        PRIMARY KEY (item_id, link_type, target_id)
+    );
+    ",
+    "
+    CREATE TABLE sprints (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        goal TEXT NOT NULL,
+        start_date TEXT NOT NULL,
+        end_date TEXT NOT NULL,
+        state TEXT NOT NULL
+    );
+
+    CREATE TABLE sprint_stories (
+        sprint_id INTEGER NOT NULL REFERENCES sprints(id) ON DELETE CASCADE,
+        story_id INTEGER NOT NULL REFERENCES stories(id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        PRIMARY KEY (sprint_id, story_id)
+    );
+    ",
+    "
+    CREATE TABLE filters (
+        name TEXT PRIMARY KEY,
+        query TEXT NOT NULL
+    );
+    "
+];
+
+/// SQLiteDatabase represents a database stored in an SQLite file.
+///
+/// Epics, stories, sub-tasks, users and comments are stored in their own tables, the stories of
+/// each epic in the `epic_stories` table, the sub-tasks of each story in the `story_subtasks`
+/// table, labels in the `epic_labels` and `story_labels` tables, the links of both epics and
+/// stories in the `item_links` table, sprints in the `sprints` table and their stories in the
+/// `sprint_stories` table, saved filters in the `filters` table, the undo and redo stacks in the
+/// `revisions` table and the audit trail in the append-only `change_log` table, of which only the
+/// latest `CHANGE_LOG_LIMIT` events are read. Unlike `JSONFileDatabase`, writing a new state only
+/// touches the rows that actually changed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::db::SQLiteDatabase;
+///
+/// let sqlite_db = SQLiteDatabase::open("/path/to/database.sqlite".to_string())?;
+/// ```
+pub struct SQLiteDatabase {
+    file_path: String,
+    connection: Connection
+}
+
+impl SQLiteDatabase {
+
+    /// Opens the SQLite database at `file_path`, creating it and its tables if needed.
+    ///
+    /// Tables created by an older version of the CLI are upgraded to the current schema.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if:
+    /// * The file cannot be opened as an SQLite database.
+    /// * The tables were created by a newer version of the CLI, or cannot be upgraded.
+    pub fn open(file_path: String) -> Result<Self> {
+        let connection = Connection::open(&file_path)?;
+
+        connection.busy_timeout(LOCK_TIMEOUT)?;
+        connection.execute_batch("PRAGMA foreign_keys = ON;")?;
+        Self::migrate(&connection)?;
+
+        Ok(Self { file_path, connection })
+    }
+
+    /// Applies every schema migration the database has not gone through yet.
+    fn migrate(connection: &Connection) -> Result<()> {
+        let version: u32 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+
+        if version > SQL_SCHEMA_VERSION {
+            return Err(anyhow!("Database has schema version {}, but this version of jira-cli only supports up to {}. Please upgrade jira-cli",
+                               version, SQL_SCHEMA_VERSION));
+        }
+
+        for (from_version, migration) in SQL_MIGRATIONS.iter().enumerate().skip(version as usize) {
+            let transaction = connection.unchecked_transaction()?;
+
+            transaction.execute_batch(migration)
+                .with_context(|| format!("Failed to migrate database from schema version {}", from_version))?;
+            transaction.pragma_update(None, "user_version", from_version as u32 + 1)?;
+
+            transaction.commit()?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the whole database state through the given connection or transaction.
+    fn read_state(connection: &Connection) -> Result<DBState> {
+        let last_item_id = Self::read_meta(connection, "last_item_id")?;
+        let last_comment_id = Self::read_meta(connection, "last_comment_id")?;
+        let last_sprint_id = Self::read_meta(connection, "last_sprint_id")?;
+
+        let mut epics = HashMap::new();
+        let mut statement = connection.prepare("SELECT id, name, description, status, priority, owner, created_at, updated_at, due_date FROM epics")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let status: String = row.get(3)?;
+            let priority: String = row.get(4)?;
+            let epic = Epic { name: row.get(1)?, description: row.get(2)?, status: status.parse()?, priority: priority.parse()?,
+                              owner: row.get(5)?, labels: vec![], created_at: row.get(6)?, updated_at: row.get(7)?, due_date: row.get(8)?,
+                              stories: vec![], links: vec![] };
+            epics.insert(row.get(0)?, epic);
+        }
+
+        let mut stories = HashMap::new();
+        let mut statement = connection.prepare(
+            "SELECT id, name, description, status, priority, assignee, created_at, updated_at, due_date, story_points, issue_type,
+                    steps_to_reproduce, severity, affected_version FROM stories")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let status: String = row.get(3)?;
+            let priority: String = row.get(4)?;
+            let issue_type: String = row.get(10)?;
+            let severity: Option<String> = row.get(12)?;
+            let story = Story { name: row.get(1)?, description: row.get(2)?, status: status.parse()?, priority: priority.parse()?,
+                                assignee: row.get(5)?, labels: vec![], created_at: row.get(6)?, updated_at: row.get(7)?, due_date: row.get(8)?,
+                                story_points: row.get(9)?, subtasks: vec![], issue_type: issue_type.parse()?, steps_to_reproduce: row.get(11)?,
+                                severity: severity.map(|severity| severity.parse()).transpose()?, affected_version: row.get(13)?,
+                                links: vec![] };
+            stories.insert(row.get(0)?, story);
+        }
+
+        let mut subtasks = HashMap::new();
+        let mut statement = connection.prepare("SELECT id, name, status, created_at, updated_at FROM subtasks")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let status: String = row.get(2)?;
+            let subtask = Subtask { name: row.get(1)?, status: status.parse()?, created_at: row.get(3)?, updated_at: row.get(4)? };
+            subtasks.insert(row.get(0)?, subtask);
+        }
+
+        let mut statement = connection.prepare("SELECT story_id, subtask_id FROM story_subtasks ORDER BY story_id, position")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let story_id: u32 = row.get(0)?;
+
+            if let Some(story) = stories.get_mut(&story_id) {
+                story.subtasks.push(row.get(1)?);
+            }
+        }
+
+        let mut statement = connection.prepare("SELECT epic_id, label FROM epic_labels ORDER BY epic_id, label")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let epic_id: u32 = row.get(0)?;
+
+            if let Some(epic) = epics.get_mut(&epic_id) {
+                epic.labels.push(row.get(1)?);
+            }
+        }
+
+        let mut statement = connection.prepare("SELECT story_id, label FROM story_labels ORDER BY story_id, label")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let story_id: u32 = row.get(0)?;
+
+            if let Some(story) = stories.get_mut(&story_id) {
+                story.labels.push(row.get(1)?);
+            }
+        }
+
+        let mut statement = connection.prepare("SELECT epic_id, story_id FROM epic_stories ORDER BY epic_id, position")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let epic_id: u32 = row.get(0)?;
+
+            if let Some(epic) = epics.get_mut(&epic_id) {
+                epic.stories.push(row.get(1)?);
+            }
+        }
+
+        let mut statement = connection.prepare("SELECT item_id, link_type, target_id FROM item_links ORDER BY item_id, position")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let item_id: u32 = row.get(0)?;
+            let link_type: String = row.get(1)?;
+            let link = Link { link_type: link_type.parse()?, item_id: row.get(2)? };
+
+            if let Some(epic) = epics.get_mut(&item_id) {
+                epic.links.push(link);
+            } else if let Some(story) = stories.get_mut(&item_id) {
+                story.links.push(link);
+            }
+        }
+
+        let mut users = HashMap::new();
+        let mut statement = connection.prepare("SELECT username, name FROM users")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            users.insert(row.get(0)?, User { name: row.get(1)? });
+        }
+
+        let mut comments = HashMap::new();
+        let mut statement = connection.prepare("SELECT id, item_id, author, created_at, updated_at, body FROM comments")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let comment = Comment { item_id: row.get(1)?, author: row.get(2)?, created_at: row.get(3)?, updated_at: row.get(4)?, body: row.get(5)? };
+            comments.insert(row.get(0)?, comment);
+        }
+
+        let mut sprints = HashMap::new();
+        let mut statement = connection.prepare("SELECT id, name, goal, start_date, end_date, state FROM sprints")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let state: String = row.get(5)?;
+            let sprint = Sprint { name: row.get(1)?, goal: row.get(2)?, start_date: row.get(3)?, end_date: row.get(4)?, state: state.parse()?,
+                                  stories: vec![] };
+            sprints.insert(row.get(0)?, sprint);
+        }
+
+        let mut statement = connection.prepare("SELECT sprint_id, story_id FROM sprint_stories ORDER BY sprint_id, position")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let sprint_id: u32 = row.get(0)?;
+
+            if let Some(sprint) = sprints.get_mut(&sprint_id) {
+                sprint.stories.push(row.get(1)?);
+            }
+        }
+
+        let mut filters = HashMap::new();
+        let mut statement = connection.prepare("SELECT name, query FROM filters")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            filters.insert(row.get(0)?, SavedFilter { query: row.get(1)? });
+        }
+
+        let mut history = History::default();
+        let mut statement = connection.prepare("SELECT stack, changes FROM revisions ORDER BY id")?;
+        let mut rows = statement.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let stack: String = row.get(0)?;
+            let changes: String = row.get(1)?;
+            let revision = Revision { changes: serde_json::from_str(&changes)? };
+
+            match stack.as_str() {
+                "undo" => history.undo.push(revision),
+                _ => history.redo.push(revision)
+            }
+        }
+
+        let mut change_log = vec![];
+        let mut statement = connection.prepare(
+            "SELECT timestamp, actor, collection, entity_id, field, old_value, new_value
+             FROM (SELECT * FROM change_log ORDER BY id DESC LIMIT ?1) ORDER BY id")?;
+        let mut rows = statement.query(params![CHANGE_LOG_LIMIT as i64])?;
+
+        while let Some(row) = rows.next()? {
+            let old_value: Option<String> = row.get(5)?;
+            let new_value: Option<String> = row.get(6)?;
+
+            change_log.push(ChangeEvent {
+                timestamp: row.get(0)?,
+                actor: row.get(1)?,
+                collection: row.get(2)?,
+                id: row.get(3)?,
+                field: row.get(4)?,
+                old_value: old_value.map(|value| serde_json::from_str(&value)).transpose()?,
+                new_value: new_value.map(|value| serde_json::from_str(&value)).transpose()?
+            });
+        }
+
+        Ok(DBState { last_item_id, epics, stories, subtasks, users, last_comment_id, comments, last_sprint_id, sprints, filters, history, change_log,
+                     ..DBState::default() })
+    }
+
+    /// Reads a counter of the `meta` table, which is 0 until it is first written.
+    fn read_meta(connection: &Connection, key: &str) -> Result<u32> {
+        let value = connection
+            .query_row("SELECT value FROM meta WHERE key = ?1", params![key], |row| row.get(0))
+            .optional()?
+            .unwrap_or(0);
+
+        Ok(value)
+    }
+
+    /// Writes the differences between `current` and `db_state` within the given transaction.
+    fn write_diff(transaction: &Transaction, current: &DBState, db_state: &DBState) -> Result<()> {
+        let counters = [("last_item_id", current.last_item_id, db_state.last_item_id),
+                        ("last_comment_id", current.last_comment_id, db_state.last_comment_id),
+                        ("last_sprint_id", current.last_sprint_id, db_state.last_sprint_id)];
+
+        for (key, current_value, value) in counters {
+            if current_value != value {
+                transaction.execute(
+                    "INSERT INTO meta (key, value) VALUES (?1, ?2)
+                     ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                    params![key, value])?;
+            }
+        }
+
+        for id in current.epics.keys().filter(|id| !db_state.epics.contains_key(id)) {
+            transaction.execute("DELETE FROM epics WHERE id = ?1", params![id])?;
+            transaction.execute("DELETE FROM item_links WHERE item_id = ?1", params![id])?;
+        }
+
+        for id in current.stories.keys().filter(|id| !db_state.stories.contains_key(id)) {
+            transaction.execute("DELETE FROM stories WHERE id = ?1", params![id])?;
+            transaction.execute("DELETE FROM item_links WHERE item_id = ?1", params![id])?;
+        }
+
+        for id in current.subtasks.keys().filter(|id| !db_state.subtasks.contains_key(id)) {
+            transaction.execute("DELETE FROM subtasks WHERE id = ?1", params![id])?;
+        }
+
+        for (id, subtask) in &db_state.subtasks {
+            if current.subtasks.get(id) != Some(subtask) {
+                transaction.execute(
+                    "INSERT INTO subtasks (id, name, status, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)
+                     ON CONFLICT(id) DO UPDATE SET name = excluded.name, status = excluded.status, created_at = excluded.created_at,
+                                                   updated_at = excluded.updated_at",
+                    params![id, subtask.name, subtask.status.to_string(), subtask.created_at, subtask.updated_at])?;
+            }
+        }
+
+        for (id, story) in &db_state.stories {
+            let current_story = current.stories.get(id);
+
+            if current_story != Some(story) {
+                transaction.execute(
+                    "INSERT INTO stories (id, name, description, status, priority, assignee, created_at, updated_at, due_date, story_points,
+                                          issue_type, steps_to_reproduce, severity, affected_version)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+                     ON CONFLICT(id) DO UPDATE SET name = excluded.name, description = excluded.description, status = excluded.status,
+                                                   priority = excluded.priority, assignee = excluded.assignee, created_at = excluded.created_at,
+                                                   updated_at = excluded.updated_at, due_date = excluded.due_date,
+                                                   story_points = excluded.story_points, issue_type = excluded.issue_type,
+                                                   steps_to_reproduce = excluded.steps_to_reproduce, severity = excluded.severity,
+                                                   affected_version = excluded.affected_version",
+                    params![id, story.name, story.description, story.status.to_string(), story.priority.to_string(), story.assignee,
+                            story.created_at, story.updated_at, story.due_date, story.story_points, story.issue_type.to_string(),
+                            story.steps_to_reproduce, story.severity.map(|severity| severity.to_string()), story.affected_version])?;
+
+                if current_story.map(|current_story| &current_story.subtasks) != Some(&story.subtasks) {
+                    transaction.execute("DELETE FROM story_subtasks WHERE story_id = ?1", params![id])?;
+
+                    for (position, subtask_id) in story.subtasks.iter().enumerate() {
+                        transaction.execute(
+                            "INSERT INTO story_subtasks (story_id, subtask_id, position) VALUES (?1, ?2, ?3)",
+                            params![id, subtask_id, position as i64])?;
+                    }
+                }
+
+                if current_story.map(|current_story| &current_story.labels) != Some(&story.labels) {
+                    transaction.execute("DELETE FROM story_labels WHERE story_id = ?1", params![id])?;
+
+                    for label in &story.labels {
+                        transaction.execute("INSERT INTO story_labels (story_id, label) VALUES (?1, ?2)", params![id, label])?;
+                    }
+                }
+
+                if current_story.map(|current_story| &current_story.links) != Some(&story.links) {
+                    Self::write_links(transaction, *id, &story.links)?;
+                }
+            }
+        }
+
+        for (id, epic) in &db_state.epics {
+            let current_epic = current.epics.get(id);
+
+            if current_epic == Some(epic) {
+                continue;
+            }
+
+            transaction.execute(
+                "INSERT INTO epics (id, name, description, status, priority, owner, created_at, updated_at, due_date)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT(id) DO UPDATE SET name = excluded.name, description = excluded.description, status = excluded.status,
+                                               priority = excluded.priority, owner = excluded.owner, created_at = excluded.created_at,
+                                               updated_at = excluded.updated_at, due_date = excluded.due_date",
+                params![id, epic.name, epic.description, epic.status.to_string(), epic.priority.to_string(), epic.owner,
+                        epic.created_at, epic.updated_at, epic.due_date])?;
+
+            if current_epic.map(|current_epic| &current_epic.stories) != Some(&epic.stories) {
+                transaction.execute("DELETE FROM epic_stories WHERE epic_id = ?1", params![id])?;
+
+                for (position, story_id) in epic.stories.iter().enumerate() {
+                    transaction.execute(
+                        "INSERT INTO epic_stories (epic_id, story_id, position) VALUES (?1, ?2, ?3)",
+                        params![id, story_id, position as i64])?;
+                }
+            }
+
+            if current_epic.map(|current_epic| &current_epic.labels) != Some(&epic.labels) {
+                transaction.execute("DELETE FROM epic_labels WHERE epic_id = ?1", params![id])?;
+
+                for label in &epic.labels {
+                    transaction.execute("INSERT INTO epic_labels (epic_id, label) VALUES (?1, ?2)", params![id, label])?;
+                }
+            }
+
+            if current_epic.map(|current_epic| &current_epic.links) != Some(&epic.links) {
+                Self::write_links(transaction, *id, &epic.links)?;
+            }
+        }
+
+        for username in current.users.keys().filter(|username| !db_state.users.contains_key(*username)) {
+            transaction.execute("DELETE FROM users WHERE username = ?1", params![username])?;
+        }
+
+        for (username, user) in &db_state.users {
+            if current.users.get(username) != Some(user) {
+                transaction.execute(
+                    "INSERT INTO users (username, name) VALUES (?1, ?2)
+                     ON CONFLICT(username) DO UPDATE SET name = excluded.name",
+                    params![username, user.name])?;
+            }
+        }
+
+        for id in current.comments.keys().filter(|id| !db_state.comments.contains_key(id)) {
+            transaction.execute("DELETE FROM comments WHERE id = ?1", params![id])?;
+        }
+
+        for (id, comment) in &db_state.comments {
+            if current.comments.get(id) != Some(comment) {
+                transaction.execute(
+                    "INSERT INTO comments (id, item_id, author, created_at, updated_at, body) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                     ON CONFLICT(id) DO UPDATE SET item_id = excluded.item_id, author = excluded.author, created_at = excluded.created_at,
+                                                   updated_at = excluded.updated_at, body = excluded.body",
+                    params![id, comment.item_id, comment.author, comment.created_at, comment.updated_at, comment.body])?;
+            }
+        }
+
+        for id in current.sprints.keys().filter(|id| !db_state.sprints.contains_key(id)) {
+            transaction.execute("DELETE FROM sprints WHERE id = ?1", params![id])?;
+        }
+
+        for (id, sprint) in &db_state.sprints {
+            let current_sprint = current.sprints.get(id);
+
+            if current_sprint == Some(sprint) {
+                continue;
+            }
+
+            transaction.execute(
+                "INSERT INTO sprints (id, name, goal, start_date, end_date, state) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(id) DO UPDATE SET name = excluded.name, goal = excluded.goal, start_date = excluded.start_date,
+                                               end_date = excluded.end_date, state = excluded.state",
+                params![id, sprint.name, sprint.goal, sprint.start_date, sprint.end_date, sprint.state.to_string()])?;
+
+            if current_sprint.map(|current_sprint| &current_sprint.stories) != Some(&sprint.stories) {
+                transaction.execute("DELETE FROM sprint_stories WHERE sprint_id = ?1", params![id])?;
+
+                for (position, story_id) in sprint.stories.iter().enumerate() {
+                    transaction.execute(
+                        "INSERT INTO sprint_stories (sprint_id, story_id, position) VALUES (?1, ?2, ?3)",
+                        params![id, story_id, position as i64])?;
+                }
+            }
+        }
+
+        for name in current.filters.keys().filter(|name| !db_state.filters.contains_key(*name)) {
+            transaction.execute("DELETE FROM filters WHERE name = ?1", params![name])?;
+        }
+
+        for (name, filter) in &db_state.filters {
+            if current.filters.get(name) != Some(filter) {
+                transaction.execute(
+                    "INSERT INTO filters (name, query) VALUES (?1, ?2)
+                     ON CONFLICT(name) DO UPDATE SET query = excluded.query",
+                    params![name, filter.query])?;
+            }
+        }
+
+        if current.history != db_state.history {
+            transaction.execute("DELETE FROM revisions", [])?;
+
+            let revisions = db_state.history.undo.iter().map(|revision| ("undo", revision))
+                .chain(db_state.history.redo.iter().map(|revision| ("redo", revision)));
+
+            for (stack, revision) in revisions {
+                transaction.execute(
+                    "INSERT INTO revisions (stack, changes) VALUES (?1, ?2)",
+                    params![stack, serde_json::to_string(&revision.changes)?])?;
+            }
+        }
+
+        // Both logs are capped, so the new events are the ones after the last event already stored
+        let new_events = current.change_log.last()
+            .and_then(|last| db_state.change_log.iter().rposition(|event| event == last))
+            .map_or(0, |position| position + 1);
+
+        for event in db_state.change_log.iter().skip(new_events) {
+            transaction.execute(
+                "INSERT INTO change_log (timestamp, actor, collection, entity_id, field, old_value, new_value)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![event.timestamp, event.actor, event.collection, event.id, event.field,
+                        event.old_value.as_ref().map(Value::to_string), event.new_value.as_ref().map(Value::to_string)])?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the links of the Epic or Story with the given ID.
+    fn write_links(transaction: &Transaction, item_id: u32, links: &[Link]) -> Result<()> {
+        transaction.execute("DELETE FROM item_links WHERE item_id = ?1", params![item_id])?;
+
+        for (position, link) in links.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO item_links (item_id, link_type, target_id, position) VALUES (?1, ?2, ?3, ?4)",
+                params![item_id, link.link_type.to_string(), link.item_id, position as i64])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Database for SQLiteDatabase {
+
+    /// Returns the path to the SQLite file of the database
+    fn get_file_path(&self) -> &str {
+        &self.file_path
+    }
+
+    /// Reads the database state from the SQLite file.
+    ///
+    /// This method reads the epics, stories, sub-tasks, users, comments, links, sprints, saved
+    /// filters, the undo and redo stacks and the change log from their tables and assembles them
+    /// into a `DBState`.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if the tables cannot be queried or contain an unknown status.
+    fn read_db(&self) -> Result<DBState> {
+        Self::read_state(&self.connection)
+    }
+
+    /// Writes the database state to the SQLite file.
+    ///
+    /// This method compares the provided state with the one currently stored and, within a
+    /// single transaction, only inserts, updates or deletes the rows that differ.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if the transaction cannot be committed.
+    fn write_db(&self, db_state: &DBState) -> Result<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+
+        let current = Self::read_state(&transaction)?;
+        Self::write_diff(&transaction, &current, db_state)?;
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Writes the changes made to the database state since it was read to the SQLite file.
+    ///
+    /// Unlike `write_db`, this method trusts `current` to be the stored state, which holds as
+    /// long as the database is locked in between, and does not read it again before only
+    /// inserting, updating or deleting the rows that differ within a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// This method can return an error if the transaction cannot be committed.
+    fn write_changes(&self, current: &DBState, db_state: &DBState) -> Result<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+
+        Self::write_diff(&transaction, current, db_state)?;
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Acquires an exclusive lock on the SQLite file.
+    ///
+    /// SQLite only locks the database for the duration of a single transaction, so the lock
+    /// is held on a `.lock` file next to the database, in the same way as `JSONFileDatabase`.
+    fn lock(&self) -> Result<DBLock> {
+        lock_file(&format!("{}.lock", self.file_path), LOCK_TIMEOUT)
+    }
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::JiraDatabase;
+    use crate::models::{IssueType, LinkType, Priority, Severity, Sprint, Status};
+    use chrono::{NaiveDate, Utc};
+
+    fn open_tmp_db(tmpdir: &tempfile::TempDir) -> SQLiteDatabase {
+        let file_path = tmpdir.path().join("db.sqlite").to_str().unwrap().to_owned();
+        SQLiteDatabase::open(file_path).unwrap()
+    }
+
+    #[test]
+    fn read_db_should_return_empty_state_for_new_database() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db = open_tmp_db(&tmpdir);
+
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state, DBState::default());
+    }
+
+    #[test]
+    fn open_should_create_tables_once() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db = open_tmp_db(&tmpdir);
+        drop(db);
+
+        let db = open_tmp_db(&tmpdir);
+        let version: u32 = db.connection.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+
+        assert_eq!(version, SQL_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn open_should_migrate_tables_created_without_version() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let file_path = tmpdir.path().join("db.sqlite").to_str().unwrap().to_owned();
+
+        let connection = Connection::open(&file_path).unwrap();
+        connection.execute_batch(SQL_MIGRATIONS[0]).unwrap();
+        connection.execute("INSERT INTO epics (id, name, description, status) VALUES (1, 'epic', '', 'OPEN')", []).unwrap();
+        drop(connection);
+
+        let db = SQLiteDatabase::open(file_path).unwrap();
+        let version: u32 = db.connection.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+
+        assert_eq!(version, SQL_SCHEMA_VERSION);
+        assert_eq!(db.read_db().unwrap().epics.get(&1).unwrap().name, "epic");
+    }
+
+    #[test]
+    fn open_should_fail_for_newer_schema_version() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db = open_tmp_db(&tmpdir);
+        db.connection.pragma_update(None, "user_version", SQL_SCHEMA_VERSION + 1).unwrap();
+        let file_path = db.file_path.clone();
+        drop(db);
+
+        assert!(SQLiteDatabase::open(file_path).is_err());
+    }
+
+    #[test]
+    fn write_db_should_work() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db = open_tmp_db(&tmpdir);
+
+        let story = Story { status: Status::InProgress, priority: Priority::High, assignee: Some("alice".to_owned()), labels: vec!["api".to_owned(), "backend".to_owned()], story_points: Some(5), ..Story::new("story 1".to_owned(), "story 1".to_owned()) };
+        let other_story = Story::new("story 2".to_owned(), "story 2".to_owned());
+        let epic = Epic { priority: Priority::Lowest, owner: Some("alice".to_owned()), labels: vec!["backend".to_owned()], due_date: NaiveDate::from_ymd_opt(2024, 12, 31), created_at: Some(Utc::now()), stories: vec![3, 2], ..Epic::new("epic 1".to_owned(), "epic 1".to_owned()) };
+
+        let mut stories = HashMap::new();
+        stories.insert(2, story);
+        stories.insert(3, other_story);
+
+        let mut epics = HashMap::new();
+        epics.insert(1, epic);
+
+        let mut users = HashMap::new();
+        users.insert("alice".to_owned(), User { name: "Alice Smith".to_owned() });
+
+        let state = DBState { last_item_id: 3, epics, stories, users, ..DBState::default() };
+
+        assert!(db.write_db(&state).is_ok());
+        assert_eq!(db.read_db().unwrap(), state);
+    }
+
+    #[test]
+    fn write_changes_should_only_write_the_differences() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db = open_tmp_db(&tmpdir);
+
+        let mut current = DBState { last_item_id: 1, ..DBState::default() };
+        current.epics.insert(1, Epic::new("epic 1".to_owned(), "".to_owned()));
+        db.write_db(&current).unwrap();
+
+        let mut db_state = current.clone();
+        db_state.last_item_id = 2;
+        db_state.epics.insert(2, Epic::new("epic 2".to_owned(), "".to_owned()));
+
+        // Epic 1 is not part of the changes, so its row must be left alone
+        db.connection.execute("UPDATE epics SET name = 'renamed' WHERE id = 1", []).unwrap();
+        db.write_changes(&current, &db_state).unwrap();
+
+        let stored = db.read_db().unwrap();
+
+        assert_eq!(stored.last_item_id, 2);
+        assert_eq!(stored.epics.get(&1).unwrap().name, "renamed");
+        assert_eq!(stored.epics.get(&2), db_state.epics.get(&2));
+    }
+
+    #[test]
+    fn write_db_should_persist_across_connections() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let story_id = jira_db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+        jira_db.update_story_status(story_id, Status::Closed).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+
+        assert_eq!(db.read_db().unwrap(), state);
+    }
+
+    #[test]
+    fn write_db_should_delete_removed_rows() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = jira_db.create_epic(Epic::new("other epic".to_owned(), "".to_owned())).unwrap();
+        let story_id = jira_db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = jira_db.create_story(Story::new("other story".to_owned(), "".to_owned()), other_epic_id).unwrap();
+
+        jira_db.delete_story(epic_id, story_id).unwrap();
+        jira_db.delete_epic(other_epic_id).unwrap();
+
+        let db_state = jira_db.read_db().unwrap();
+
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories, Vec::<u32>::new());
+        assert_eq!(db_state.epics.get(&other_epic_id), None);
+        assert_eq!(db_state.stories.get(&story_id), None);
+        assert_eq!(db_state.stories.get(&other_story_id), None);
+        assert_eq!(db_state.last_item_id, 4);
+    }
+
+    #[test]
+    fn write_db_should_persist_subtasks() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let story_id = jira_db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+        let subtask_id = jira_db.create_subtask(Subtask::new("first".to_owned()), story_id).unwrap();
+        let other_subtask_id = jira_db.create_subtask(Subtask::new("second".to_owned()), story_id).unwrap();
+        jira_db.update_subtask_status(other_subtask_id, Status::Resolved).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+
+        assert_eq!(jira_db.read_db().unwrap(), state);
+        assert_eq!(state.stories.get(&story_id).unwrap().subtasks, vec![subtask_id, other_subtask_id]);
+
+        jira_db.delete_story(epic_id, story_id).unwrap();
+        assert!(jira_db.read_db().unwrap().subtasks.is_empty());
+    }
+
+    #[test]
+    fn write_db_should_persist_issue_types() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let bug = Story {
+            issue_type: IssueType::Bug,
+            steps_to_reproduce: Some("open the app".to_owned()),
+            severity: Some(Severity::Critical),
+            affected_version: Some("1.0.0".to_owned()),
+            ..Story::new("bug".to_owned(), "".to_owned())
+        };
+        jira_db.create_story(bug, epic_id).unwrap();
+        jira_db.create_story(Story { issue_type: IssueType::Spike, ..Story::new("spike".to_owned(), "".to_owned()) }, epic_id).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+
+        assert_eq!(db.read_db().unwrap(), state);
+    }
+
+    #[test]
+    fn write_db_should_persist_links() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let story_id = jira_db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = jira_db.create_story(Story::new("other".to_owned(), "".to_owned()), epic_id).unwrap();
+        jira_db.add_link(story_id, LinkType::Blocks, other_story_id).unwrap();
+        jira_db.add_link(story_id, LinkType::RelatesTo, epic_id).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+
+        assert_eq!(jira_db.read_db().unwrap(), state);
+
+        jira_db.delete_story(epic_id, other_story_id).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+
+        assert_eq!(db.read_db().unwrap(), state);
+        assert_eq!(state.stories.get(&story_id).unwrap().links.len(), 1);
+    }
+
+    #[test]
+    fn write_db_should_persist_sprints() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let story_id = jira_db.create_story(Story::new("story".to_owned(), "".to_owned()), epic_id).unwrap();
+        let other_story_id = jira_db.create_story(Story::new("other".to_owned(), "".to_owned()), epic_id).unwrap();
+        let sprint_id = jira_db.create_sprint(Sprint::new("Sprint 1".to_owned(), "Ship it".to_owned(), date(3), date(14))).unwrap();
+        let next_sprint_id = jira_db.create_sprint(Sprint::new("Sprint 2".to_owned(), "".to_owned(), date(17), date(28))).unwrap();
+        jira_db.add_story_to_sprint(sprint_id, story_id).unwrap();
+        jira_db.add_story_to_sprint(sprint_id, other_story_id).unwrap();
+        jira_db.start_sprint(sprint_id).unwrap();
+        jira_db.complete_sprint(sprint_id).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+
+        assert_eq!(jira_db.read_db().unwrap(), state);
+        assert_eq!(state.sprints.get(&next_sprint_id).unwrap().stories, vec![story_id, other_story_id]);
+
+        jira_db.delete_story(epic_id, story_id).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+
+        assert_eq!(db.read_db().unwrap(), state);
+        assert_eq!(state.last_sprint_id, next_sprint_id);
+    }
+
+    #[test]
+    fn write_db_should_persist_filters() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        jira_db.save_filter("my open bugs", "type = bug AND status = open").unwrap();
+        jira_db.save_filter("big stories", "points > 5").unwrap();
+        jira_db.save_filter("my open bugs", "type = bug").unwrap();
+        jira_db.delete_filter("big stories").unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state, state);
+        assert_eq!(db_state.filters.len(), 1);
+        assert_eq!(db_state.filters["my open bugs"].query, "type = bug".to_owned());
+    }
+
+    #[test]
+    fn write_db_should_persist_comments() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        let comment_id = jira_db.add_comment(epic_id, "first").unwrap();
+        jira_db.add_comment(epic_id, "second").unwrap();
+        jira_db.edit_comment(comment_id, "edited").unwrap();
+        jira_db.delete_comment(comment_id + 1).unwrap();
+        let state = jira_db.read_db().unwrap();
+        drop(jira_db);
+
+        let db = open_tmp_db(&tmpdir);
+        let db_state = db.read_db().unwrap();
+
+        assert_eq!(db_state, state);
+        assert_eq!(db_state.comments.len(), 1);
+        assert_eq!(db_state.last_comment_id, 2);
+    }
+
+    #[test]
+    fn read_db_should_only_load_latest_change_log_events() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+
+        let old_event = ChangeEvent { timestamp: Utc::now(), actor: "bob".to_owned(), collection: "epics".to_owned(), id: "0".to_owned(),
+                                      field: "name".to_owned(), old_value: None, new_value: None };
+        jira_db.database.write_db(&DBState { change_log: vec![old_event; CHANGE_LOG_LIMIT], ..DBState::default() }).unwrap();
+
+        jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+
+        let db = open_tmp_db(&tmpdir);
+        let stored: u32 = db.connection.query_row("SELECT COUNT(*) FROM change_log", [], |row| row.get(0)).unwrap();
+        let change_log = db.read_db().unwrap().change_log;
+
+        assert_eq!(stored as usize, CHANGE_LOG_LIMIT + 1);
+        assert_eq!(change_log.len(), CHANGE_LOG_LIMIT);
+        assert_eq!(change_log.last().unwrap().field, "created");
+    }
+
+    #[test]
+    fn write_db_should_persist_history() {
+        let tmpdir = tempfile::tempdir().unwrap();
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let epic_id = jira_db.create_epic(Epic::new("epic".to_owned(), "".to_owned())).unwrap();
+        jira_db.update_epic_status(epic_id, Status::Closed).unwrap();
+        jira_db.undo().unwrap();
+        drop(jira_db);
+
+        let jira_db = JiraDatabase::from_database(Box::new(open_tmp_db(&tmpdir)));
+        let db_state = jira_db.read_db().unwrap();
+
+        assert_eq!(db_state.history.undo.len(), 1);
+        assert_eq!(db_state.history.redo.len(), 1);
+
+        jira_db.redo().unwrap();
+
+        assert_eq!(jira_db.read_db().unwrap().epics.get(&epic_id).unwrap().status, Status::Closed);
+    }
+}
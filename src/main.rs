@@ -3,13 +3,65 @@ mod db;
 mod ui;
 mod io_utils;
 mod navigator;
+mod config;
+mod cli;
+mod output;
+mod query;
 
+use std::io;
+use std::process::ExitCode;
 use std::rc::Rc;
+use clap::Parser;
 use db::*;
 use io_utils::*;
 use navigator::*;
+use config::*;
+use cli::*;
 use colored::Colorize;
 
+/// Entry point of the JIRA-like CLI application.
+///
+/// This function parses the command line arguments, loads the configuration and opens the
+/// database selected by `--db` or the config file. It then runs the given subcommand, or
+/// starts the interactive mode if there is none.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match Config::load(CONFIG_FILE_PATH) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{} {:#}", "Error loading config:".red(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let location = cli.db.unwrap_or_else(|| config.database.clone());
+
+    let db = match JiraDatabase::open(&location) {
+        Ok(db) => Rc::new(JiraDatabase { config, ..db }),
+        Err(error) => {
+            eprintln!("{} {:#}. Location: {}", "Error opening database:".red(), error, location);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Some(command) => {
+            if let Err(error) = run_command(command, &db, cli.output, &mut io::stdout()) {
+                eprintln!("{} {:#}", "Error:".red(), error);
+
+                return match error.downcast_ref::<CommandError>() {
+                    Some(error) => ExitCode::from(error.exit_code),
+                    None => ExitCode::FAILURE
+                };
+            }
+        }
+        None => run_interactive(db)
+    }
+
+    ExitCode::SUCCESS
+}
+
 /// Main execution loop for the JIRA-like CLI application.
 ///
 /// This function serves as the main entry point for the JIRA-like CLI application.
@@ -18,9 +70,7 @@ use colored::Colorize;
 /// If an error occurs during page rendering, user input retrieval, or action handling,
 /// it displays an error message and waits for the user to press any key to continue.
 ///
-/// // The main entry point of the application
-fn main() {
-    let db = Rc::new(JiraDatabase::new("./data/db.json".to_owned()));
+fn run_interactive(db: Rc<JiraDatabase>) {
     let mut navigator = Navigator::new(Rc::clone(&db));
     
     loop {
@@ -28,7 +78,7 @@ fn main() {
 
         if let Some(page) = navigator.get_current_page() {
             if let Err(error) = page.draw_page() {
-                println!("{} {}. File: {}\nPress any key to continue or CTRL+C to quit.", "Error rendering page:".red(), error, db.database.get_file_path());
+                println!("{} {:#}. File: {}\nPress any key to continue or CTRL+C to quit.", "Error rendering page:".red(), error, db.database.get_file_path());
                 wait_for_key_press();
             };
 
@@ -36,13 +86,13 @@ fn main() {
 
             match page.handle_input(user_input.trim()) {
                 Err(error) => {
-                    println!("{} {}\nPress any key to continue...", "Error getting user input:".red(), error);
+                    println!("{} {:#}\nPress any key to continue...", "Error getting user input:".red(), error);
                     wait_for_key_press();
                 }
                 Ok(action) => {
                     if let Some(action) = action {
                         if let Err(error) = navigator.handle_action(action) {
-                            println!("{} {}\nPress any key to continue...", "Error handling processing user input:".red(), error);
+                            println!("{} {:#}\nPress any key to continue...", "Error handling processing user input:".red(), error);
                             wait_for_key_press();
                         }
                     }
@@ -1,6 +1,12 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{collections::HashMap, fmt::Display, ops::Range, str::FromStr};
 
+use anyhow::anyhow;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::Workflow;
+use crate::db::CURRENT_SCHEMA_VERSION;
 
 /// Represents actions that can be triggered in the user interface.
 ///
@@ -32,18 +38,98 @@ pub enum Action {
     /// Update the status of an Epic identified by its ID.
     UpdateEpicStatus { epic_id: u32 },
 
+    /// Edit the name, description, priority, labels and due date of an Epic identified by its ID.
+    EditEpic { epic_id: u32 },
+
     /// Delete an Epic identified by its ID.
     DeleteEpic { epic_id: u32 },
 
     /// Create a new Story within an Epic identified by its ID.
     CreateStory { epic_id: u32 },
 
-    /// Update the status of a Story identified by its ID.
-    UpdateStoryStatus { story_id: u32 },
+    /// Update the status of a Story identified by its ID, to the given status or to a status
+    /// chosen by the user if there is none.
+    UpdateStoryStatus { story_id: u32, status: Option<Status> },
+
+    /// Edit the name, description, issue type, priority, labels, due date, story points and bug
+    /// fields of a Story identified by its ID.
+    EditStory { story_id: u32 },
 
     /// Delete a Story within an Epic identified by their IDs.
     DeleteStory { epic_id: u32, story_id: u32 },
 
+    /// Move a Story out of the Epic identified by their IDs, into an Epic chosen by the user.
+    MoveStory { epic_id: u32, story_id: u32 },
+
+    /// Assign a Story identified by its ID to a user.
+    AssignStory { story_id: u32 },
+
+    /// Set the owner of an Epic identified by its ID.
+    AssignEpic { epic_id: u32 },
+
+    /// Undo the last change made to the database.
+    Undo,
+
+    /// Redo the last change that was undone.
+    Redo,
+
+    /// Navigate to the detail page of a Sub-task within a Story identified by their IDs.
+    NavigateToSubtaskDetail { story_id: u32, subtask_id: u32 },
+
+    /// Create a new Sub-task within a Story identified by its ID.
+    CreateSubtask { story_id: u32 },
+
+    /// Update the status of a Sub-task identified by its ID.
+    UpdateSubtaskStatus { subtask_id: u32 },
+
+    /// Delete a Sub-task within a Story identified by their IDs.
+    DeleteSubtask { story_id: u32, subtask_id: u32 },
+
+    /// Navigate to the change history of an Epic, a Story or a Sub-task identified by its ID.
+    NavigateToHistory { item_id: u32 },
+
+    /// Add a comment to an Epic or a Story identified by its ID.
+    AddComment { item_id: u32 },
+
+    /// Link an Epic or a Story identified by its ID to another item chosen by the user.
+    AddLink { item_id: u32 },
+
+    /// Remove a link of an Epic or a Story identified by its ID, chosen by the user.
+    RemoveLink { item_id: u32 },
+
+    /// Navigate to the board of the stories of an Epic identified by its ID, or of every Epic.
+    NavigateToBoard { epic_id: Option<u32> },
+
+    /// Navigate to the epics and stories whose name, description or comments contain the query.
+    NavigateToSearch { query: String },
+
+    /// Navigate to the stories matching a query, see `Query` for the language.
+    NavigateToQuery { query: String },
+
+    /// Save a query under a name chosen by the user.
+    SaveFilter { query: String },
+
+    /// Navigate to the list of sprints.
+    NavigateToSprints,
+
+    /// Navigate to the detail page of a Sprint identified by its ID.
+    NavigateToSprintDetail { sprint_id: u32 },
+
+    /// Create a new Sprint.
+    CreateSprint,
+
+    /// Add a Story chosen by the user to a Sprint identified by its ID.
+    AddStoryToSprint { sprint_id: u32 },
+
+    /// Remove a Story chosen by the user from a Sprint identified by its ID.
+    RemoveStoryFromSprint { sprint_id: u32 },
+
+    /// Start a Sprint identified by its ID.
+    StartSprint { sprint_id: u32 },
+
+    /// Complete a Sprint identified by its ID, rolling its incomplete stories into the next one.
+    CompleteSprint { sprint_id: u32 },
+
     /// Exit the application.
     Exit,
 }
@@ -51,7 +137,8 @@ pub enum Action {
 /// Represents the status of an Epic or a Story.
 ///
 /// The `Status` enum defines various statuses that can be assigned to an Epic or a Story.
-/// These statuses include Open, InProgress, Resolved, and Closed.
+/// These statuses include the built-in Open, InProgress, Resolved, and Closed, and any other
+/// status defined by the workflow of the project, see `config::Workflow`.
 ///
 /// # Examples
 ///
@@ -59,8 +146,9 @@ pub enum Action {
 /// use crate::models::Status;
 ///
 /// let status = Status::Open;
+/// let code_review = Status::Custom("CODE REVIEW".to_string());
 /// ```
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, Debug, Clone)]
 pub enum Status {
 
     /// Indicates that an Epic or a Story is in an open state.
@@ -73,7 +161,10 @@ pub enum Status {
     Resolved,
 
     /// Indicates that an Epic or a Story is closed.
-    Closed
+    Closed,
+
+    /// A status defined by the workflow of the project, in uppercase, e.g. "CODE REVIEW".
+    Custom(String)
 }
 
 /// Formats the `Status` enum variant for display.
@@ -103,7 +194,292 @@ impl Display for Status {
             Self::Open =>  write!(f, "OPEN"),
             Self::InProgress =>  write!(f, "IN PROGRESS"),
             Self::Resolved => write!(f, "RESOLVED"),
-            Self::Closed => write!(f, "CLOSED")
+            Self::Closed => write!(f, "CLOSED"),
+            Self::Custom(name) => write!(f, "{}", name)
+        }
+    }
+}
+
+/// Parses a `Status` from its display form.
+///
+/// Parsing is case insensitive and accepts `-` or `_` in place of spaces, so "IN PROGRESS",
+/// "in-progress" and "In_Progress" all parse to `Status::InProgress`. Any other name parses to a
+/// `Status::Custom`, whether the workflow allows it is checked by `config::Workflow`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Status;
+///
+/// let status: Status = "resolved".parse().unwrap();
+/// assert_eq!(status, Status::Resolved);
+///
+/// let status: Status = "code-review".parse().unwrap();
+/// assert_eq!(status, Status::Custom("CODE REVIEW".to_string()));
+/// ```
+impl FromStr for Status {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_uppercase().replace(['-', '_'], " ");
+
+        match name.as_str() {
+            "OPEN" => Ok(Self::Open),
+            "IN PROGRESS" => Ok(Self::InProgress),
+            "RESOLVED" => Ok(Self::Resolved),
+            "CLOSED" => Ok(Self::Closed),
+            "" => Err(anyhow!("Unknown status: {}", s)),
+            _ => Ok(Self::Custom(name))
+        }
+    }
+}
+
+/// Represents the priority of an Epic or a Story.
+///
+/// Variants are declared from most to least urgent, so sorting by `Priority` puts the most
+/// urgent items first. Items created before priorities existed default to `Priority::Medium`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Priority;
+///
+/// assert!(Priority::Highest < Priority::Low);
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Default)]
+pub enum Priority {
+    Highest,
+    High,
+    #[default]
+    Medium,
+    Low,
+    Lowest
+}
+
+impl Priority {
+
+    /// Every priority, from most to least urgent.
+    pub const ALL: [Priority; 5] = [Self::Highest, Self::High, Self::Medium, Self::Low, Self::Lowest];
+}
+
+/// Formats the `Priority` enum variant for display, e.g. `Priority::Highest` as "HIGHEST".
+impl Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Highest => write!(f, "HIGHEST"),
+            Self::High => write!(f, "HIGH"),
+            Self::Medium => write!(f, "MEDIUM"),
+            Self::Low => write!(f, "LOW"),
+            Self::Lowest => write!(f, "LOWEST")
+        }
+    }
+}
+
+/// Parses a `Priority` from its display form, case insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Priority;
+///
+/// let priority: Priority = "high".parse().unwrap();
+/// assert_eq!(priority, Priority::High);
+/// ```
+impl FromStr for Priority {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|priority| priority.to_string() == s.trim().to_uppercase())
+            .ok_or_else(|| anyhow!("Unknown priority: {}", s))
+    }
+}
+
+/// Represents the type of a Story, i.e. of an issue within an Epic.
+///
+/// Items created before issue types existed default to `IssueType::Story`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::IssueType;
+///
+/// let issue_type: IssueType = "bug".parse().unwrap();
+/// assert_eq!(issue_type, IssueType::Bug);
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum IssueType {
+
+    /// A piece of work delivering value to users.
+    #[default]
+    Story,
+
+    /// A piece of technical work.
+    Task,
+
+    /// A defect, which can record how to reproduce it, its severity and the affected version.
+    Bug,
+
+    /// A time-boxed investigation.
+    Spike
+}
+
+impl IssueType {
+
+    /// Every issue type, in the order they are offered when creating a Story.
+    pub const ALL: [IssueType; 4] = [Self::Story, Self::Task, Self::Bug, Self::Spike];
+}
+
+/// Formats the `IssueType` enum variant for display, e.g. `IssueType::Bug` as "BUG".
+impl Display for IssueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Story => write!(f, "STORY"),
+            Self::Task => write!(f, "TASK"),
+            Self::Bug => write!(f, "BUG"),
+            Self::Spike => write!(f, "SPIKE")
+        }
+    }
+}
+
+/// Parses an `IssueType` from its display form, case insensitively.
+impl FromStr for IssueType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|issue_type| issue_type.to_string() == s.trim().to_uppercase())
+            .ok_or_else(|| anyhow!("Unknown issue type: {}", s))
+    }
+}
+
+/// Represents how badly a bug affects its users.
+///
+/// Variants are declared from most to least severe.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Severity;
+///
+/// let severity: Severity = "critical".parse().unwrap();
+/// assert!(severity < Severity::Minor);
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Severity {
+    Blocker,
+    Critical,
+    Major,
+    Minor,
+    Trivial
+}
+
+impl Severity {
+
+    /// Every severity, from most to least severe.
+    pub const ALL: [Severity; 5] = [Self::Blocker, Self::Critical, Self::Major, Self::Minor, Self::Trivial];
+}
+
+/// Formats the `Severity` enum variant for display, e.g. `Severity::Blocker` as "BLOCKER".
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blocker => write!(f, "BLOCKER"),
+            Self::Critical => write!(f, "CRITICAL"),
+            Self::Major => write!(f, "MAJOR"),
+            Self::Minor => write!(f, "MINOR"),
+            Self::Trivial => write!(f, "TRIVIAL")
+        }
+    }
+}
+
+/// Parses a `Severity` from its display form, case insensitively.
+impl FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|severity| severity.to_string() == s.trim().to_uppercase())
+            .ok_or_else(|| anyhow!("Unknown severity: {}", s))
+    }
+}
+
+/// Represents the order of Epics or Stories in list views.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::SortKey;
+///
+/// let sort_key: SortKey = "priority".parse().unwrap();
+/// assert_eq!(sort_key.toggled(), SortKey::Id);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum SortKey {
+
+    /// Sort by id, i.e. by creation order.
+    #[default]
+    Id,
+
+    /// Sort from most to least urgent priority, ties being broken by id.
+    Priority
+}
+
+impl SortKey {
+
+    /// Returns the other sort key, used to switch between the two in the interactive pages.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Id => Self::Priority,
+            Self::Priority => Self::Id
+        }
+    }
+
+    /// Sorts id/item pairs by this key.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to sort, along with their ids.
+    /// * `priority` - Returns the priority of an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::models::SortKey;
+    ///
+    /// let epics = SortKey::Priority.sort(db_state.epics.iter(), |epic| epic.priority);
+    /// ```
+    pub fn sort<'a, T>(self, items: impl Iterator<Item = (&'a u32, &'a T)>, priority: impl Fn(&T) -> Priority) -> Vec<(u32, &'a T)> {
+        let mut items: Vec<(u32, &T)> = items.map(|(id, item)| (*id, item)).collect();
+
+        match self {
+            Self::Id => items.sort_by_key(|(id, _)| *id),
+            Self::Priority => items.sort_by_key(|(id, item)| (priority(item), *id))
+        }
+
+        items
+    }
+}
+
+/// Formats the `SortKey` as its lowercase name, e.g. "priority".
+impl Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id => write!(f, "id"),
+            Self::Priority => write!(f, "priority")
+        }
+    }
+}
+
+/// Parses a `SortKey` from its name, case insensitively.
+impl FromStr for SortKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "id" => Ok(Self::Id),
+            "priority" => Ok(Self::Priority),
+            _ => Err(anyhow!("Unknown sort key: {}", s))
         }
     }
 }
@@ -111,18 +487,26 @@ impl Display for Status {
 /// Represents an Epic in the JIRA-like CLI tool.
 ///
 /// The `Epic` struct represents an Epic within the JIRA-like CLI tool. It contains fields for
-/// the name, description, status, and a list of story IDs associated with the Epic.
+/// the name, description, status, priority, owner, labels, timestamps, due date, a list of
+/// story IDs associated with the Epic, and its links to other items.
 ///
 /// # Examples
 ///
 /// ```
-/// use crate::models::{Epic, Status};
+/// use crate::models::{Epic, Priority, Status};
 ///
 /// let epic = Epic {
 ///     name: "Epic Name".to_string(),
 ///     description: "Epic Description".to_string(),
 ///     status: Status::Open,
+///     priority: Priority::High,
+///     owner: Some("alice".to_string()),
+///     labels: vec!["backend".to_string()],
+///     created_at: None,
+///     updated_at: None,
+///     due_date: NaiveDate::from_ymd_opt(2024, 12, 31),
 ///     stories: vec![1, 2, 3],
+///     links: vec![],
 /// };
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -130,7 +514,34 @@ pub struct Epic {
     pub name: String,
     pub description: String,
     pub status: Status,
-    pub stories: Vec<u32>
+    #[serde(default)]
+    pub priority: Priority,
+
+    /// Username of the user owning the Epic, a key of `DBState::users`.
+    #[serde(default)]
+    pub owner: Option<String>,
+
+    /// Free-form labels of the Epic, lowercase and sorted, see `normalize_labels`.
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// When the Epic was created, set by `JiraDatabase`. `None` for epics created before
+    /// timestamps were recorded.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the Epic was last changed, set by `JiraDatabase`.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The day the Epic is due.
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    pub stories: Vec<u32>,
+
+    /// Links of the Epic to other epics or stories.
+    #[serde(default)]
+    pub links: Vec<Link>
 }
 
 impl Epic {
@@ -138,8 +549,8 @@ impl Epic {
     /// Constructs a new `Epic` instance.
     ///
     /// This method creates a new `Epic` instance with the provided name and description.
-    /// The status of the Epic is set to `Status::Open` by default, and the list of associated
-    /// story IDs is initialized as an empty vector.
+    /// The status of the Epic is set to `Status::Open` and its priority to `Priority::Medium`
+    /// by default, and the list of associated story IDs is initialized as an empty vector.
     ///
     /// # Arguments
     ///
@@ -158,25 +569,113 @@ impl Epic {
             name,
             description,
             status: Status::Open,
-            stories: vec![]
+            priority: Priority::default(),
+            owner: None,
+            labels: vec![],
+            created_at: None,
+            updated_at: None,
+            due_date: None,
+            stories: vec![],
+            links: vec![]
+        }
+    }
+
+    /// Returns `true` if the Epic is past its due date and not done yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The current day.
+    /// * `workflow` - The workflow telling which statuses are done.
+    pub fn is_overdue(&self, today: NaiveDate, workflow: &Workflow) -> bool {
+        is_overdue(self.due_date, &self.status, today, workflow)
+    }
+
+    /// Rolls up the story points of the stories of the Epic.
+    ///
+    /// # Arguments
+    ///
+    /// * `stories` - All stories of the database, e.g. `DBState::stories`. Only those listed in
+    ///   `Epic::stories` are counted.
+    /// * `workflow` - The workflow telling which statuses are done.
+    pub fn progress(&self, stories: &HashMap<u32, Story>, workflow: &Workflow) -> Progress {
+        progress(&self.stories, stories, workflow)
+    }
+}
+
+/// Rolls up the story points of the stories with the given IDs.
+fn progress(story_ids: &[u32], stories: &HashMap<u32, Story>, workflow: &Workflow) -> Progress {
+    let mut progress = Progress::default();
+
+    for story in story_ids.iter().filter_map(|story_id| stories.get(story_id)) {
+        let points = story.story_points.unwrap_or(0);
+
+        progress.total_points += points;
+
+        if workflow.is_done(&story.status) {
+            progress.completed_points += points;
         }
     }
+
+    progress
+}
+
+/// The progress of an Epic or a Sprint, in story points. Stories without points are not counted.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Progress;
+///
+/// let progress = Progress { total_points: 8, completed_points: 2 };
+/// assert_eq!(progress.percent(), Some(25));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Progress {
+
+    /// Sum of the points of every story of the Epic or Sprint.
+    pub total_points: u32,
+
+    /// Sum of the points of the resolved and closed stories of the Epic or Sprint.
+    pub completed_points: u32
+}
+
+impl Progress {
+
+    /// Returns the share of completed points, rounded down, or `None` if there are no points.
+    pub fn percent(&self) -> Option<u32> {
+        (self.total_points > 0).then(|| self.completed_points * 100 / self.total_points)
+    }
 }
 
 /// Represents a Story in the JIRA-like CLI tool.
 ///
 /// The `Story` struct represents a Story within the JIRA-like CLI tool. It contains fields for
-/// the name, description, and status of the Story.
+/// the name, description, status, priority, assignee, labels, timestamps, due date and estimate of
+/// the Story, a list of the IDs of its sub-tasks, its issue type, the fields specific to bugs and
+/// its links to other items.
 ///
 /// # Examples
 ///
 /// ```
-/// use crate::models::{Story, Status};
+/// use crate::models::{IssueType, Link, LinkType, Priority, Severity, Story, Status};
 ///
 /// let story = Story {
 ///     name: "Story Name".to_string(),
 ///     description: "Story Description".to_string(),
 ///     status: Status::Open,
+///     priority: Priority::Low,
+///     assignee: None,
+///     labels: vec![],
+///     created_at: None,
+///     updated_at: None,
+///     due_date: None,
+///     story_points: Some(3),
+///     subtasks: vec![4, 5],
+///     issue_type: IssueType::Bug,
+///     steps_to_reproduce: Some("Open the home page".to_string()),
+///     severity: Some(Severity::Major),
+///     affected_version: Some("1.2.0".to_string()),
+///     links: vec![Link { link_type: LinkType::Blocks, item_id: 6 }],
 /// };
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -184,6 +683,57 @@ pub struct Story {
     pub name: String,
     pub description: String,
     pub status: Status,
+    #[serde(default)]
+    pub priority: Priority,
+
+    /// Username of the user the Story is assigned to, a key of `DBState::users`.
+    #[serde(default)]
+    pub assignee: Option<String>,
+
+    /// Free-form labels of the Story, lowercase and sorted, see `normalize_labels`.
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// When the Story was created, set by `JiraDatabase`. `None` for stories created before
+    /// timestamps were recorded.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the Story was last changed, set by `JiraDatabase`.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The day the Story is due.
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+
+    /// The estimate of the Story, in story points.
+    #[serde(default)]
+    pub story_points: Option<u32>,
+
+    /// IDs of the sub-tasks of the Story, keys of `DBState::subtasks`.
+    #[serde(default)]
+    pub subtasks: Vec<u32>,
+
+    /// The type of the issue.
+    #[serde(default)]
+    pub issue_type: IssueType,
+
+    /// How to reproduce a bug.
+    #[serde(default)]
+    pub steps_to_reproduce: Option<String>,
+
+    /// How badly a bug affects its users.
+    #[serde(default)]
+    pub severity: Option<Severity>,
+
+    /// The version of the product a bug was found in.
+    #[serde(default)]
+    pub affected_version: Option<String>,
+
+    /// Links of the Story to other epics or stories.
+    #[serde(default)]
+    pub links: Vec<Link>,
 }
 
 impl Story {
@@ -191,7 +741,9 @@ impl Story {
     /// Constructs a new `Story` instance.
     ///
     /// This method creates a new `Story` instance with the provided name and description.
-    /// The status of the Story is set to `Status::Open` by default.
+    /// The status of the Story is set to `Status::Open`, its priority to `Priority::Medium` and
+    /// its type to `IssueType::Story` by default, and the list of sub-task IDs is initialized as
+    /// an empty vector.
     ///
     /// # Arguments
     ///
@@ -210,38 +762,1032 @@ impl Story {
             name,
             description,
             status: Status::Open,
+            priority: Priority::default(),
+            assignee: None,
+            labels: vec![],
+            created_at: None,
+            updated_at: None,
+            due_date: None,
+            story_points: None,
+            subtasks: vec![],
+            issue_type: IssueType::default(),
+            steps_to_reproduce: None,
+            severity: None,
+            affected_version: None,
+            links: vec![],
         }
     }
-}
 
+    /// Returns `true` if the Story is past its due date and not done yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The current day.
+    /// * `workflow` - The workflow telling which statuses are done.
+    pub fn is_overdue(&self, today: NaiveDate, workflow: &Workflow) -> bool {
+        is_overdue(self.due_date, &self.status, today, workflow)
+    }
+}
 
-/// Represents the state of the database in the JIRA-like CLI tool.
-///
-/// The `DBState` struct represents the state of the database within the JIRA-like CLI tool.
-/// It contains fields to keep track of the last item ID to create new IDs, as well as HashMaps
-/// for storing Epics and Stories with their respective IDs as keys.
+/// Represents a Sub-task of a Story, i.e. a checklist-style step of the work on the Story.
 ///
 /// # Examples
 ///
 /// ```
-/// use crate::models::{DBState, Epic, Story};
-/// use std::collections::HashMap;
+/// use crate::models::{Subtask, Status};
 ///
-/// let db_state = DBState {
-///     last_item_id: 1,
-///     epics: HashMap::new(),
-///     stories: HashMap::new(),
+/// let subtask = Subtask {
+///     name: "Write the migration".to_string(),
+///     status: Status::Open,
+///     created_at: None,
+///     updated_at: None,
 /// };
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-pub struct DBState {
+pub struct Subtask {
+    pub name: String,
+    pub status: Status,
 
-    /// Keeps track of the last item ID to create new IDs.
-    pub last_item_id: u32,
+    /// When the Sub-task was created, set by `JiraDatabase`.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
 
-    /// HashMap storing Epics with their IDs as keys.
-    pub epics: HashMap<u32, Epic>,
+    /// When the Sub-task was last changed, set by `JiraDatabase`.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>
+}
 
-    /// HashMap storing Stories with their IDs as keys.
-    pub stories: HashMap<u32, Story>
-}
\ No newline at end of file
+impl Subtask {
+
+    /// Constructs a new open `Subtask` with the given name.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            status: Status::Open,
+            created_at: None,
+            updated_at: None
+        }
+    }
+}
+
+/// Returns `true` if an item with the given due date and status is overdue on `today`.
+fn is_overdue(due_date: Option<NaiveDate>, status: &Status, today: NaiveDate, workflow: &Workflow) -> bool {
+    due_date.is_some_and(|due_date| due_date < today) && !workflow.is_done(status)
+}
+
+/// A partial update of an Epic.
+///
+/// Every field that is `Some` replaces the corresponding field of the Epic, while fields that
+/// are `None` are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::EpicPatch;
+///
+/// let patch = EpicPatch { name: Some("New Name".to_string()), ..EpicPatch::default() };
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct EpicPatch {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub labels: Option<Vec<String>>,
+
+    /// `Some(None)` removes the due date.
+    pub due_date: Option<Option<NaiveDate>>
+}
+
+/// A partial update of a Story.
+///
+/// Every field that is `Some` replaces the corresponding field of the Story, while fields that
+/// are `None` are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::StoryPatch;
+///
+/// let patch = StoryPatch { description: Some("New Description".to_string()), ..StoryPatch::default() };
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct StoryPatch {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub labels: Option<Vec<String>>,
+
+    /// `Some(None)` removes the due date.
+    pub due_date: Option<Option<NaiveDate>>,
+
+    /// `Some(None)` removes the estimate.
+    pub story_points: Option<Option<u32>>,
+    pub issue_type: Option<IssueType>,
+
+    /// `Some(None)` removes the steps to reproduce.
+    pub steps_to_reproduce: Option<Option<String>>,
+
+    /// `Some(None)` removes the severity.
+    pub severity: Option<Option<Severity>>,
+
+    /// `Some(None)` removes the affected version.
+    pub affected_version: Option<Option<String>>
+}
+
+/// Normalizes labels entered by a user, so that they can be compared and filtered on.
+///
+/// Labels are trimmed, lowercased and stripped of a leading `#`. Empty labels are dropped and
+/// the result is sorted and free of duplicates.
+///
+/// # Errors
+///
+/// Returns an error if a label contains whitespace or a comma, which are used to separate labels.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::normalize_labels;
+///
+/// let labels = normalize_labels(vec!["#Backend".to_string(), "ui".to_string(), "backend".to_string()])?;
+/// assert_eq!(labels, vec!["backend".to_string(), "ui".to_string()]);
+/// ```
+pub fn normalize_labels(labels: Vec<String>) -> anyhow::Result<Vec<String>> {
+    let mut normalized = vec![];
+
+    for label in labels {
+        let label = label.trim().trim_start_matches('#').to_lowercase();
+
+        if label.contains(|c: char| c.is_whitespace() || c == ',') {
+            return Err(anyhow!("Invalid label: {}", label));
+        }
+
+        if !label.is_empty() {
+            normalized.push(label);
+        }
+    }
+
+    normalized.sort();
+    normalized.dedup();
+
+    Ok(normalized)
+}
+
+/// Represents a user that Epics and Stories can be assigned to.
+///
+/// Users are stored in `DBState::users`, keyed by their username.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::User;
+///
+/// let user = User { name: "Alice Smith".to_string() };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct User {
+
+    /// The full name of the user, shown next to the username.
+    pub name: String
+}
+
+/// Represents a comment on an Epic or a Story.
+///
+/// Comments are stored in `DBState::comments`, keyed by their own ids, which are counted
+/// separately from the ids of epics and stories.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Comment;
+///
+/// let comment = Comment {
+///     item_id: 2,
+///     author: "alice".to_string(),
+///     created_at: chrono::Utc::now(),
+///     updated_at: None,
+///     body: "Looks good to me".to_string(),
+/// };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Comment {
+
+    /// ID of the Epic or Story the comment is attached to.
+    pub item_id: u32,
+
+    /// Name of the user who wrote the comment.
+    pub author: String,
+
+    /// When the comment was written.
+    pub created_at: DateTime<Utc>,
+
+    /// When the comment was last edited, if ever.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The text of the comment.
+    pub body: String
+}
+
+/// Represents the type of a link from one Epic or Story to another.
+///
+/// Links are stored on both of the items they connect, each side with the type read from its
+/// own point of view, e.g. if story 2 `Blocks` story 3, then story 3 `IsBlockedBy` story 2.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::LinkType;
+///
+/// let link_type: LinkType = "is-blocked-by".parse().unwrap();
+/// assert_eq!(link_type.inverse(), LinkType::Blocks);
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum LinkType {
+    Blocks,
+    IsBlockedBy,
+    RelatesTo,
+    Duplicates,
+    IsDuplicatedBy
+}
+
+impl LinkType {
+
+    /// Every link type, in the order they are offered when linking items.
+    pub const ALL: [LinkType; 5] = [Self::Blocks, Self::IsBlockedBy, Self::RelatesTo, Self::Duplicates, Self::IsDuplicatedBy];
+
+    /// Returns the type of the same link seen from the other item.
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Blocks => Self::IsBlockedBy,
+            Self::IsBlockedBy => Self::Blocks,
+            Self::RelatesTo => Self::RelatesTo,
+            Self::Duplicates => Self::IsDuplicatedBy,
+            Self::IsDuplicatedBy => Self::Duplicates
+        }
+    }
+}
+
+/// Formats the `LinkType` enum variant for display, e.g. `LinkType::IsBlockedBy` as "is blocked by".
+impl Display for LinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blocks => write!(f, "blocks"),
+            Self::IsBlockedBy => write!(f, "is blocked by"),
+            Self::RelatesTo => write!(f, "relates to"),
+            Self::Duplicates => write!(f, "duplicates"),
+            Self::IsDuplicatedBy => write!(f, "is duplicated by")
+        }
+    }
+}
+
+/// Parses a `LinkType` from its display form, case insensitively and with words separated by
+/// spaces, dashes or underscores, e.g. "is-blocked-by".
+impl FromStr for LinkType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace(['-', '_'], " ");
+
+        Self::ALL.into_iter()
+            .find(|link_type| link_type.to_string() == name)
+            .ok_or_else(|| anyhow!("Unknown link type: {}", s))
+    }
+}
+
+/// Represents one side of a link between two items, stored on the item the link is seen from.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Link {
+    pub link_type: LinkType,
+
+    /// ID of the Epic or Story on the other side of the link.
+    pub item_id: u32
+}
+
+/// Represents where a Sprint is in its life cycle.
+///
+/// Sprints are planned first, then started, and finally completed. Only one Sprint can be active
+/// at a time, and completed Sprints can no longer be changed.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum SprintState {
+    #[default]
+    Planned,
+    Active,
+    Completed
+}
+
+impl SprintState {
+
+    /// Every sprint state, in life cycle order.
+    pub const ALL: [SprintState; 3] = [Self::Planned, Self::Active, Self::Completed];
+}
+
+/// Formats the `SprintState` enum variant for display, e.g. `SprintState::Active` as "ACTIVE".
+impl Display for SprintState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Planned => write!(f, "PLANNED"),
+            Self::Active => write!(f, "ACTIVE"),
+            Self::Completed => write!(f, "COMPLETED")
+        }
+    }
+}
+
+/// Parses a `SprintState` from its display form, case insensitively.
+impl FromStr for SprintState {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|state| state.to_string() == s.trim().to_uppercase())
+            .ok_or_else(|| anyhow!("Unknown sprint state: {}", s))
+    }
+}
+
+/// Represents a Sprint, i.e. a time-boxed iteration the team commits to a set of stories for.
+///
+/// Sprints are stored in `DBState::sprints`, keyed by their own ids, which are counted separately
+/// from the ids of epics and stories. A Story belongs to at most one Sprint that is not completed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::Sprint;
+/// use chrono::NaiveDate;
+///
+/// let sprint = Sprint::new("Sprint 1".to_string(), "Ship the login page".to_string(),
+///                          NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(), NaiveDate::from_ymd_opt(2024, 6, 14).unwrap());
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Sprint {
+    pub name: String,
+
+    /// What the team wants to achieve by the end of the Sprint.
+    pub goal: String,
+
+    /// The first day of the Sprint.
+    pub start_date: NaiveDate,
+
+    /// The last day of the Sprint.
+    pub end_date: NaiveDate,
+
+    #[serde(default)]
+    pub state: SprintState,
+
+    /// IDs of the Stories planned for the Sprint, in the order they were added.
+    #[serde(default)]
+    pub stories: Vec<u32>
+}
+
+impl Sprint {
+
+    /// Constructs a new planned `Sprint` without stories.
+    pub fn new(name: String, goal: String, start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        Self {
+            name,
+            goal,
+            start_date,
+            end_date,
+            state: SprintState::Planned,
+            stories: vec![]
+        }
+    }
+
+    /// Rolls up the story points of the stories of the Sprint.
+    ///
+    /// # Arguments
+    ///
+    /// * `stories` - All stories of the database, e.g. `DBState::stories`. Only those listed in
+    ///   `Sprint::stories` are counted.
+    /// * `workflow` - The workflow telling which statuses are done.
+    pub fn progress(&self, stories: &HashMap<u32, Story>, workflow: &Workflow) -> Progress {
+        progress(&self.stories, stories, workflow)
+    }
+}
+
+/// Represents a query saved under a name, e.g. "my open bugs", see `Query` for the language.
+///
+/// Saved filters are stored in `DBState::filters`, keyed by their names, and listed on the home
+/// page.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::SavedFilter;
+///
+/// let filter = SavedFilter { query: "type = bug AND assignee = alice AND status != closed".to_string() };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SavedFilter {
+
+    /// The text of the query.
+    pub query: String
+}
+
+/// Represents the state of the database in the JIRA-like CLI tool.
+///
+/// The `DBState` struct represents the state of the database within the JIRA-like CLI tool.
+/// It contains fields to keep track of the version of its persisted format and of the last item
+/// ID to create new IDs, HashMaps for storing Epics, Stories and Sub-tasks with their respective
+/// IDs as keys, Users with their usernames as keys, Comments and Sprints with their IDs as keys,
+/// saved filters with their names as keys, the history of changes that can be undone or redone,
+/// and the log of every change.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::{DBState, Epic, Story};
+/// use std::collections::HashMap;
+///
+/// let db_state = DBState {
+///     last_item_id: 1,
+///     epics: HashMap::new(),
+///     stories: HashMap::new(),
+///     ..DBState::default()
+/// };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DBState {
+
+    /// Version of the persisted format, used to migrate documents written by older versions.
+    pub schema_version: u32,
+
+    /// Keeps track of the last item ID to create new IDs.
+    pub last_item_id: u32,
+
+    /// HashMap storing Epics with their IDs as keys.
+    pub epics: HashMap<u32, Epic>,
+
+    /// HashMap storing Stories with their IDs as keys.
+    pub stories: HashMap<u32, Story>,
+
+    /// HashMap storing Sub-tasks with their IDs as keys.
+    #[serde(default)]
+    pub subtasks: HashMap<u32, Subtask>,
+
+    /// HashMap storing Users with their usernames as keys.
+    #[serde(default)]
+    pub users: HashMap<String, User>,
+
+    /// Keeps track of the last comment ID to create new comment IDs.
+    #[serde(default)]
+    pub last_comment_id: u32,
+
+    /// HashMap storing the Comments on epics and stories with their IDs as keys.
+    #[serde(default)]
+    pub comments: HashMap<u32, Comment>,
+
+    /// Keeps track of the last sprint ID to create new sprint IDs.
+    #[serde(default)]
+    pub last_sprint_id: u32,
+
+    /// HashMap storing Sprints with their IDs as keys.
+    #[serde(default)]
+    pub sprints: HashMap<u32, Sprint>,
+
+    /// HashMap storing the saved filters with their names as keys.
+    #[serde(default)]
+    pub filters: HashMap<String, SavedFilter>,
+
+    /// The changes that can be undone and redone.
+    #[serde(default)]
+    pub history: History,
+
+    /// Log of the latest changes, the oldest one first. It is capped at `CHANGE_LOG_LIMIT` events.
+    #[serde(default)]
+    pub change_log: Vec<ChangeEvent>
+}
+
+/// Constructs an empty `DBState` in the current schema version.
+impl Default for DBState {
+    fn default() -> Self {
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            last_item_id: 0,
+            epics: HashMap::new(),
+            stories: HashMap::new(),
+            subtasks: HashMap::new(),
+            users: HashMap::new(),
+            last_comment_id: 0,
+            comments: HashMap::new(),
+            last_sprint_id: 0,
+            sprints: HashMap::new(),
+            filters: HashMap::new(),
+            history: History::default(),
+            change_log: vec![]
+        }
+    }
+}
+
+impl DBState {
+
+    /// Returns the comments on the Epic or Story with the given ID, the oldest one first.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - The ID of the Epic or Story.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::models::DBState;
+    ///
+    /// for (comment_id, comment) in db_state.comments_on(2) {
+    ///     println!("{} {}: {}", comment_id, comment.author, comment.body);
+    /// }
+    /// ```
+    pub fn comments_on(&self, item_id: u32) -> Vec<(u32, &Comment)> {
+        let mut comments: Vec<(u32, &Comment)> = self.comments.iter()
+            .filter(|(_, comment)| comment.item_id == item_id)
+            .map(|(id, comment)| (*id, comment))
+            .collect();
+
+        comments.sort_by_key(|(id, comment)| (comment.created_at, *id));
+
+        comments
+    }
+
+    /// Returns the links of the Epic or Story with the given ID, or `None` if there is no such item.
+    pub fn links_of(&self, item_id: u32) -> Option<&Vec<Link>> {
+        self.epics.get(&item_id).map(|epic| &epic.links)
+            .or_else(|| self.stories.get(&item_id).map(|story| &story.links))
+    }
+
+    /// Returns the links of the Epic or Story with the given ID for modification, or `None` if
+    /// there is no such item.
+    pub fn links_of_mut(&mut self, item_id: u32) -> Option<&mut Vec<Link>> {
+        match self.epics.get_mut(&item_id) {
+            Some(epic) => Some(&mut epic.links),
+            None => self.stories.get_mut(&item_id).map(|story| &mut story.links)
+        }
+    }
+
+    /// Returns the name and status of the Epic or Story with the given ID, or `None` if there is
+    /// no such item.
+    pub fn item_summary(&self, item_id: u32) -> Option<(&str, &Status)> {
+        self.epics.get(&item_id).map(|epic| (epic.name.as_str(), &epic.status))
+            .or_else(|| self.stories.get(&item_id).map(|story| (story.name.as_str(), &story.status)))
+    }
+
+    /// Returns the IDs of the items blocking the Epic or Story with the given ID that are not
+    /// done yet according to the `workflow`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::models::DBState;
+    ///
+    /// if !db_state.open_blockers(2, &config.workflow).is_empty() {
+    ///     println!("Story 2 is still blocked");
+    /// }
+    /// ```
+    pub fn open_blockers(&self, item_id: u32, workflow: &Workflow) -> Vec<u32> {
+        self.links_of(item_id).into_iter().flatten()
+            .filter(|link| link.link_type == LinkType::IsBlockedBy)
+            .filter(|link| self.item_summary(link.item_id).is_some_and(|(_, status)| !workflow.is_done(status)))
+            .map(|link| link.item_id)
+            .collect()
+    }
+
+    /// Returns the ID of the Epic the Story with the given ID belongs to, or `None` if there is no
+    /// such Story.
+    pub fn epic_of(&self, story_id: u32) -> Option<u32> {
+        self.epics.iter()
+            .find(|(_, epic)| epic.stories.contains(&story_id) && self.stories.contains_key(&story_id))
+            .map(|(epic_id, _)| *epic_id)
+    }
+
+    /// Returns the ID of the Sprint that is not completed yet and contains the Story with the
+    /// given ID, if any.
+    pub fn sprint_of(&self, story_id: u32) -> Option<u32> {
+        self.sprints.iter()
+            .find(|(_, sprint)| sprint.state != SprintState::Completed && sprint.stories.contains(&story_id))
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns the ID of the planned Sprint that starts first, i.e. the one incomplete stories roll
+    /// into when the active Sprint is completed. Sprints starting on the same day are ordered by ID.
+    pub fn next_planned_sprint(&self) -> Option<u32> {
+        self.sprints.iter()
+            .filter(|(_, sprint)| sprint.state == SprintState::Planned)
+            .min_by_key(|(id, sprint)| (sprint.start_date, **id))
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns the saved filters ordered by name. The home page numbers them in this order.
+    pub fn sorted_filters(&self) -> Vec<(&String, &SavedFilter)> {
+        let mut filters: Vec<(&String, &SavedFilter)> = self.filters.iter().collect();
+        filters.sort_by_key(|(name, _)| name.to_lowercase());
+
+        filters
+    }
+
+    /// Returns the saved filter with the given name. Surrounding whitespace is ignored, as names
+    /// are trimmed when a filter is saved.
+    pub fn filter(&self, name: &str) -> Option<&SavedFilter> {
+        self.filters.get(name.trim())
+    }
+
+    /// Searches the names, descriptions and comments of every Epic and Story for the given text,
+    /// ignoring case.
+    ///
+    /// # Returns
+    ///
+    /// Returns one hit per matching field, ordered by item ID and then by field. An empty query
+    /// matches nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::models::DBState;
+    ///
+    /// for hit in db_state.search("login") {
+    ///     println!("{} {}: {}", hit.item_id, hit.field, hit.text);
+    /// }
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let items = self.epics.iter().map(|(id, epic)| (*id, &epic.name, &epic.description))
+            .chain(self.stories.iter().map(|(id, story)| (*id, &story.name, &story.description)));
+
+        let mut fields: Vec<(u32, SearchField, &String)> = vec![];
+
+        for (item_id, name, description) in items {
+            fields.push((item_id, SearchField::Name, name));
+            fields.push((item_id, SearchField::Description, description));
+            fields.extend(self.comments_on(item_id).into_iter().map(|(comment_id, comment)| (item_id, SearchField::Comment(comment_id), &comment.body)));
+        }
+
+        let mut hits: Vec<SearchHit> = fields.into_iter()
+            .filter_map(|(item_id, field, text)| find_ignoring_case(text, query).map(|matched| SearchHit { item_id, field, text: text.clone(), matched }))
+            .collect();
+
+        hits.sort_by_key(|hit| (hit.item_id, hit.field));
+
+        hits
+    }
+}
+
+/// Represents the field of an Epic or Story a search matched.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum SearchField {
+    Name,
+    Description,
+
+    /// A comment on the item, identified by its ID.
+    Comment(u32)
+}
+
+/// Displays a `SearchField` in lowercase, e.g. `name` or `comment #4`.
+impl Display for SearchField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Name => write!(f, "name"),
+            Self::Description => write!(f, "description"),
+            Self::Comment(comment_id) => write!(f, "comment #{}", comment_id)
+        }
+    }
+}
+
+/// Represents a match of a search in a field of an Epic or Story.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::{SearchField, SearchHit};
+///
+/// let hit = SearchHit { item_id: 2, field: SearchField::Name, text: "Login page".to_owned(), matched: 0..5 };
+/// assert_eq!(&hit.text[hit.matched.clone()], "Login");
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SearchHit {
+
+    /// The ID of the Epic or Story that matched.
+    pub item_id: u32,
+
+    /// The field that matched.
+    pub field: SearchField,
+
+    /// The whole text of the field.
+    pub text: String,
+
+    /// The byte range of the first match within `text`.
+    pub matched: Range<usize>
+}
+
+/// Returns the byte range of the first occurrence of `query` in `text`, ignoring case, or `None`
+/// if there is none or the query is empty.
+fn find_ignoring_case(text: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+
+    if query.is_empty() {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let mut query_chars = query.iter();
+        let mut end = start;
+
+        for (offset, c) in text[start..].char_indices() {
+            if query_chars.as_slice().is_empty() {
+                break;
+            }
+
+            for lower in c.to_lowercase() {
+                if query_chars.next() != Some(&lower) {
+                    return None;
+                }
+            }
+
+            end = start + offset + c.len_utf8();
+        }
+
+        query_chars.as_slice().is_empty().then_some(start..end)
+    })
+}
+
+/// Represents the undo and redo stacks of the database.
+///
+/// Every change made through `JiraDatabase` pushes a `Revision` onto the undo stack and clears
+/// the redo stack. Undoing a change moves its revision onto the redo stack, and redoing it moves
+/// it back. Both stacks are persisted with the rest of the state, so they survive a restart.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct History {
+
+    /// Revisions that can be undone, the most recent one last.
+    pub undo: Vec<Revision>,
+
+    /// Revisions that can be redone, the most recently undone one last.
+    pub redo: Vec<Revision>
+}
+
+/// Represents a single change made to the database.
+///
+/// A revision holds the value of every entry it touched both before and after the change, so
+/// it can be reverted by restoring the former and re-applied by restoring the latter.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Revision {
+
+    /// The entries touched by the change.
+    pub changes: Vec<EntryChange>
+}
+
+/// Represents a single entry of the change log, i.e. the change of one field of an Epic or a Story.
+///
+/// Creating or deleting an item is logged as a change of its `created` or `deleted` pseudo-field,
+/// with the name of the item as new or old value respectively.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::ChangeEvent;
+///
+/// let event = ChangeEvent {
+///     timestamp: chrono::Utc::now(),
+///     actor: "alice".to_owned(),
+///     collection: "stories".to_owned(),
+///     id: "2".to_owned(),
+///     field: "status".to_owned(),
+///     old_value: Some(serde_json::json!("InProgress")),
+///     new_value: Some(serde_json::json!("Closed"))
+/// };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ChangeEvent {
+
+    /// When the change was made.
+    pub timestamp: DateTime<Utc>,
+
+    /// Who made the change.
+    pub actor: String,
+
+    /// Name of the collection of the changed item, e.g. `stories`.
+    pub collection: String,
+
+    /// ID of the changed item.
+    pub id: String,
+
+    /// Name of the changed field.
+    pub field: String,
+
+    /// Value of the field before the change, if any.
+    pub old_value: Option<Value>,
+
+    /// Value of the field after the change, if any.
+    pub new_value: Option<Value>
+}
+
+/// Represents the change of a single entry of one of the collections of `DBState`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::models::EntryChange;
+///
+/// // Story 2 was deleted
+/// let change = EntryChange {
+///     collection: "stories".to_owned(),
+///     id: "2".to_owned(),
+///     before: Some(serde_json::json!({ "name": "Story", "description": "", "status": "Open" })),
+///     after: None
+/// };
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct EntryChange {
+
+    /// Name of the collection of the entry, e.g. `epics`.
+    pub collection: String,
+
+    /// Key of the entry within its collection.
+    pub id: String,
+
+    /// Serialized entry before the change, or `None` if it was created.
+    pub before: Option<Value>,
+
+    /// Serialized entry after the change, or `None` if it was deleted.
+    pub after: Option<Value>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_should_parse_from_display_form() {
+        for status in [Status::Open, Status::InProgress, Status::Resolved, Status::Closed, Status::Custom("QA".to_owned())] {
+            assert_eq!(status.to_string().parse::<Status>().unwrap(), status);
+        }
+
+        assert_eq!("in-progress".parse::<Status>().unwrap(), Status::InProgress);
+        assert_eq!(" closed ".parse::<Status>().unwrap(), Status::Closed);
+        assert_eq!("code_review".parse::<Status>().unwrap(), Status::Custom("CODE REVIEW".to_owned()));
+        assert!(" ".parse::<Status>().is_err());
+    }
+
+    #[test]
+    fn priority_should_parse_from_display_form() {
+        for priority in Priority::ALL {
+            assert_eq!(priority.to_string().parse::<Priority>().unwrap(), priority);
+        }
+
+        assert_eq!("high".parse::<Priority>().unwrap(), Priority::High);
+        assert!("urgent".parse::<Priority>().is_err());
+    }
+
+    #[test]
+    fn issue_type_and_severity_should_parse_from_display_form() {
+        for issue_type in IssueType::ALL {
+            assert_eq!(issue_type.to_string().parse::<IssueType>().unwrap(), issue_type);
+        }
+
+        for severity in Severity::ALL {
+            assert_eq!(severity.to_string().parse::<Severity>().unwrap(), severity);
+        }
+
+        assert_eq!("spike".parse::<IssueType>().unwrap(), IssueType::Spike);
+        assert!("epic".parse::<IssueType>().is_err());
+        assert!("urgent".parse::<Severity>().is_err());
+    }
+
+    #[test]
+    fn link_type_should_parse_and_invert() {
+        for link_type in LinkType::ALL {
+            assert_eq!(link_type.to_string().parse::<LinkType>().unwrap(), link_type);
+            assert_eq!(link_type.inverse().inverse(), link_type);
+        }
+
+        assert_eq!("is-blocked-by".parse::<LinkType>().unwrap(), LinkType::IsBlockedBy);
+        assert_eq!("RELATES_TO".parse::<LinkType>().unwrap(), LinkType::RelatesTo);
+        assert!("clones".parse::<LinkType>().is_err());
+    }
+
+    #[test]
+    fn open_blockers_should_skip_done_blockers() {
+        let mut db_state = DBState::default();
+        let blocked = Story {
+            links: vec![Link { link_type: LinkType::IsBlockedBy, item_id: 2 }, Link { link_type: LinkType::IsBlockedBy, item_id: 3 },
+                        Link { link_type: LinkType::RelatesTo, item_id: 4 }],
+            ..Story::new("".to_owned(), "".to_owned())
+        };
+
+        db_state.stories.insert(1, blocked);
+        db_state.stories.insert(2, Story { status: Status::Resolved, ..Story::new("".to_owned(), "".to_owned()) });
+        db_state.epics.insert(3, Epic::new("".to_owned(), "".to_owned()));
+        db_state.stories.insert(4, Story::new("".to_owned(), "".to_owned()));
+
+        assert_eq!(db_state.open_blockers(1, &Workflow::default()), vec![3]);
+        assert!(db_state.open_blockers(2, &Workflow::default()).is_empty());
+    }
+
+    #[test]
+    fn sort_key_should_sort_by_priority_then_id() {
+        let mut stories = HashMap::new();
+        stories.insert(1, Story::new("".to_owned(), "".to_owned()));
+        stories.insert(2, Story { priority: Priority::Highest, ..Story::new("".to_owned(), "".to_owned()) });
+        stories.insert(3, Story { priority: Priority::Lowest, ..Story::new("".to_owned(), "".to_owned()) });
+        stories.insert(4, Story::new("".to_owned(), "".to_owned()));
+
+        let ids = |sort_key: SortKey| sort_key.sort(stories.iter(), |story| story.priority).into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+
+        assert_eq!(ids(SortKey::Id), vec![1, 2, 3, 4]);
+        assert_eq!(ids(SortKey::Priority), vec![2, 1, 4, 3]);
+    }
+
+    #[test]
+    fn normalize_labels_should_sort_and_deduplicate() {
+        let labels = vec!["#Backend".to_owned(), " ui ".to_owned(), "".to_owned(), "backend".to_owned()];
+
+        assert_eq!(normalize_labels(labels).unwrap(), vec!["backend".to_owned(), "ui".to_owned()]);
+        assert!(normalize_labels(vec!["two words".to_owned()]).is_err());
+        assert!(normalize_labels(vec!["a,b".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn is_overdue_should_ignore_done_items() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        let due_date = NaiveDate::from_ymd_opt(2024, 6, 14);
+        let workflow = Workflow::default();
+
+        assert!(!Story::new("".to_owned(), "".to_owned()).is_overdue(today, &workflow));
+        assert!(Story { due_date, ..Story::new("".to_owned(), "".to_owned()) }.is_overdue(today, &workflow));
+        assert!(!Story { due_date, status: Status::Resolved, ..Story::new("".to_owned(), "".to_owned()) }.is_overdue(today, &workflow));
+        assert!(!Epic { due_date: Some(today), ..Epic::new("".to_owned(), "".to_owned()) }.is_overdue(today, &workflow));
+    }
+
+    #[test]
+    fn progress_should_roll_up_story_points() {
+        let mut stories = HashMap::new();
+        stories.insert(2, Story { story_points: Some(3), status: Status::Resolved, ..Story::new("".to_owned(), "".to_owned()) });
+        stories.insert(3, Story { story_points: Some(5), ..Story::new("".to_owned(), "".to_owned()) });
+        stories.insert(4, Story::new("".to_owned(), "".to_owned()));
+        stories.insert(5, Story { story_points: Some(8), status: Status::Closed, ..Story::new("".to_owned(), "".to_owned()) });
+
+        let epic = Epic { stories: vec![2, 3, 4], ..Epic::new("".to_owned(), "".to_owned()) };
+        let progress = epic.progress(&stories, &Workflow::default());
+
+        assert_eq!(progress, Progress { total_points: 8, completed_points: 3 });
+        assert_eq!(progress.percent(), Some(37));
+        assert_eq!(Epic::new("".to_owned(), "".to_owned()).progress(&stories, &Workflow::default()).percent(), None);
+    }
+
+    #[test]
+    fn sprints_should_be_found_by_story_and_start_date() {
+        let date = |day| NaiveDate::from_ymd_opt(2024, 6, day).unwrap();
+        let mut db_state = DBState::default();
+
+        db_state.sprints.insert(1, Sprint { state: SprintState::Completed, stories: vec![7], ..Sprint::new("".to_owned(), "".to_owned(), date(3), date(14)) });
+        db_state.sprints.insert(2, Sprint { state: SprintState::Active, stories: vec![7], ..Sprint::new("".to_owned(), "".to_owned(), date(17), date(28)) });
+        db_state.sprints.insert(3, Sprint::new("".to_owned(), "".to_owned(), date(24), date(30)));
+        db_state.sprints.insert(4, Sprint::new("".to_owned(), "".to_owned(), date(20), date(30)));
+
+        assert_eq!(db_state.sprint_of(7), Some(2));
+        assert_eq!(db_state.sprint_of(8), None);
+        assert_eq!(db_state.next_planned_sprint(), Some(4));
+        assert_eq!("active".parse::<SprintState>().unwrap(), SprintState::Active);
+    }
+
+    #[test]
+    fn comments_on_should_list_comments_oldest_first() {
+        let now = Utc::now();
+        let comment = |item_id: u32, minutes: i64| Comment { item_id, author: "".to_owned(), created_at: now + chrono::Duration::minutes(minutes), updated_at: None, body: "".to_owned() };
+
+        let mut db_state = DBState::default();
+        db_state.comments.insert(1, comment(2, 5));
+        db_state.comments.insert(2, comment(3, 0));
+        db_state.comments.insert(3, comment(2, 0));
+        db_state.comments.insert(4, comment(2, 0));
+
+        let ids: Vec<u32> = db_state.comments_on(2).into_iter().map(|(id, _)| id).collect();
+
+        assert_eq!(ids, vec![3, 4, 1]);
+        assert!(db_state.comments_on(9).is_empty());
+    }
+
+    #[test]
+    fn search_should_match_names_descriptions_and_comments_ignoring_case() {
+        let mut db_state = DBState::default();
+        db_state.epics.insert(1, Epic { stories: vec![2], ..Epic::new("Accounts".to_owned(), "Everything about LOGIN".to_owned()) });
+        db_state.stories.insert(2, Story::new("Login page".to_owned(), "".to_owned()));
+        db_state.stories.insert(3, Story::new("Logout".to_owned(), "Straße".to_owned()));
+        db_state.comments.insert(4, Comment { item_id: 2, author: "".to_owned(), created_at: Utc::now(), updated_at: None, body: "Use the new login API".to_owned() });
+
+        let hits: Vec<(u32, SearchField, String)> = db_state.search("login").into_iter()
+            .map(|hit| (hit.item_id, hit.field, hit.text[hit.matched].to_owned()))
+            .collect();
+
+        assert_eq!(hits, vec![
+            (1, SearchField::Description, "LOGIN".to_owned()),
+            (2, SearchField::Name, "Login".to_owned()),
+            (2, SearchField::Comment(4), "login".to_owned())
+        ]);
+        assert_eq!(db_state.search("STRAßE")[0].matched, 0..7);
+        assert!(db_state.search("").is_empty());
+        assert_eq!(SearchField::Comment(4).to_string(), "comment #4");
+    }
+
+    #[test]
+    fn priority_should_default_when_missing() {
+        let story: Story = serde_json::from_str(r#"{ "name": "story", "description": "", "status": "Open" }"#).unwrap();
+
+        assert_eq!(story.priority, Priority::Medium);
+        assert_eq!(story.issue_type, IssueType::Story);
+    }
+}
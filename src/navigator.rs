@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Result, Context, Ok};
 use std::rc::Rc;
 
-use crate::{ui::{Page, HomePage, EpicDetail, StoryDetail, Prompts}, db::JiraDatabase, models::Action};
+use crate::{ui::{Page, HomePage, EpicDetail, StoryDetail, SubtaskDetail, HistoryPage, SearchResults, QueryPage, BoardPage, SprintPage, SprintDetail, Prompts}, db::JiraDatabase, models::Action};
 
 pub struct Navigator {
     pages: Vec<Box<dyn Page>>,
@@ -12,9 +12,7 @@ pub struct Navigator {
 impl Navigator {
     pub fn new(db: Rc<JiraDatabase>) -> Self {
         Self {
-            pages: vec![Box::new(HomePage {
-                db: Rc::clone(&db)
-            })],
+            pages: vec![Box::new(HomePage::new(Rc::clone(&db)))],
             prompts: Prompts::new(),
             db
         }
@@ -27,11 +25,17 @@ impl Navigator {
     pub fn handle_action(&mut self, action: Action) -> Result<()> {
         match action {
             Action::NavigateToEpicDetail { epic_id } => {
-                self.pages.push(Box::new(EpicDetail{epic_id, db: Rc::clone(&self.db)}));
+                self.pages.push(Box::new(EpicDetail::new(epic_id, Rc::clone(&self.db))));
             }
             Action::NavigateToStoryDetail { epic_id, story_id } => {
                 self.pages.push(Box::new(StoryDetail{epic_id, story_id, db: Rc::clone(&self.db)}));
             }
+            Action::NavigateToSubtaskDetail { story_id, subtask_id } => {
+                self.pages.push(Box::new(SubtaskDetail{story_id, subtask_id, db: Rc::clone(&self.db)}));
+            }
+            Action::NavigateToHistory { item_id } => {
+                self.pages.push(Box::new(HistoryPage{item_id, db: Rc::clone(&self.db)}));
+            }
             Action::NavigateToPreviousPage => {
                 if !self.pages.is_empty() { self.pages.pop(); }
             }
@@ -40,12 +44,21 @@ impl Navigator {
                 self.db.create_epic(epic).with_context(|| anyhow!("Failed to create epic!"))?;
             }
             Action::UpdateEpicStatus { epic_id } => {
-                let status = (self.prompts.update_status)();
+                let db_state = self.db.read_db()?;
+                let epic = db_state.epics.get(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!"))?;
+                let status = (self.prompts.update_status)(&self.db.config.workflow.next_statuses(&epic.status));
 
                 if let Some(status) = status {
                     self.db.update_epic_status(epic_id, status).with_context(|| anyhow!("Failed to update epic!"))?;
                 }
             }
+            Action::EditEpic { epic_id } => {
+                let db_state = self.db.read_db()?;
+                let epic = db_state.epics.get(&epic_id).ok_or_else(|| anyhow!("Could not find epic in the database!"))?;
+                let patch = (self.prompts.edit_epic)(epic);
+
+                self.db.update_epic(epic_id, patch).with_context(|| anyhow!("Failed to edit epic!"))?;
+            }
             Action::DeleteEpic { epic_id } => {
                 if(self.prompts.delete_epic)() {
                     self.db.delete_epic(epic_id).with_context(|| anyhow!("Failed to delete epic!"))?;
@@ -59,13 +72,28 @@ impl Navigator {
                 let story = (self.prompts.create_story)();
                 self.db.create_story(story, epic_id).with_context(|| anyhow!("Failed to create story!"))?;
             }
-            Action::UpdateStoryStatus { story_id } => {
-                let status = (self.prompts.update_status)();
+            Action::UpdateStoryStatus { story_id, status } => {
+                let db_state = self.db.read_db()?;
+                let story = db_state.stories.get(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!"))?;
+                let status = status.or_else(|| (self.prompts.update_status)(&self.db.config.workflow.next_statuses(&story.status)));
 
                 if let Some(status) = status {
+                    let blockers = db_state.open_blockers(story_id, &self.db.config.workflow);
+
+                    if self.db.config.workflow.is_done(&status) && !blockers.is_empty() && !(self.prompts.resolve_blocked)(&blockers) {
+                        return Ok(());
+                    }
+
                     self.db.update_story_status(story_id, status).with_context(|| anyhow!("Failed to update story!"))?;
                 }
             }
+            Action::EditStory { story_id } => {
+                let db_state = self.db.read_db()?;
+                let story = db_state.stories.get(&story_id).ok_or_else(|| anyhow!("Could not find story in the database!"))?;
+                let patch = (self.prompts.edit_story)(story);
+
+                self.db.update_story(story_id, patch).with_context(|| anyhow!("Failed to edit story!"))?;
+            }
             Action::DeleteStory { epic_id, story_id } => {
                 if (self.prompts.delete_story)() {
                     self.db.delete_story(epic_id, story_id).with_context(|| anyhow!("Failed to delete story!"))?;
@@ -75,6 +103,127 @@ impl Navigator {
                     }
                 }
             }
+            Action::MoveStory { epic_id, story_id } => {
+                if let Some(target_epic_id) = (self.prompts.move_story)() {
+                    self.db.move_story(story_id, epic_id, target_epic_id).with_context(|| anyhow!("Failed to move story!"))?;
+
+                    if !self.pages.is_empty() {
+                        self.pages.pop();
+                    }
+
+                    self.pages.push(Box::new(StoryDetail{epic_id: target_epic_id, story_id, db: Rc::clone(&self.db)}));
+                }
+            }
+            Action::CreateSubtask { story_id } => {
+                let subtask = (self.prompts.create_subtask)();
+                self.db.create_subtask(subtask, story_id).with_context(|| anyhow!("Failed to create sub-task!"))?;
+            }
+            Action::UpdateSubtaskStatus { subtask_id } => {
+                let db_state = self.db.read_db()?;
+                let subtask = db_state.subtasks.get(&subtask_id).ok_or_else(|| anyhow!("Could not find sub-task in the database!"))?;
+                let status = (self.prompts.update_status)(&self.db.config.workflow.next_statuses(&subtask.status));
+
+                if let Some(status) = status {
+                    self.db.update_subtask_status(subtask_id, status).with_context(|| anyhow!("Failed to update sub-task!"))?;
+                }
+            }
+            Action::DeleteSubtask { story_id, subtask_id } => {
+                if (self.prompts.delete_subtask)() {
+                    self.db.delete_subtask(story_id, subtask_id).with_context(|| anyhow!("Failed to delete sub-task!"))?;
+
+                    if !self.pages.is_empty() {
+                        self.pages.pop();
+                    }
+                }
+            }
+            Action::AssignStory { story_id } => {
+                let usernames: Vec<String> = self.db.read_db()?.users.into_keys().collect();
+                let assignee = (self.prompts.assign)(&usernames);
+
+                self.db.assign_story(story_id, assignee.as_deref()).with_context(|| anyhow!("Failed to assign story!"))?;
+            }
+            Action::AssignEpic { epic_id } => {
+                let usernames: Vec<String> = self.db.read_db()?.users.into_keys().collect();
+                let owner = (self.prompts.assign)(&usernames);
+
+                self.db.set_epic_owner(epic_id, owner.as_deref()).with_context(|| anyhow!("Failed to assign epic!"))?;
+            }
+            Action::AddComment { item_id } => {
+                if let Some(body) = (self.prompts.comment)() {
+                    self.db.add_comment(item_id, &body).with_context(|| anyhow!("Failed to add comment!"))?;
+                }
+            }
+            Action::AddLink { item_id } => {
+                if let Some((link_type, target_id)) = (self.prompts.link)() {
+                    self.db.add_link(item_id, link_type, target_id).with_context(|| anyhow!("Failed to add link!"))?;
+                }
+            }
+            Action::RemoveLink { item_id } => {
+                let db_state = self.db.read_db()?;
+                let links = db_state.links_of(item_id).ok_or_else(|| anyhow!("Could not find epic or story in the database!"))?;
+
+                if let Some(link) = (self.prompts.unlink)(links) {
+                    self.db.remove_link(item_id, link.link_type, link.item_id).with_context(|| anyhow!("Failed to remove link!"))?;
+                }
+            }
+            Action::NavigateToBoard { epic_id } => {
+                self.pages.push(Box::new(BoardPage{epic_id, db: Rc::clone(&self.db)}));
+            }
+            Action::NavigateToSearch { query } => {
+                self.pages.push(Box::new(SearchResults{query, db: Rc::clone(&self.db)}));
+            }
+            Action::NavigateToQuery { query } => {
+                self.pages.push(Box::new(QueryPage::new(query, Rc::clone(&self.db))));
+            }
+            Action::SaveFilter { query } => {
+                if let Some(name) = (self.prompts.save_filter)() {
+                    self.db.save_filter(&name, &query).with_context(|| anyhow!("Failed to save filter!"))?;
+                }
+            }
+            Action::NavigateToSprints => {
+                self.pages.push(Box::new(SprintPage{db: Rc::clone(&self.db)}));
+            }
+            Action::NavigateToSprintDetail { sprint_id } => {
+                self.pages.push(Box::new(SprintDetail{sprint_id, db: Rc::clone(&self.db)}));
+            }
+            Action::CreateSprint => {
+                if let Some(sprint) = (self.prompts.create_sprint)() {
+                    self.db.create_sprint(sprint).with_context(|| anyhow!("Failed to create sprint!"))?;
+                }
+            }
+            Action::AddStoryToSprint { sprint_id } => {
+                if let Some(story_id) = (self.prompts.sprint_story)() {
+                    self.db.add_story_to_sprint(sprint_id, story_id).with_context(|| anyhow!("Failed to add story to sprint!"))?;
+                }
+            }
+            Action::RemoveStoryFromSprint { sprint_id } => {
+                if let Some(story_id) = (self.prompts.sprint_story)() {
+                    self.db.remove_story_from_sprint(sprint_id, story_id).with_context(|| anyhow!("Failed to remove story from sprint!"))?;
+                }
+            }
+            Action::StartSprint { sprint_id } => {
+                self.db.start_sprint(sprint_id).with_context(|| anyhow!("Failed to start sprint!"))?;
+            }
+            Action::CompleteSprint { sprint_id } => {
+                let db_state = self.db.read_db()?;
+                let sprint = db_state.sprints.get(&sprint_id).ok_or_else(|| anyhow!("Could not find sprint in the database!"))?;
+                let incomplete_stories: Vec<u32> = sprint.stories.iter()
+                    .filter(|story_id| db_state.stories.get(story_id).is_some_and(|story| !self.db.config.workflow.is_done(&story.status)))
+                    .copied()
+                    .collect();
+
+                if (self.prompts.complete_sprint)(&incomplete_stories, db_state.next_planned_sprint()) {
+                    self.db.complete_sprint(sprint_id).with_context(|| anyhow!("Failed to complete sprint!"))?;
+                }
+            }
+            Action::Undo => {
+                self.db.undo().with_context(|| anyhow!("Failed to undo!"))?;
+                self.close_deleted_pages()?;
+            }
+            Action::Redo => {
+                self.db.redo().with_context(|| anyhow!("Failed to redo!"))?;
+                self.close_deleted_pages()?;
+            }
             Action::Exit => {
                 self.pages.clear()
             },
@@ -83,6 +232,32 @@ impl Navigator {
         Ok(())
     }
 
+    /// Closes the pages showing an epic, story, sub-task or sprint that no longer exists, e.g.
+    /// after undoing its creation, or a story that is no longer in the epic it was opened from.
+    fn close_deleted_pages(&mut self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+
+        self.pages.retain(|page| {
+            let page = page.as_any();
+
+            if let Some(page) = page.downcast_ref::<EpicDetail>() {
+                db_state.epics.contains_key(&page.epic_id)
+            } else if let Some(page) = page.downcast_ref::<StoryDetail>() {
+                db_state.epics.get(&page.epic_id).is_some_and(|epic| epic.stories.contains(&page.story_id))
+            } else if let Some(page) = page.downcast_ref::<SubtaskDetail>() {
+                db_state.subtasks.contains_key(&page.subtask_id)
+            } else if let Some(page) = page.downcast_ref::<BoardPage>() {
+                page.epic_id.is_none_or(|epic_id| db_state.epics.contains_key(&epic_id))
+            } else if let Some(page) = page.downcast_ref::<SprintDetail>() {
+                db_state.sprints.contains_key(&page.sprint_id)
+            } else {
+                true
+            }
+        });
+
+        Ok(())
+    }
+
     // Private functions used for testing
     #[cfg(test)]
     fn get_page_count(&self) -> usize {
@@ -96,12 +271,15 @@ impl Navigator {
 
 #[cfg(test)]
 mod tests {
-    use crate::{db::test_utils::MockDB, models::{Epic, Status, Story}};
+    use std::collections::HashMap;
+
+    use crate::{config::{Config, Workflow}, db::test_utils::MockDB, models::{Epic, EpicPatch, LinkType, Sprint, SprintState, Status, Story, StoryPatch, Subtask, User}};
+    use chrono::NaiveDate;
     use super::*;
 
     #[test]
     fn should_start_on_home_page() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let nav = Navigator::new(db);
 
         assert_eq!(nav.get_page_count(), 1);
@@ -114,7 +292,7 @@ mod tests {
 
     #[test]
     fn handle_action_should_navigate_pages() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
         let mut nav = Navigator::new(db);
         
@@ -132,6 +310,26 @@ mod tests {
         let story_detail_page = current_page.as_any().downcast_ref::<StoryDetail>();
         assert_eq!(story_detail_page.is_some(), true);
 
+        nav.handle_action(Action::NavigateToSubtaskDetail { story_id: 2, subtask_id: 3 }).unwrap();
+        assert_eq!(nav.get_page_count(), 4);
+
+        let current_page = nav.get_current_page().unwrap();
+        let subtask_detail_page = current_page.as_any().downcast_ref::<SubtaskDetail>();
+        assert!(subtask_detail_page.is_some());
+
+        nav.handle_action(Action::NavigateToHistory { item_id: 3 }).unwrap();
+        assert_eq!(nav.get_page_count(), 5);
+
+        let current_page = nav.get_current_page().unwrap();
+        let history_page = current_page.as_any().downcast_ref::<HistoryPage>();
+        assert!(history_page.is_some());
+
+        nav.handle_action(Action::NavigateToPreviousPage).unwrap();
+        assert_eq!(nav.get_page_count(), 4);
+
+        nav.handle_action(Action::NavigateToPreviousPage).unwrap();
+        assert_eq!(nav.get_page_count(), 3);
+
         nav.handle_action(Action::NavigateToPreviousPage).unwrap();
         assert_eq!(nav.get_page_count(), 2);
 
@@ -155,7 +353,7 @@ mod tests {
 
     #[test]
     fn handle_action_should_clear_pages_on_exit() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
         let mut nav = Navigator::new(db);
         
@@ -168,7 +366,7 @@ mod tests {
 
     #[test]
     fn handle_action_should_handle_create_epic() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
         let mut nav = Navigator::new(Rc::clone(&db));
 
@@ -189,13 +387,13 @@ mod tests {
 
     #[test]
     fn handle_action_should_handle_update_epic() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
 
         let mut nav = Navigator::new(Rc::clone(&db));
 
         let mut prompts = Prompts::new();
-        prompts.update_status = Box::new(|| Some(Status::InProgress));
+        prompts.update_status = Box::new(|_| Some(Status::InProgress));
 
         nav.set_prompts(prompts);
         
@@ -205,9 +403,29 @@ mod tests {
         assert_eq!(db_state.epics.get(&epic_id).unwrap().status, Status::InProgress);
     }
 
+    #[test]
+    fn handle_action_should_handle_edit_epic() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("name".to_owned(), "description".to_owned())).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.edit_epic = Box::new(|epic| EpicPatch { name: Some(format!("{} fixed", epic.name)), ..EpicPatch::default() });
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::EditEpic { epic_id }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        let epic = db_state.epics.get(&epic_id).unwrap();
+        assert_eq!(epic.name, "name fixed".to_owned());
+        assert_eq!(epic.description, "description".to_owned());
+    }
+
     #[test]
     fn handle_action_should_handle_delete_epic() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
 
         let mut nav = Navigator::new(Rc::clone(&db));
@@ -225,7 +443,7 @@ mod tests {
 
     #[test]
     fn handle_action_should_handle_create_story() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
 
         let mut nav = Navigator::new(Rc::clone(&db));
@@ -247,26 +465,47 @@ mod tests {
 
     #[test]
     fn handle_action_should_handle_update_story() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
         let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
 
         let mut nav = Navigator::new(Rc::clone(&db));
 
         let mut prompts = Prompts::new();
-        prompts.update_status = Box::new(|| Some(Status::InProgress));
+        prompts.update_status = Box::new(|_| Some(Status::InProgress));
 
         nav.set_prompts(prompts);
         
-        nav.handle_action(Action::UpdateStoryStatus { story_id }).unwrap();
+        nav.handle_action(Action::UpdateStoryStatus { story_id, status: None }).unwrap();
 
         let db_state = db.read_db().unwrap();
         assert_eq!(db_state.stories.get(&story_id).unwrap().status, Status::InProgress);
     }
 
+    #[test]
+    fn handle_action_should_handle_edit_story() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("name".to_owned(), "description".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.edit_story = Box::new(|_| StoryPatch { description: Some("new description".to_owned()), ..StoryPatch::default() });
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::EditStory { story_id }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        let story = db_state.stories.get(&story_id).unwrap();
+        assert_eq!(story.name, "name".to_owned());
+        assert_eq!(story.description, "new description".to_owned());
+    }
+
     #[test]
     fn handle_action_should_handle_delete_story() {
-        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
         let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
         let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
 
@@ -282,4 +521,322 @@ mod tests {
         let db_state = db.read_db().unwrap();
         assert_eq!(db_state.stories.len(), 0);
     }
+
+    #[test]
+    fn handle_action_should_handle_move_story() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.move_story = Box::new(move || Some(other_epic_id));
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::NavigateToEpicDetail { epic_id }).unwrap();
+        nav.handle_action(Action::NavigateToStoryDetail { epic_id, story_id }).unwrap();
+        nav.handle_action(Action::MoveStory { epic_id, story_id }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.epics.get(&epic_id).unwrap().stories, Vec::<u32>::new());
+        assert_eq!(db_state.epics.get(&other_epic_id).unwrap().stories, vec![story_id]);
+
+        assert_eq!(nav.get_page_count(), 3);
+
+        let current_page = nav.get_current_page().unwrap();
+        let story_detail_page = current_page.as_any().downcast_ref::<StoryDetail>().unwrap();
+        assert_eq!(story_detail_page.epic_id, other_epic_id);
+    }
+
+    #[test]
+    fn handle_action_should_fail_to_move_story_to_invalid_epic() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.move_story = Box::new(|| Some(999));
+
+        nav.set_prompts(prompts);
+
+        assert!(nav.handle_action(Action::MoveStory { epic_id, story_id }).is_err());
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().stories, vec![story_id]);
+    }
+
+    #[test]
+    fn handle_action_should_handle_subtasks() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.create_subtask = Box::new(|| Subtask::new("subtask".to_owned()));
+        prompts.update_status = Box::new(|_| Some(Status::Resolved));
+        prompts.delete_subtask = Box::new(|| true);
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::CreateSubtask { story_id }).unwrap();
+
+        let subtask_id = db.read_db().unwrap().stories.get(&story_id).unwrap().subtasks[0];
+
+        nav.handle_action(Action::UpdateSubtaskStatus { subtask_id }).unwrap();
+        assert_eq!(db.read_db().unwrap().subtasks.get(&subtask_id).unwrap().status, Status::Resolved);
+
+        nav.handle_action(Action::NavigateToSubtaskDetail { story_id, subtask_id }).unwrap();
+        nav.handle_action(Action::DeleteSubtask { story_id, subtask_id }).unwrap();
+
+        assert!(db.read_db().unwrap().subtasks.is_empty());
+        assert_eq!(nav.get_page_count(), 1);
+    }
+
+    #[test]
+    fn handle_action_should_offer_next_statuses_of_workflow() {
+        let workflow = Workflow {
+            statuses: vec!["Open".to_owned(), "In Progress".to_owned(), "QA".to_owned(), "Closed".to_owned()],
+            transitions: HashMap::from([("In Progress".to_owned(), vec!["QA".to_owned()])]),
+            ..Workflow::default()
+        };
+        let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()), config: Config { workflow, ..Config::default() } });
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        db.update_story_status(story_id, Status::InProgress).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.update_status = Box::new(|next_statuses| {
+            assert_eq!(next_statuses, [Status::Custom("QA".to_owned())]);
+            next_statuses.first().cloned()
+        });
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::UpdateStoryStatus { story_id, status: None }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().status, Status::Custom("QA".to_owned()));
+    }
+
+    #[test]
+    fn handle_action_should_handle_search_queries_and_filters() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        nav.handle_action(Action::NavigateToSearch { query: "login".to_owned() }).unwrap();
+        assert_eq!(nav.get_page_count(), 2);
+
+        let current_page = nav.get_current_page().unwrap();
+        let search_results = current_page.as_any().downcast_ref::<SearchResults>();
+        assert_eq!(search_results.unwrap().query, "login".to_owned());
+
+        nav.handle_action(Action::NavigateToQuery { query: "points > 2".to_owned() }).unwrap();
+        assert_eq!(nav.get_page_count(), 3);
+
+        let current_page = nav.get_current_page().unwrap();
+        let query_page = current_page.as_any().downcast_ref::<QueryPage>();
+        assert_eq!(*query_page.unwrap().query.borrow(), "points > 2".to_owned());
+
+        nav.set_prompts(Prompts { save_filter: Box::new(|| Some("big stories".to_owned())), ..Prompts::new() });
+        nav.handle_action(Action::SaveFilter { query: "points > 2".to_owned() }).unwrap();
+        assert_eq!(db.read_db().unwrap().filters["big stories"].query, "points > 2".to_owned());
+    }
+
+    #[test]
+    fn handle_action_should_move_cards_on_board() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        nav.handle_action(Action::NavigateToBoard { epic_id: Some(epic_id) }).unwrap();
+        assert_eq!(nav.get_page_count(), 2);
+
+        let current_page = nav.get_current_page().unwrap();
+        let board_page = current_page.as_any().downcast_ref::<BoardPage>();
+        assert!(board_page.is_some());
+
+        let mut prompts = Prompts::new();
+        prompts.update_status = Box::new(|_| panic!("the status of a card should not be prompted"));
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::UpdateStoryStatus { story_id, status: Some(Status::InProgress) }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().status, Status::InProgress);
+    }
+
+    #[test]
+    fn handle_action_should_handle_sprints() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.create_sprint = Box::new(move || Some(Sprint::new("Sprint".to_owned(), "".to_owned(), date, date)));
+        prompts.sprint_story = Box::new(move || Some(story_id));
+        prompts.complete_sprint = Box::new(move |incomplete_stories, next_sprint_id| {
+            assert_eq!(incomplete_stories, [story_id]);
+            assert_eq!(next_sprint_id, Some(2));
+            true
+        });
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::NavigateToSprints).unwrap();
+        nav.handle_action(Action::CreateSprint).unwrap();
+        nav.handle_action(Action::CreateSprint).unwrap();
+        nav.handle_action(Action::NavigateToSprintDetail { sprint_id: 1 }).unwrap();
+        assert_eq!(nav.get_page_count(), 3);
+
+        nav.handle_action(Action::AddStoryToSprint { sprint_id: 1 }).unwrap();
+        nav.handle_action(Action::StartSprint { sprint_id: 1 }).unwrap();
+        nav.handle_action(Action::CompleteSprint { sprint_id: 1 }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.sprints.get(&1).unwrap().state, SprintState::Completed);
+        assert_eq!(db_state.sprint_of(story_id), Some(2));
+
+        nav.handle_action(Action::RemoveStoryFromSprint { sprint_id: 2 }).unwrap();
+        assert_eq!(db.read_db().unwrap().sprint_of(story_id), None);
+    }
+
+    #[test]
+    fn handle_action_should_handle_assign_story() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        db.add_user("alice", User { name: "Alice".to_owned() }).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.assign = Box::new(|usernames| usernames.first().cloned());
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::AssignStory { story_id }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().assignee, Some("alice".to_owned()));
+
+        nav.set_prompts(Prompts { assign: Box::new(|_| None), ..Prompts::new() });
+
+        nav.handle_action(Action::AssignStory { story_id }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().assignee, None);
+    }
+
+    #[test]
+    fn handle_action_should_handle_assign_epic() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        let mut prompts = Prompts::new();
+        prompts.assign = Box::new(|_| Some("bob".to_owned()));
+
+        nav.set_prompts(prompts);
+
+        nav.handle_action(Action::AssignEpic { epic_id }).unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id).unwrap().owner, Some("bob".to_owned()));
+
+        assert!(nav.handle_action(Action::AssignEpic { epic_id: 999 }).is_err());
+    }
+
+    #[test]
+    fn handle_action_should_handle_add_comment() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        nav.set_prompts(Prompts { comment: Box::new(|| None), ..Prompts::new() });
+        nav.handle_action(Action::AddComment { item_id: epic_id }).unwrap();
+        assert!(db.read_db().unwrap().comments.is_empty());
+
+        nav.set_prompts(Prompts { comment: Box::new(|| Some("comment".to_owned())), ..Prompts::new() });
+        nav.handle_action(Action::AddComment { item_id: epic_id }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert_eq!(db_state.comments_on(epic_id)[0].1.body, "comment".to_owned());
+    }
+
+    #[test]
+    fn handle_action_should_handle_links() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+        let blocker_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        nav.set_prompts(Prompts { link: Box::new(move || Some((LinkType::IsBlockedBy, blocker_id))), ..Prompts::new() });
+        nav.handle_action(Action::AddLink { item_id: story_id }).unwrap();
+        assert_eq!(db.read_db().unwrap().open_blockers(story_id, &db.config.workflow), vec![blocker_id]);
+
+        nav.set_prompts(Prompts {
+            update_status: Box::new(|_| Some(Status::Resolved)),
+            resolve_blocked: Box::new(|_| false),
+            ..Prompts::new()
+        });
+        nav.handle_action(Action::UpdateStoryStatus { story_id, status: None }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().status, Status::Open);
+
+        nav.set_prompts(Prompts {
+            update_status: Box::new(|_| Some(Status::Resolved)),
+            resolve_blocked: Box::new(|_| true),
+            ..Prompts::new()
+        });
+        nav.handle_action(Action::UpdateStoryStatus { story_id, status: None }).unwrap();
+        assert_eq!(db.read_db().unwrap().stories.get(&story_id).unwrap().status, Status::Resolved);
+
+        nav.set_prompts(Prompts { unlink: Box::new(|links| links.first().copied()), ..Prompts::new() });
+        nav.handle_action(Action::RemoveLink { item_id: blocker_id }).unwrap();
+
+        let db_state = db.read_db().unwrap();
+        assert!(db_state.stories.get(&story_id).unwrap().links.is_empty());
+        assert!(db_state.stories.get(&blocker_id).unwrap().links.is_empty());
+    }
+
+    #[test]
+    fn handle_action_should_handle_undo_and_redo() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+
+        nav.handle_action(Action::Undo).unwrap();
+        assert_eq!(db.read_db().unwrap().epics.get(&epic_id), None);
+        assert!(nav.handle_action(Action::Undo).is_err());
+
+        nav.handle_action(Action::Redo).unwrap();
+        assert!(db.read_db().unwrap().epics.contains_key(&epic_id));
+        assert!(nav.handle_action(Action::Redo).is_err());
+    }
+
+    #[test]
+    fn handle_action_should_close_deleted_pages_on_undo() {
+        let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+        let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+        let mut nav = Navigator::new(Rc::clone(&db));
+        nav.handle_action(Action::NavigateToEpicDetail { epic_id }).unwrap();
+        nav.handle_action(Action::NavigateToStoryDetail { epic_id, story_id }).unwrap();
+
+        nav.handle_action(Action::Undo).unwrap();
+        assert_eq!(nav.get_page_count(), 2);
+
+        let current_page = nav.get_current_page().unwrap();
+        assert!(current_page.as_any().downcast_ref::<EpicDetail>().is_some());
+
+        nav.handle_action(Action::Undo).unwrap();
+        assert_eq!(nav.get_page_count(), 1);
+    }
 }
\ No newline at end of file
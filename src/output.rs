@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::config::Workflow;
+use crate::models::{Comment, DBState, Epic, Link, SavedFilter, Story, User};
+
+/// Output formats of the non-interactive subcommands, selected with `--output`.
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OutputFormat {
+
+    /// Aligned columns for reading in a terminal.
+    #[default]
+    Table,
+
+    /// A single JSON document: an array for lists, an object for single items.
+    Json,
+
+    /// One JSON object per line.
+    Ndjson,
+
+    /// Tab-separated values with a header line. Tabs, newlines and backslashes in values are
+    /// escaped as `\t`, `\n` and `\\`.
+    Tsv
+}
+
+/// A row of output, i.e. an epic or a story, that can be rendered in every `OutputFormat`.
+///
+/// Records contain the full data of the item, with untruncated names and descriptions.
+pub trait Record: Serialize {
+
+    /// Names of the columns, used as the TSV header.
+    fn columns() -> &'static [&'static str];
+
+    /// Values of the columns, in the same order as `columns`.
+    fn values(&self) -> Vec<String>;
+
+    /// The line shown for this record in `OutputFormat::Table`.
+    fn table_line(&self) -> String;
+}
+
+/// An Epic as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct EpicRecord {
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    pub status: String,
+    pub priority: String,
+    pub owner: Option<String>,
+    pub labels: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub due_date: Option<NaiveDate>,
+    pub total_points: u32,
+    pub completed_points: u32,
+    pub stories: Vec<u32>
+}
+
+impl EpicRecord {
+
+    /// Builds the record of an Epic, rolling up the story points of its `stories` that are done
+    /// according to the `workflow`.
+    pub fn new(id: u32, epic: &Epic, stories: &HashMap<u32, Story>, workflow: &Workflow) -> Self {
+        let progress = epic.progress(stories, workflow);
+
+        Self {
+            id,
+            name: epic.name.clone(),
+            description: epic.description.clone(),
+            status: epic.status.to_string(),
+            priority: epic.priority.to_string(),
+            owner: epic.owner.clone(),
+            labels: epic.labels.clone(),
+            created_at: epic.created_at,
+            updated_at: epic.updated_at,
+            due_date: epic.due_date,
+            total_points: progress.total_points,
+            completed_points: progress.completed_points,
+            stories: epic.stories.clone()
+        }
+    }
+}
+
+impl Record for EpicRecord {
+    fn columns() -> &'static [&'static str] {
+        &["id", "name", "description", "status", "priority", "owner", "labels", "created_at", "updated_at", "due_date", "total_points", "completed_points", "stories"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            self.description.clone(),
+            self.status.clone(),
+            self.priority.clone(),
+            self.owner.clone().unwrap_or_default(),
+            self.labels.join(","),
+            optional_string(&self.created_at),
+            optional_string(&self.updated_at),
+            optional_string(&self.due_date),
+            self.total_points.to_string(),
+            self.completed_points.to_string(),
+            self.stories.iter().map(u32::to_string).collect::<Vec<_>>().join(",")
+        ]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:>5}  {:<12} {:<8} {}", self.id, self.status, self.priority, self.name)
+    }
+}
+
+/// A Story as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct StoryRecord {
+    pub id: u32,
+    pub epic_id: u32,
+    pub name: String,
+    pub description: String,
+    pub status: String,
+    pub priority: String,
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub due_date: Option<NaiveDate>,
+    pub story_points: Option<u32>,
+    pub subtasks: Vec<u32>,
+    pub issue_type: String,
+    pub steps_to_reproduce: Option<String>,
+    pub severity: Option<String>,
+    pub affected_version: Option<String>
+}
+
+impl StoryRecord {
+    pub fn new(id: u32, epic_id: u32, story: &Story) -> Self {
+        Self {
+            id,
+            epic_id,
+            name: story.name.clone(),
+            description: story.description.clone(),
+            status: story.status.to_string(),
+            priority: story.priority.to_string(),
+            assignee: story.assignee.clone(),
+            labels: story.labels.clone(),
+            created_at: story.created_at,
+            updated_at: story.updated_at,
+            due_date: story.due_date,
+            story_points: story.story_points,
+            subtasks: story.subtasks.clone(),
+            issue_type: story.issue_type.to_string(),
+            steps_to_reproduce: story.steps_to_reproduce.clone(),
+            severity: story.severity.map(|severity| severity.to_string()),
+            affected_version: story.affected_version.clone()
+        }
+    }
+}
+
+impl Record for StoryRecord {
+    fn columns() -> &'static [&'static str] {
+        &["id", "epic_id", "name", "description", "status", "priority", "assignee", "labels", "created_at", "updated_at", "due_date", "story_points", "subtasks",
+          "issue_type", "steps_to_reproduce", "severity", "affected_version"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.epic_id.to_string(),
+            self.name.clone(),
+            self.description.clone(),
+            self.status.clone(),
+            self.priority.clone(),
+            self.assignee.clone().unwrap_or_default(),
+            self.labels.join(","),
+            optional_string(&self.created_at),
+            optional_string(&self.updated_at),
+            optional_string(&self.due_date),
+            optional_string(&self.story_points),
+            self.subtasks.iter().map(u32::to_string).collect::<Vec<_>>().join(","),
+            self.issue_type.clone(),
+            self.steps_to_reproduce.clone().unwrap_or_default(),
+            self.severity.clone().unwrap_or_default(),
+            self.affected_version.clone().unwrap_or_default()
+        ]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:>5}  {:>5}  {:<12} {:<8} {}", self.id, self.epic_id, self.status, self.priority, self.name)
+    }
+}
+
+/// A User as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct UserRecord {
+    pub username: String,
+    pub name: String
+}
+
+impl UserRecord {
+    pub fn new(username: &str, user: &User) -> Self {
+        Self {
+            username: username.to_owned(),
+            name: user.name.clone()
+        }
+    }
+}
+
+impl Record for UserRecord {
+    fn columns() -> &'static [&'static str] {
+        &["username", "name"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![self.username.clone(), self.name.clone()]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:<16} {}", self.username, self.name)
+    }
+}
+
+/// A Comment as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct CommentRecord {
+    pub id: u32,
+    pub item_id: u32,
+    pub author: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub body: String
+}
+
+impl CommentRecord {
+    pub fn new(id: u32, comment: &Comment) -> Self {
+        Self {
+            id,
+            item_id: comment.item_id,
+            author: comment.author.clone(),
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            body: comment.body.clone()
+        }
+    }
+}
+
+impl Record for CommentRecord {
+    fn columns() -> &'static [&'static str] {
+        &["id", "item_id", "author", "created_at", "updated_at", "body"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.item_id.to_string(),
+            self.author.clone(),
+            self.created_at.to_string(),
+            optional_string(&self.updated_at),
+            self.body.clone()
+        ]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:>5}  {:<16} {}  {}", self.id, self.author, self.created_at.format("%Y-%m-%d %H:%M"), self.body.replace('\n', " "))
+    }
+}
+
+/// A Link of an epic or story as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LinkRecord {
+    pub item_id: u32,
+    pub link_type: String,
+    pub target_id: u32,
+    pub target_name: String,
+    pub target_status: String
+}
+
+impl LinkRecord {
+    pub fn new(item_id: u32, link: &Link, db_state: &DBState) -> Self {
+        let (target_name, target_status) = db_state.item_summary(link.item_id)
+            .map(|(name, status)| (name.to_owned(), status.to_string()))
+            .unwrap_or_default();
+
+        Self {
+            item_id,
+            link_type: link.link_type.to_string(),
+            target_id: link.item_id,
+            target_name,
+            target_status
+        }
+    }
+}
+
+impl Record for LinkRecord {
+    fn columns() -> &'static [&'static str] {
+        &["item_id", "link_type", "target_id", "target_name", "target_status"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.item_id.to_string(),
+            self.link_type.clone(),
+            self.target_id.to_string(),
+            self.target_name.clone(),
+            self.target_status.clone()
+        ]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:<16} {:>5}  {:<12} {}", self.link_type, self.target_id, self.target_status, self.target_name)
+    }
+}
+
+/// A saved filter as rendered by the non-interactive subcommands.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct FilterRecord {
+    pub name: String,
+    pub query: String
+}
+
+impl FilterRecord {
+    pub fn new(name: &str, filter: &SavedFilter) -> Self {
+        Self {
+            name: name.to_owned(),
+            query: filter.query.clone()
+        }
+    }
+}
+
+impl Record for FilterRecord {
+    fn columns() -> &'static [&'static str] {
+        &["name", "query"]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![self.name.clone(), self.query.clone()]
+    }
+
+    fn table_line(&self) -> String {
+        format!("{:<24} {}", self.name, self.query)
+    }
+}
+
+/// Renders a list of records.
+///
+/// # Arguments
+///
+/// * `records` - The records to render, in order.
+/// * `format` - The output format.
+/// * `out` - Where the output is written.
+///
+/// # Examples
+///
+/// ```
+/// use crate::output::{render_list, OutputFormat, EpicRecord};
+///
+/// let records = vec![EpicRecord::new(1, &epic, &db_state.stories)];
+/// render_list(&records, OutputFormat::Json, &mut std::io::stdout())?;
+/// ```
+pub fn render_list<T: Record>(records: &[T], format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match format {
+        OutputFormat::Table => {
+            for record in records {
+                writeln!(out, "{}", record.table_line())?;
+            }
+        }
+        OutputFormat::Json => {
+            writeln!(out, "{}", serde_json::to_string_pretty(records)?)?;
+        }
+        OutputFormat::Ndjson => {
+            for record in records {
+                writeln!(out, "{}", serde_json::to_string(record)?)?;
+            }
+        }
+        OutputFormat::Tsv => {
+            writeln!(out, "{}", T::columns().join("\t"))?;
+
+            for record in records {
+                writeln!(out, "{}", record.values().iter().map(|value| escape_tsv(value)).collect::<Vec<_>>().join("\t"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders a single record.
+///
+/// This is the same as `render_list` with a single record, except that JSON output is an object
+/// rather than an array, and table output shows every column on its own line.
+pub fn render_one<T: Record>(record: &T, format: OutputFormat, out: &mut dyn Write) -> Result<()> {
+    match format {
+        OutputFormat::Table => {
+            for (column, value) in T::columns().iter().zip(record.values()) {
+                writeln!(out, "{:<12} {}", format!("{}:", column), value)?;
+            }
+        }
+        OutputFormat::Json => {
+            writeln!(out, "{}", serde_json::to_string_pretty(record)?)?;
+        }
+        OutputFormat::Ndjson | OutputFormat::Tsv => render_list(std::slice::from_ref(record), format, out)?
+    }
+
+    Ok(())
+}
+
+/// Formats an optional value, using an empty string for `None`.
+fn optional_string<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+/// Escapes the characters that would break a TSV row.
+fn escape_tsv(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IssueType, Severity, Status};
+
+    fn render<T: Record>(records: &[T], format: OutputFormat) -> String {
+        let mut out = vec![];
+        render_list(records, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn records() -> Vec<StoryRecord> {
+        let description = "A long description\twith a tab and\na newline, that is not truncated".to_owned();
+        let story = Story { status: Status::InProgress, assignee: Some("alice".to_owned()), labels: vec!["api".to_owned(), "backend".to_owned()], due_date: NaiveDate::from_ymd_opt(2024, 12, 31), story_points: Some(3), subtasks: vec![4, 5], issue_type: IssueType::Bug, severity: Some(Severity::Major), ..Story::new("story".to_owned(), description) };
+
+        vec![StoryRecord::new(2, 1, &story), StoryRecord::new(3, 1, &Story::new("other".to_owned(), "".to_owned()))]
+    }
+
+    #[test]
+    fn render_list_should_emit_json_array() {
+        let output: serde_json::Value = serde_json::from_str(&render(&records(), OutputFormat::Json)).unwrap();
+
+        assert_eq!(output[0]["id"], 2);
+        assert_eq!(output[0]["status"], "IN PROGRESS");
+        assert_eq!(output[0]["description"], "A long description\twith a tab and\na newline, that is not truncated");
+        assert_eq!(output[1]["name"], "other");
+    }
+
+    #[test]
+    fn render_list_should_emit_one_json_object_per_line() {
+        let output = render(&records(), OutputFormat::Ndjson);
+        let lines: Vec<serde_json::Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["id"], 3);
+        assert_eq!(lines[1]["epic_id"], 1);
+    }
+
+    #[test]
+    fn render_list_should_emit_escaped_tsv() {
+        let output = render(&records(), OutputFormat::Tsv);
+
+        assert_eq!(output, "id\tepic_id\tname\tdescription\tstatus\tpriority\tassignee\tlabels\tcreated_at\tupdated_at\tdue_date\tstory_points\tsubtasks\t\
+                            issue_type\tsteps_to_reproduce\tseverity\taffected_version\n\
+                            2\t1\tstory\tA long description\\twith a tab and\\na newline, that is not truncated\tIN PROGRESS\tMEDIUM\talice\tapi,backend\t\t\t2024-12-31\t3\t4,5\tBUG\t\tMAJOR\t\n\
+                            3\t1\tother\t\tOPEN\tMEDIUM\t\t\t\t\t\t\t\tSTORY\t\t\t\n");
+    }
+
+    #[test]
+    fn render_one_should_emit_json_object() {
+        let epic = Epic { stories: vec![2, 3], ..Epic::new("epic".to_owned(), "".to_owned()) };
+
+        let mut out = vec![];
+        let mut stories = HashMap::new();
+        stories.insert(2, Story { story_points: Some(3), status: Status::Closed, ..Story::new("".to_owned(), "".to_owned()) });
+        stories.insert(3, Story { story_points: Some(2), ..Story::new("".to_owned(), "".to_owned()) });
+
+        render_one(&EpicRecord::new(1, &epic, &stories, &Workflow::default()), OutputFormat::Json, &mut out).unwrap();
+        let output: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(output, serde_json::json!({ "id": 1, "name": "epic", "description": "", "status": "OPEN", "priority": "MEDIUM", "owner": null, "labels": [],
+                                        "created_at": null, "updated_at": null, "due_date": null,
+                                        "total_points": 5, "completed_points": 3, "stories": [2, 3] }));
+    }
+}
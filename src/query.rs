@@ -0,0 +1,752 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use chrono::NaiveDate;
+
+use crate::config::Workflow;
+use crate::models::{DBState, IssueType, Priority, Severity, Story};
+
+/// Represents a query selecting and ordering stories, written in a small JQL-like language.
+///
+/// A query is an optional filter followed by an optional `ORDER BY` clause. The filter combines
+/// comparisons of the fields of a story with `AND`, `OR`, `NOT` and parentheses. Keywords, field
+/// names and text values are case insensitive, and values containing spaces are quoted.
+/// Stories are ordered by ID unless the query says otherwise. An empty query selects every story.
+/// Statuses are checked against the workflow when the query is parsed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::config::Workflow;
+/// use crate::query::Query;
+///
+/// let query = Query::parse(r#"status = "IN PROGRESS" AND epic = 3 AND points > 2 ORDER BY id DESC"#, &Workflow::default())?;
+///
+/// for (story_id, story) in query.run(&db_state) {
+///     println!("{} {}", story_id, story.name);
+/// }
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Query {
+
+    /// The condition stories must meet, or `None` to select every story.
+    pub filter: Option<Expr>,
+
+    /// The fields the stories are ordered on, the most significant one first.
+    pub order_by: Vec<OrderBy>
+}
+
+/// Represents a condition of a query.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+
+    /// Compares a field of the story with a value, e.g. `points > 2`.
+    Compare { field: Field, operator: Operator, value: Value },
+
+    /// Checks whether a field of the story is empty, e.g. `assignee IS EMPTY`.
+    IsEmpty { field: Field, negated: bool }
+}
+
+/// Represents one field of an `ORDER BY` clause, e.g. `priority DESC`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct OrderBy {
+    pub field: Field,
+    pub descending: bool
+}
+
+/// Represents the fields of a story a query can refer to.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Field {
+    Id,
+    Epic,
+    Name,
+    Description,
+    Status,
+    Type,
+    Priority,
+    Severity,
+    Assignee,
+    Label,
+    Points,
+    Sprint,
+    Due,
+    Created,
+    Updated
+}
+
+/// Describes which operators and values a field accepts.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum FieldKind {
+
+    /// An ID or a count, compared as a number.
+    Number,
+
+    /// A priority or severity, where the more urgent value is the greater one.
+    Rank,
+
+    /// A status or issue type, which can only be tested for equality.
+    Enum,
+
+    /// Free text, compared ignoring case. `~` tests whether the text contains the value.
+    Text,
+
+    /// The labels of the story. `=` tests whether one of them is the value.
+    Labels,
+
+    /// A date, written as `YYYY-MM-DD`.
+    Date
+}
+
+impl Field {
+
+    /// Every field with the names it can be written as, the canonical one first.
+    const NAMES: [(Field, &'static [&'static str]); 15] = [
+        (Self::Id, &["id"]),
+        (Self::Epic, &["epic"]),
+        (Self::Name, &["name", "summary"]),
+        (Self::Description, &["description"]),
+        (Self::Status, &["status"]),
+        (Self::Type, &["type", "issuetype"]),
+        (Self::Priority, &["priority"]),
+        (Self::Severity, &["severity"]),
+        (Self::Assignee, &["assignee"]),
+        (Self::Label, &["label", "labels"]),
+        (Self::Points, &["points", "storypoints"]),
+        (Self::Sprint, &["sprint"]),
+        (Self::Due, &["due", "duedate"]),
+        (Self::Created, &["created"]),
+        (Self::Updated, &["updated"])
+    ];
+
+    fn kind(self) -> FieldKind {
+        match self {
+            Self::Id | Self::Epic | Self::Points | Self::Sprint => FieldKind::Number,
+            Self::Priority | Self::Severity => FieldKind::Rank,
+            Self::Status | Self::Type => FieldKind::Enum,
+            Self::Name | Self::Description | Self::Assignee => FieldKind::Text,
+            Self::Label => FieldKind::Labels,
+            Self::Due | Self::Created | Self::Updated => FieldKind::Date
+        }
+    }
+
+    /// Parses the value a field is compared with, e.g. `high` for `priority`. Statuses must be
+    /// statuses of the given workflow.
+    fn parse_value(self, text: &str, workflow: &Workflow) -> anyhow::Result<Value> {
+        match self {
+            Self::Status => Ok(Value::Text(workflow.parse_status(text)?.to_string())),
+            Self::Type => Ok(Value::Text(text.parse::<IssueType>()?.to_string())),
+            Self::Priority => Ok(Value::Number(rank(&Priority::ALL, &text.parse()?))),
+            Self::Severity => Ok(Value::Number(rank(&Severity::ALL, &text.parse()?))),
+            _ => match self.kind() {
+                FieldKind::Number => Ok(Value::Number(text.parse().map_err(|_| anyhow!("Expected a number, found {}", text))?)),
+                FieldKind::Date => Ok(Value::Date(NaiveDate::parse_from_str(text, "%Y-%m-%d").map_err(|_| anyhow!("Expected a date like 2024-12-31, found {}", text))?)),
+                _ => Ok(Value::Text(text.to_lowercase()))
+            }
+        }
+    }
+
+    /// Returns the values of the field for the given story. Every field has at most one value,
+    /// except `label`, which has one per label.
+    fn values(self, story_id: u32, story: &Story, db_state: &DBState) -> Vec<Value> {
+        let text = |text: &str| Some(Value::Text(text.to_lowercase()));
+
+        let value = match self {
+            Self::Id => Some(Value::Number(story_id)),
+            Self::Epic => db_state.epic_of(story_id).map(Value::Number),
+            Self::Name => text(&story.name),
+            Self::Description => Some(&story.description).filter(|description| !description.is_empty()).and_then(|description| text(description)),
+            Self::Status => Some(Value::Text(story.status.to_string())),
+            Self::Type => Some(Value::Text(story.issue_type.to_string())),
+            Self::Priority => Some(Value::Number(rank(&Priority::ALL, &story.priority))),
+            Self::Severity => story.severity.map(|severity| Value::Number(rank(&Severity::ALL, &severity))),
+            Self::Assignee => story.assignee.as_deref().and_then(text),
+            Self::Label => return story.labels.iter().filter_map(|label| text(label)).collect(),
+            Self::Points => story.story_points.map(Value::Number),
+            Self::Sprint => db_state.sprint_of(story_id).map(Value::Number),
+            Self::Due => story.due_date.map(Value::Date),
+            Self::Created => story.created_at.map(|created_at| Value::Date(created_at.date_naive())),
+            Self::Updated => story.updated_at.map(|updated_at| Value::Date(updated_at.date_naive()))
+        };
+
+        value.into_iter().collect()
+    }
+}
+
+/// Formats a `Field` as its canonical name, e.g. `points`.
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (_, names) = Self::NAMES.iter().find(|(field, _)| field == self).expect("every field has a name");
+
+        write!(f, "{}", names[0])
+    }
+}
+
+/// Parses a `Field` from any of its names, case insensitively.
+impl FromStr for Field {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::NAMES.iter()
+            .find(|(_, names)| names.contains(&s.to_lowercase().as_str()))
+            .map(|(field, _)| *field)
+            .ok_or_else(|| anyhow!("Unknown field: {}", s))
+    }
+}
+
+/// Returns the rank of a priority or severity, 0 being the least urgent.
+fn rank<T: PartialEq>(all: &[T], value: &T) -> u32 {
+    (all.len() - 1 - all.iter().position(|other| other == value).unwrap_or(0)) as u32
+}
+
+/// Represents the comparison operators of a query.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+
+    /// Tests whether a text contains the value, ignoring case.
+    Contains
+}
+
+impl Operator {
+    const ALL: [Operator; 7] = [Self::Eq, Self::NotEq, Self::Lt, Self::LtEq, Self::Gt, Self::GtEq, Self::Contains];
+
+    /// Returns `true` if the operator can be used with fields of the given kind.
+    fn supports(self, kind: FieldKind) -> bool {
+        match self {
+            Self::Eq | Self::NotEq => true,
+            Self::Lt | Self::LtEq | Self::Gt | Self::GtEq => matches!(kind, FieldKind::Number | FieldKind::Rank | FieldKind::Date),
+            Self::Contains => matches!(kind, FieldKind::Text | FieldKind::Labels)
+        }
+    }
+
+    /// Returns `true` if `value` compares to `other` as the operator requires.
+    fn matches(self, value: &Value, other: &Value) -> bool {
+        let ordering = value.partial_cmp(other);
+
+        match self {
+            Self::Eq => ordering == Some(Ordering::Equal),
+            Self::NotEq => ordering.is_some_and(Ordering::is_ne),
+            Self::Lt => ordering == Some(Ordering::Less),
+            Self::LtEq => ordering.is_some_and(Ordering::is_le),
+            Self::Gt => ordering == Some(Ordering::Greater),
+            Self::GtEq => ordering.is_some_and(Ordering::is_ge),
+            Self::Contains => matches!((value, other), (Value::Text(value), Value::Text(other)) if value.contains(other.as_str()))
+        }
+    }
+}
+
+/// Formats an `Operator` as it is written in a query, e.g. `>=`.
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eq => write!(f, "="),
+            Self::NotEq => write!(f, "!="),
+            Self::Lt => write!(f, "<"),
+            Self::LtEq => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::GtEq => write!(f, ">="),
+            Self::Contains => write!(f, "~")
+        }
+    }
+}
+
+/// Represents a value a field is compared with. Text is kept in lowercase, except for statuses
+/// and issue types, which are kept in their display form.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub enum Value {
+    Number(u32),
+    Text(String),
+    Date(NaiveDate)
+}
+
+/// An error in the text of a query, pointing at the column it was found at.
+///
+/// # Examples
+///
+/// ```
+/// use crate::config::Workflow;
+/// use crate::query::Query;
+///
+/// let error = Query::parse("points > two", &Workflow::default()).unwrap_err();
+/// assert_eq!(error.column, 10);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct QueryError {
+
+    /// The text of the query.
+    pub query: String,
+
+    /// The column of the offending character, starting at 1.
+    pub column: usize,
+
+    /// What is wrong at that column.
+    pub message: String
+}
+
+/// Formats a `QueryError` as its message followed by the query, with a caret under the column
+/// of the error.
+impl Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Invalid query at column {}: {}", self.column, self.message)?;
+        writeln!(f, "  {}", self.query)?;
+        write!(f, "  {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// Represents the tokens of a query.
+#[derive(PartialEq, Debug, Clone)]
+enum Token {
+
+    /// A keyword, field name or unquoted value.
+    Word(String),
+
+    /// A quoted value.
+    Quoted(String),
+
+    Operator(Operator),
+    LeftParen,
+    RightParen,
+    Comma,
+    End
+}
+
+impl Token {
+
+    /// Returns `true` if the token is the given keyword, ignoring case.
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Self::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+/// Formats a `Token` for error messages.
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "{}", word),
+            Self::Quoted(text) => write!(f, "\"{}\"", text),
+            Self::Operator(operator) => write!(f, "{}", operator),
+            Self::LeftParen => write!(f, "("),
+            Self::RightParen => write!(f, ")"),
+            Self::Comma => write!(f, ","),
+            Self::End => write!(f, "end of query")
+        }
+    }
+}
+
+/// Splits the query into tokens, each with the column it starts at.
+fn tokenize(query: &str) -> Result<Vec<(Token, usize)>, (usize, String)> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let column = i + 1;
+
+        let token = match chars[i] {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            '=' => Token::Operator(Operator::Eq),
+            '~' => Token::Operator(Operator::Contains),
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                i += 1;
+                Token::Operator(Operator::NotEq)
+            }
+            c @ ('<' | '>') => {
+                let or_equal = chars.get(i + 1) == Some(&'=');
+                i += usize::from(or_equal);
+
+                Token::Operator(match (c, or_equal) {
+                    ('<', false) => Operator::Lt,
+                    ('<', true) => Operator::LtEq,
+                    (_, false) => Operator::Gt,
+                    (_, true) => Operator::GtEq
+                })
+            }
+            quote @ ('"' | '\'') => {
+                let mut text = String::new();
+
+                loop {
+                    i += 1;
+
+                    match chars.get(i) {
+                        None => return Err((column, "Unterminated string".to_owned())),
+                        Some('\\') if chars.get(i + 1).is_some() => {
+                            i += 1;
+                            text.push(chars[i]);
+                        }
+                        Some(c) if *c == quote => break,
+                        Some(c) => text.push(*c)
+                    }
+                }
+
+                Token::Quoted(text)
+            }
+            c if is_word_char(c) => {
+                let start = i;
+
+                while chars.get(i + 1).is_some_and(|c| is_word_char(*c)) {
+                    i += 1;
+                }
+
+                Token::Word(chars[start..=i].iter().collect())
+            }
+            c => return Err((column, format!("Unexpected character {}", c)))
+        };
+
+        tokens.push((token, column));
+        i += 1;
+    }
+
+    tokens.push((Token::End, chars.len() + 1));
+
+    Ok(tokens)
+}
+
+/// Returns `true` if the character can be part of a keyword, field name or unquoted value.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':' | '#')
+}
+
+/// A recursive descent parser over the tokens of a query.
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+
+    /// The workflow statuses are checked against.
+    workflow: &'a Workflow
+}
+
+impl Parser<'_> {
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position].0
+    }
+
+    fn column(&self) -> usize {
+        self.tokens[self.position].1
+    }
+
+    fn next(&mut self) -> (Token, usize) {
+        let token = self.tokens[self.position].clone();
+
+        if token.0 != Token::End {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    /// Consumes the next token if it is the given keyword.
+    fn accept_keyword(&mut self, keyword: &str) -> bool {
+        let accepted = self.peek().is_keyword(keyword);
+
+        if accepted {
+            self.next();
+        }
+
+        accepted
+    }
+
+    fn error<T>(&self, column: usize, message: String) -> Result<T, (usize, String)> {
+        Err((column, message))
+    }
+
+    /// query := [or_expr] [ORDER BY order_by {, order_by}]
+    fn parse_query(&mut self) -> Result<Query, (usize, String)> {
+        let filter = match self.peek() {
+            Token::End => None,
+            token if token.is_keyword("order") => None,
+            _ => Some(self.parse_or()?)
+        };
+
+        let mut order_by = vec![];
+
+        if self.accept_keyword("order") {
+            if !self.accept_keyword("by") {
+                return self.error(self.column(), format!("Expected BY, found {}", self.peek()));
+            }
+
+            loop {
+                let field = self.parse_field()?;
+                let descending = self.accept_keyword("desc");
+
+                if !descending {
+                    self.accept_keyword("asc");
+                }
+
+                order_by.push(OrderBy { field, descending });
+
+                if *self.peek() != Token::Comma {
+                    break;
+                }
+
+                self.next();
+            }
+        }
+
+        match self.peek() {
+            Token::End => Ok(Query { filter, order_by }),
+            token if order_by.is_empty() => self.error(self.column(), format!("Expected AND, OR or ORDER BY, found {}", token)),
+            token => self.error(self.column(), format!("Expected a comma or the end of the query, found {}", token))
+        }
+    }
+
+    /// or_expr := and_expr {OR and_expr}
+    fn parse_or(&mut self) -> Result<Expr, (usize, String)> {
+        let mut expr = self.parse_and()?;
+
+        while self.accept_keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+
+        Ok(expr)
+    }
+
+    /// and_expr := unary {AND unary}
+    fn parse_and(&mut self) -> Result<Expr, (usize, String)> {
+        let mut expr = self.parse_unary()?;
+
+        while self.accept_keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+
+        Ok(expr)
+    }
+
+    /// unary := NOT unary | ( or_expr ) | clause
+    fn parse_unary(&mut self) -> Result<Expr, (usize, String)> {
+        if self.accept_keyword("not") {
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+
+        if *self.peek() != Token::LeftParen {
+            return self.parse_clause();
+        }
+
+        let column = self.column();
+        self.next();
+
+        let expr = self.parse_or()?;
+
+        match self.next() {
+            (Token::RightParen, _) => Ok(expr),
+            (Token::End, _) => self.error(column, "Unclosed parenthesis".to_owned()),
+            (token, column) => self.error(column, format!("Expected ), found {}", token))
+        }
+    }
+
+    /// clause := field operator value | field IS [NOT] EMPTY
+    fn parse_clause(&mut self) -> Result<Expr, (usize, String)> {
+        let field = self.parse_field()?;
+
+        if self.accept_keyword("is") {
+            let negated = self.accept_keyword("not");
+
+            if !self.accept_keyword("empty") {
+                return self.error(self.column(), format!("Expected EMPTY, found {}", self.peek()));
+            }
+
+            return Ok(Expr::IsEmpty { field, negated });
+        }
+
+        let operator = match self.next() {
+            (Token::Operator(operator), column) if !operator.supports(field.kind()) => {
+                let supported: Vec<String> = Operator::ALL.iter().filter(|other| other.supports(field.kind())).map(Operator::to_string).collect();
+
+                return self.error(column, format!("Operator {} cannot be used with {}, use one of {}", operator, field, supported.join(" ")));
+            }
+            (Token::Operator(operator), _) => operator,
+            (token, column) => return self.error(column, format!("Expected an operator or IS after {}, found {}", field, token))
+        };
+
+        let value = match self.next() {
+            (Token::Word(text) | Token::Quoted(text), column) => field.parse_value(&text, self.workflow).or_else(|error| self.error(column, error.to_string()))?,
+            (token, column) => return self.error(column, format!("Expected a value, found {}", token))
+        };
+
+        Ok(Expr::Compare { field, operator, value })
+    }
+
+    fn parse_field(&mut self) -> Result<Field, (usize, String)> {
+        match self.next() {
+            (Token::Word(word), column) => word.parse().or_else(|error: anyhow::Error| self.error(column, error.to_string())),
+            (token, column) => self.error(column, format!("Expected a field, found {}", token))
+        }
+    }
+}
+
+impl Query {
+
+    /// Parses a query, see `Query`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the query.
+    /// * `workflow` - The workflow the statuses of the query must belong to.
+    ///
+    /// # Errors
+    ///
+    /// This method returns a `QueryError` pointing at the first error in the query, including
+    /// statuses that are not part of the workflow.
+    pub fn parse(text: &str, workflow: &Workflow) -> Result<Self, QueryError> {
+        let to_error = |(column, message)| QueryError { query: text.to_owned(), column, message };
+
+        let tokens = tokenize(text).map_err(to_error)?;
+
+        Parser { tokens, position: 0, workflow }.parse_query().map_err(to_error)
+    }
+}
+
+impl Expr {
+
+    /// Returns `true` if the story with the given ID meets the condition.
+    fn matches(&self, story_id: u32, story: &Story, db_state: &DBState) -> bool {
+        match self {
+            Self::And(left, right) => left.matches(story_id, story, db_state) && right.matches(story_id, story, db_state),
+            Self::Or(left, right) => left.matches(story_id, story, db_state) || right.matches(story_id, story, db_state),
+            Self::Not(expr) => !expr.matches(story_id, story, db_state),
+            Self::IsEmpty { field, negated } => field.values(story_id, story, db_state).is_empty() != *negated,
+            Self::Compare { field, operator, value } => {
+                let values = field.values(story_id, story, db_state);
+
+                match (field, operator) {
+                    (Field::Label, Operator::NotEq) => !values.contains(value),
+                    _ => values.iter().any(|field_value| operator.matches(field_value, value))
+                }
+            }
+        }
+    }
+}
+
+impl Query {
+
+    /// Returns the stories meeting the filter of the query, in the order it asks for.
+    ///
+    /// Comparing a field the story has no value for, e.g. the points of a story that is not
+    /// estimated, never matches. When ordering, stories without a value come last.
+    pub fn run<'a>(&self, db_state: &'a DBState) -> Vec<(u32, &'a Story)> {
+        let mut stories: Vec<(u32, &Story)> = db_state.stories.iter()
+            .filter(|(id, story)| self.filter.as_ref().is_none_or(|filter| filter.matches(**id, story, db_state)))
+            .map(|(id, story)| (*id, story))
+            .collect();
+
+        stories.sort_by(|(a_id, a), (b_id, b)| {
+            self.order_by.iter()
+                .map(|order_by| {
+                    let a_value = order_by.field.values(*a_id, a, db_state).into_iter().next();
+                    let b_value = order_by.field.values(*b_id, b, db_state).into_iter().next();
+
+                    match (a_value, b_value) {
+                        (Some(a_value), Some(b_value)) if order_by.descending => b_value.cmp(&a_value),
+                        (Some(a_value), Some(b_value)) => a_value.cmp(&b_value),
+                        (a_value, b_value) => a_value.is_none().cmp(&b_value.is_none())
+                    }
+                })
+                .fold(Ordering::Equal, Ordering::then)
+                .then(a_id.cmp(b_id))
+        });
+
+        stories
+    }
+}
+
+// ------------------------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Epic, Status};
+
+    fn db_state() -> DBState {
+        let mut db_state = DBState::default();
+
+        db_state.epics.insert(1, Epic { stories: vec![2, 3], ..Epic::new("".to_owned(), "".to_owned()) });
+        db_state.epics.insert(4, Epic { stories: vec![5], ..Epic::new("".to_owned(), "".to_owned()) });
+
+        db_state.stories.insert(2, Story {
+            status: Status::InProgress, story_points: Some(3), priority: Priority::High, labels: vec!["backend".to_owned()],
+            ..Story::new("Login page".to_owned(), "".to_owned())
+        });
+        db_state.stories.insert(3, Story {
+            status: Status::InProgress, story_points: Some(5), assignee: Some("alice".to_owned()),
+            ..Story::new("Logout".to_owned(), "".to_owned())
+        });
+        db_state.stories.insert(5, Story {
+            issue_type: IssueType::Bug, story_points: Some(8), priority: Priority::Highest,
+            ..Story::new("Crash on start".to_owned(), "".to_owned())
+        });
+
+        db_state
+    }
+
+    fn run(query: &str) -> Vec<u32> {
+        Query::parse(query, &Workflow::default()).unwrap().run(&db_state()).into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn error(query: &str) -> (usize, String) {
+        let error = Query::parse(query, &Workflow::default()).unwrap_err();
+        (error.column, error.message)
+    }
+
+    #[test]
+    fn parse_should_build_filter_and_order() {
+        let query = Query::parse(r#"status = "IN PROGRESS" AND epic = 1 or NOT points > 2 ORDER BY id DESC, name"#, &Workflow::default()).unwrap();
+
+        let compare = |field, operator, value| Box::new(Expr::Compare { field, operator, value });
+
+        assert_eq!(query.filter, Some(Expr::Or(
+            Box::new(Expr::And(compare(Field::Status, Operator::Eq, Value::Text("IN PROGRESS".to_owned())), compare(Field::Epic, Operator::Eq, Value::Number(1)))),
+            Box::new(Expr::Not(compare(Field::Points, Operator::Gt, Value::Number(2))))
+        )));
+        assert_eq!(query.order_by, vec![OrderBy { field: Field::Id, descending: true }, OrderBy { field: Field::Name, descending: false }]);
+        assert_eq!(Query::parse("", &Workflow::default()).unwrap(), Query::default());
+    }
+
+    #[test]
+    fn run_should_filter_and_order_stories() {
+        assert_eq!(run(r#"status = "IN PROGRESS" AND epic = 1 AND points > 2 ORDER BY id DESC"#), vec![3, 2]);
+        assert_eq!(run("status = in-progress AND (points >= 5 OR label = BACKEND)"), vec![2, 3]);
+        assert_eq!(run("ORDER BY priority DESC"), vec![5, 2, 3]);
+        assert_eq!(run("priority > medium ORDER BY points"), vec![2, 5]);
+        assert_eq!(run("type = bug OR name ~ OUT"), vec![3, 5]);
+        assert_eq!(run("label != backend"), vec![3, 5]);
+        assert_eq!(run("assignee IS EMPTY"), vec![2, 5]);
+        assert_eq!(run("assignee != alice"), Vec::<u32>::new());
+        assert_eq!(run("ORDER BY assignee"), vec![3, 2, 5]);
+        assert_eq!(run(""), vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn parse_should_point_at_the_offending_column() {
+        assert_eq!(error(r#"status = "IN PROGRESS" AND epc = 3"#), (28, "Unknown field: epc".to_owned()));
+        assert_eq!(error("points > two"), (10, "Expected a number, found two".to_owned()));
+        assert_eq!(error("status > open"), (8, "Operator > cannot be used with status, use one of = !=".to_owned()));
+        assert_eq!(error("(status = open"), (1, "Unclosed parenthesis".to_owned()));
+        assert_eq!(error("name ~ \"log"), (8, "Unterminated string".to_owned()));
+        assert_eq!(error("points 3"), (8, "Expected an operator or IS after points, found 3".to_owned()));
+        assert_eq!(error("points = 3 points = 4"), (12, "Expected AND, OR or ORDER BY, found points".to_owned()));
+        assert_eq!(error("points ="), (9, "Expected a value, found end of query".to_owned()));
+        assert_eq!(error("priority = urgent"), (12, "Unknown priority: urgent".to_owned()));
+        assert_eq!(error("status = reslved"), (10, "RESLVED is not a status of the workflow, use one of OPEN, IN PROGRESS, RESOLVED, CLOSED".to_owned()));
+
+        let error = Query::parse("points > two", &Workflow::default()).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid query at column 10: Expected a number, found two\n  points > two\n           ^".to_owned());
+    }
+}
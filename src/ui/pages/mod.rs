@@ -1,20 +1,26 @@
 use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use colored::ColoredString;
-use itertools::Itertools;
 use anyhow::Result;
 use anyhow::anyhow;
 use colored::Colorize;
 
+use crate::config::Workflow;
 use crate::db::JiraDatabase;
-use crate::models::Action;
+use crate::models::{Action, Comment, DBState, IssueType, LinkType, SortKey, Sprint, Status, Story};
+use crate::query::Query;
+use chrono::{DateTime, Local, NaiveDate, Utc};
 
 mod page_helpers;
 use page_helpers::*;
 
 /// Returns a colored string corresponding to the given status.
 ///
+/// If the provided `status` is not one of the built-in statuses, it is a status of the configured
+/// workflow and the function returns it in cyan.
+///
 /// # Arguments
 ///
 /// * `status` - A string slice representing the status.
@@ -29,13 +35,10 @@ use page_helpers::*;
 /// println!("{}", colored_status);
 /// ```
 ///
-/// # Panics
-///
-/// If the provided `status` is not recognized, the function returns an empty colored string.
-///
 /// # Notes
 ///
-/// - The recognized status values are "OPEN", "IN PROGRESS", "RESOLVED", and "CLOSED".
+/// - The built-in status values are "OPEN", "IN PROGRESS", "RESOLVED", and "CLOSED", each with
+///   its own color. Every other status is colored cyan.
 ///
 /// # Returns
 ///
@@ -51,7 +54,176 @@ fn get_status_color(status: &str) -> ColoredString {
         "IN PROGRESS" => "IN PROGRESS".yellow(),
         "RESOLVED" => "RESOLVED".green(),
         "CLOSED" => "CLOSED".blue(),
-        _ => "".clear()
+        other => other.cyan()
+    }
+}
+
+/// Returns the given priority column colored by how urgent the priority is.
+///
+/// Unlike `get_status_color`, the padding of the column is kept, so the priority can be shown
+/// in the middle of a table row.
+///
+/// # Arguments
+///
+/// * `priority` - A string slice containing the priority, padded to the column width.
+///
+/// # Example
+///
+/// ```
+/// let priority_col = get_column_string("HIGH", 9);
+/// println!("{}", get_priority_color(&priority_col));
+/// ```
+///
+/// # Returns
+///
+/// A `ColoredString` representing the colorized column, or the plain column if the priority is
+/// not recognized.
+fn get_priority_color(priority: &str) -> ColoredString {
+    match priority.trim() {
+        "HIGHEST" => priority.red().bold(),
+        "HIGH" => priority.red(),
+        "MEDIUM" => priority.yellow(),
+        "LOW" => priority.green(),
+        "LOWEST" => priority.blue(),
+        _ => priority.clear()
+    }
+}
+
+/// Returns the given issue type column colored by the type of the Story.
+///
+/// Like `get_priority_color`, the padding of the column is kept.
+///
+/// # Arguments
+///
+/// * `issue_type` - A string slice containing the issue type, padded to the column width.
+///
+/// # Returns
+///
+/// A `ColoredString` representing the colorized column, or the plain column if the issue type is
+/// not recognized.
+fn get_issue_type_color(issue_type: &str) -> ColoredString {
+    match issue_type.trim() {
+        "STORY" => issue_type.green(),
+        "TASK" => issue_type.blue(),
+        "BUG" => issue_type.red(),
+        "SPIKE" => issue_type.purple(),
+        _ => issue_type.clear()
+    }
+}
+
+/// Returns the given sprint state column colored by where the sprint is in its life cycle.
+///
+/// # Arguments
+///
+/// * `state` - A string slice containing the sprint state, padded to the column width.
+fn get_sprint_state_color(state: &str) -> ColoredString {
+    match state.trim() {
+        "PLANNED" => state.blue(),
+        "ACTIVE" => state.yellow(),
+        "COMPLETED" => state.green(),
+        _ => state.clear()
+    }
+}
+
+/// Returns the given column highlighted if the item of its row is overdue.
+///
+/// # Arguments
+///
+/// * `column` - A string slice containing the column, padded to the column width.
+/// * `overdue` - Whether the item is past its due date and not done yet.
+fn get_overdue_color(column: &str, overdue: bool) -> ColoredString {
+    if overdue {
+        column.red().bold()
+    } else {
+        column.clear()
+    }
+}
+
+/// Formats an optional timestamp in local time, or `-` if there is none.
+fn get_time_string(time: &Option<DateTime<Utc>>) -> String {
+    time.map_or_else(|| "-".to_owned(), |time| time.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+}
+
+/// Formats an optional due date, or `-` if there is none.
+fn get_date_string(date: &Option<NaiveDate>) -> String {
+    date.map_or_else(|| "-".to_owned(), |date| date.to_string())
+}
+
+/// Returns the username shown for an assignee or owner, which is `-` if there is none.
+fn get_user_string(username: &Option<String>) -> String {
+    username.clone().unwrap_or_else(|| "-".to_owned())
+}
+
+/// Returns the menu entry toggling the "my work" filter.
+///
+/// # Arguments
+///
+/// * `mine` - Whether only the work of the current user is currently listed.
+fn get_work_filter_label(mine: bool) -> &'static str {
+    if mine {
+        "[w] all work"
+    } else {
+        "[w] my work"
+    }
+}
+
+/// Prints the comments on an Epic or Story, the oldest one first.
+///
+/// # Arguments
+///
+/// * `comments` - The comments along with their ids, see `DBState::comments_on`.
+fn draw_comments(comments: &[(u32, &Comment)]) {
+    println!("{}", "------------------------------ COMMENTS ------------------------------".cyan());
+
+    if comments.is_empty() {
+        println!("{}", "no comments yet".dimmed());
+    }
+
+    for (id, comment) in comments {
+        let edited = if comment.updated_at.is_some() { " (edited)" } else { "" };
+
+        println!("{} {} {}{}", format!("#{}", id).cyan(), comment.author.yellow(), get_time_string(&Some(comment.created_at)), edited.dimmed());
+
+        for line in comment.body.lines() {
+            println!("    {}", line);
+        }
+    }
+}
+
+/// Prints the links of an Epic or Story, in the order they were added.
+///
+/// Links to items that still block the Epic or Story are highlighted.
+///
+/// # Arguments
+///
+/// * `db_state` - The state of the database, used to look up the linked items.
+/// * `item_id` - The ID of the Epic or Story.
+/// * `workflow` - The workflow telling which linked items are done.
+fn draw_links(db_state: &DBState, item_id: u32, workflow: &Workflow) {
+    println!("{}", "------------------------------- LINKS -------------------------------".cyan());
+    println!("{}", "       link      |   id  |            name            |    status    ".cyan());
+
+    for link in db_state.links_of(item_id).into_iter().flatten() {
+        let Some((name, status)) = db_state.item_summary(link.item_id) else { continue };
+
+        let link_col = get_column_string(&link.link_type.to_string(), 16);
+        let link_color = match link.link_type {
+            LinkType::IsBlockedBy if !workflow.is_done(status) => link_col.red().bold(),
+            _ => link_col.as_str().clear()
+        };
+        let id_col = get_column_string(&link.item_id.to_string(), 5);
+        let name_col = get_column_string(name, 26);
+        let status_col = get_column_string(&status.to_string(), 13);
+        let status_color = get_status_color(&status_col);
+
+        println!("{} {} {} {} {} {} {}",
+                               link_color,
+                               "|".cyan(),
+                               id_col,
+                               "|".cyan(),
+                               name_col,
+                               "|".cyan(),
+                               status_color);
     }
 }
 
@@ -133,7 +305,7 @@ pub trait Page {
 /// use std::rc::Rc;
 ///
 /// let database = Rc::new(JiraDatabase::new());
-/// let home_page = HomePage { db: database.clone() };
+/// let home_page = HomePage::new(database.clone());
 /// ```
 pub struct HomePage {
 
@@ -141,7 +313,24 @@ pub struct HomePage {
     ///
     /// This field holds a shared reference to the JIRA database, allowing the home page to access
     /// and interact with the underlying data.
-    pub db: Rc<JiraDatabase>
+    pub db: Rc<JiraDatabase>,
+
+    /// The order of the epics, toggled with `[s]`.
+    pub sort_key: Cell<SortKey>,
+
+    /// Whether only the work of the current user is listed, toggled with `[w]`.
+    pub mine: Cell<bool>,
+
+    /// The label the epics are filtered on, selected with `#label` and cleared with `#`.
+    pub label: RefCell<Option<String>>
+}
+
+impl HomePage {
+
+    /// Constructs a new `HomePage` listing all the epics of the given database by id.
+    pub fn new(db: Rc<JiraDatabase>) -> Self {
+        Self { db, sort_key: Cell::new(SortKey::Id), mine: Cell::new(false), label: RefCell::new(None) }
+    }
 }
 
 impl Page for HomePage {
@@ -149,7 +338,10 @@ impl Page for HomePage {
     /// Draws the contents of the home page.
     ///
     /// This method prints the list of epics from the JIRA database, displaying their IDs, names,
-    /// and statuses in a formatted table on the command-line interface (CLI).
+    /// due dates, progress, priorities and statuses in a formatted table on the command-line
+    /// interface (CLI), ordered by the current sort key. Overdue epics are highlighted. When
+    /// showing "my work", only the epics owned by the current user or containing stories assigned
+    /// to them are listed, and when filtering on a label, only the epics carrying that label.
     ///
     /// # Errors
     ///
@@ -163,29 +355,52 @@ impl Page for HomePage {
     /// use std::rc::Rc;
     ///
     /// let database = Rc::new(JiraDatabase::new());
-    /// let home_page = HomePage { db: database.clone() };
+    /// let home_page = HomePage::new(database.clone());
     ///
     /// // Assuming database has been populated with epics
     /// let result = home_page.draw_page();
     /// assert!(result.is_ok());
     /// ```
     fn draw_page(&self) -> Result<()> {
-        println!("{}", "----------------------------- EPICS -----------------------------".cyan());
-        println!("{}", "     id     |               name               |      status      ".cyan());
-
-        let epics = self.db.read_db()?.epics;
+        println!("{}", "-------------------------------------- EPICS ---------------------------------------".cyan());
+        println!("{}", "  id  |         name         |    due     |   progress   |  priority |    status    ".cyan());
 
-        for id in epics.keys().sorted() {
-            let epic = &epics[id];
-            let id_col = get_column_string(&id.to_string(), 11);
-            let name_col = get_column_string(&epic.name, 32);
-            let status_col = get_column_string(&epic.status.to_string(), 17);
+        let db_state = self.db.read_db()?;
+        let sort_key = self.sort_key.get();
+        let mine = self.mine.get();
+        let current_user = self.db.config.current_user();
+        let label = self.label.borrow();
+        let today = Local::now().date_naive();
+
+        let epics = db_state.epics.iter()
+            .filter(|(_, epic)| {
+                !mine || epic.owner.as_ref() == Some(&current_user) || epic.stories.iter()
+                    .filter_map(|story_id| db_state.stories.get(story_id))
+                    .any(|story| story.assignee.as_ref() == Some(&current_user))
+            })
+            .filter(|(_, epic)| label.as_ref().is_none_or(|label| epic.labels.contains(label)));
+
+        for (id, epic) in sort_key.sort(epics, |epic| epic.priority) {
+            let overdue = epic.is_overdue(today, &self.db.config.workflow);
+            let id_col = get_column_string(&id.to_string(), 5);
+            let name_col = get_column_string(&epic.name, 20);
+            let due_col = get_column_string(&get_date_string(&epic.due_date), 10);
+            let progress_col = get_column_string(&get_progress_string(&epic.progress(&db_state.stories, &self.db.config.workflow)), 12);
+            let priority_col = get_column_string(&epic.priority.to_string(), 9);
+            let priority_color = get_priority_color(&priority_col);
+            let status_col = get_column_string(&epic.status.to_string(), 13);
             let status_color = get_status_color(&status_col);
 
-            println!("{} {} {} {} {}",
+            println!("{} {} {} {} {} {} {} {} {} {} {}",
                                     id_col,
                                     "|".cyan(),
-                                    name_col,
+                                    get_overdue_color(&name_col, overdue),
+                                    "|".cyan(),
+                                    get_overdue_color(&due_col, overdue),
+                                    "|".cyan(),
+                                    progress_col,
+                                    "|".cyan(),
+                                    priority_color,
                                     "|".cyan(),
                                     status_color);
         }
@@ -193,9 +408,35 @@ impl Page for HomePage {
         println!();
         println!();
 
-        println!("{} | {} | {}", "[q] quit".red(),
-                                 "[c] create epic".green(),
-                                 "[:id:] navigate to epic".yellow());
+        if let Some(label) = label.as_ref() {
+            println!("{} {}", "Showing epics labeled".cyan(), format!("#{}", label).yellow());
+            println!();
+        }
+
+        let filters = db_state.sorted_filters();
+
+        if !filters.is_empty() {
+            println!("{}", "Saved filters:".cyan());
+
+            for (i, (name, filter)) in filters.iter().enumerate() {
+                println!("  {} {} {}", format!("[v{}]", i + 1).yellow(), get_column_string(name, 20), filter.query.dimmed());
+            }
+
+            println!();
+        }
+
+        println!("{} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {}", "[q] quit".red(),
+                                                                    "[c] create epic".green(),
+                                                                    "[:id:] navigate to epic".yellow(),
+                                                                    "[r] sprints".yellow(),
+                                                                    "[b] board".yellow(),
+                                                                    "[/text] search".yellow(),
+                                                                    "[j] query".yellow(),
+                                                                    format!("[s] sort by {}", sort_key.toggled()).blue(),
+                                                                    get_work_filter_label(mine).blue(),
+                                                                    "[#label] filter by label".blue(),
+                                                                    "[z] undo".purple(),
+                                                                    "[y] redo".purple());
 
         Ok(())
     }
@@ -206,6 +447,8 @@ impl Page for HomePage {
     /// matches predefined commands such as quitting the application or creating a new epic, it
     /// returns the corresponding action. If the input represents an epic ID, it checks if the
     /// ID exists in the JIRA database and returns an action to navigate to the details of that epic.
+    /// If the input starts with `/`, it returns an action to search every epic and story for
+    /// the rest of the input, and `v` followed by the number of a saved filter runs its query.
     /// If the input does not match any predefined command or epic ID, it returns `None`.
     ///
     /// # Arguments
@@ -225,7 +468,7 @@ impl Page for HomePage {
     /// use std::rc::Rc;
     ///
     /// let database = Rc::new(JiraDatabase::new());
-    /// let home_page = HomePage { db: database.clone() };
+    /// let home_page = HomePage::new(database.clone());
     ///
     /// // Assuming database has been populated with epics
     /// let result = home_page.handle_input("1");
@@ -239,7 +482,36 @@ impl Page for HomePage {
         match input {
             "q" => Ok(Some(Action::Exit)),
             "c" => Ok(Some(Action::CreateEpic)),
+            "r" => Ok(Some(Action::NavigateToSprints)),
+            "b" => Ok(Some(Action::NavigateToBoard { epic_id: None })),
+            "j" => Ok(Some(Action::NavigateToQuery { query: String::new() })),
+            "s" => {
+                self.sort_key.set(self.sort_key.get().toggled());
+                Ok(None)
+            }
+            "w" => {
+                self.mine.set(!self.mine.get());
+                Ok(None)
+            }
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
             input => {
+                if let Some(label) = get_label_filter(input) {
+                    *self.label.borrow_mut() = label;
+                    return Ok(None);
+                }
+
+                if let Some(query) = input.strip_prefix('/').map(str::trim) {
+                    return Ok((!query.is_empty()).then(|| Action::NavigateToSearch { query: query.to_owned() }));
+                }
+
+                if let Some(number) = input.strip_prefix('v').and_then(|number| number.parse::<usize>().ok()) {
+                    let db_state = self.db.read_db()?;
+                    let filter = number.checked_sub(1).and_then(|i| db_state.sorted_filters().get(i).map(|(_, filter)| filter.query.clone()));
+
+                    return Ok(filter.map(|query| Action::NavigateToQuery { query }));
+                }
+
                 if let Ok(epic_id) = input.parse::<u32>() {
                     if epics.contains_key(&epic_id) {
                         return Ok(Some(Action::NavigateToEpicDetail { epic_id }));
@@ -292,7 +564,7 @@ impl Page for HomePage {
 /// use std::rc::Rc;
 ///
 /// let database = Rc::new(JiraDatabase::new());
-/// let epic_detail_page = EpicDetail { epic_id: 1, db: database.clone() };
+/// let epic_detail_page = EpicDetail::new(1, database.clone());
 /// ```
 pub struct EpicDetail {
     /// The ID of the Epic being displayed.
@@ -305,7 +577,24 @@ pub struct EpicDetail {
     ///
     /// This field holds a shared reference to the JIRA database, allowing the
     /// EpicDetail page to access and display data associated with the specified Epic.
-    pub db: Rc<JiraDatabase>
+    pub db: Rc<JiraDatabase>,
+
+    /// The order of the stories, toggled with `[s]`.
+    pub sort_key: Cell<SortKey>,
+
+    /// Whether only the stories assigned to the current user are listed, toggled with `[w]`.
+    pub mine: Cell<bool>,
+
+    /// The label the stories are filtered on, selected with `#label` and cleared with `#`.
+    pub label: RefCell<Option<String>>
+}
+
+impl EpicDetail {
+
+    /// Constructs a new `EpicDetail` page listing all the stories of the Epic by id.
+    pub fn new(epic_id: u32, db: Rc<JiraDatabase>) -> Self {
+        Self { epic_id, db, sort_key: Cell::new(SortKey::Id), mine: Cell::new(false), label: RefCell::new(None) }
+    }
 }
 
 impl Page for EpicDetail {
@@ -313,7 +602,7 @@ impl Page for EpicDetail {
     /// Draws the contents of the EpicDetail page.
     ///
     /// This method prints detailed information about the Epic, including its ID, name, description,
-    /// status, and associated stories. It retrieves the relevant data from the JIRA database and
+    /// status, associated stories and comments. It retrieves the relevant data from the JIRA database and
     /// formats it into a structured output on the command-line interface (CLI).
     ///
     /// # Errors
@@ -328,7 +617,7 @@ impl Page for EpicDetail {
     /// use std::rc::Rc;
     ///
     /// let database = Rc::new(JiraDatabase::new());
-    /// let epic_detail_page = EpicDetail { epic_id: 1, db: database.clone() };
+    /// let epic_detail_page = EpicDetail::new(1, database.clone());
     ///
     /// // Assuming database has been populated with the specified Epic and its associated stories
     /// let result = epic_detail_page.draw_page();
@@ -337,60 +626,127 @@ impl Page for EpicDetail {
     fn draw_page(&self) -> Result<()> {
         let db_state = self.db.read_db()?;
         let epic = db_state.epics.get(&self.epic_id).ok_or_else(|| anyhow!("could not find epic!".red().bold()))?;
+        let today = Local::now().date_naive();
 
-        println!("{}", "------------------------------ EPIC ------------------------------".cyan());
-        println!("{}", "  id  |     name     |         description         |    status    ".cyan());
+        println!("{}", "------------------------------------- EPIC -------------------------------------".cyan());
+        println!("{}", "  id  |     name     |         description         |  priority |    status    ".cyan());
 
         let id_col = get_column_string(&self.epic_id.to_string(), 5);
         let name_col = get_column_string(&epic.name, 12);
         let desc_col = get_column_string(&epic.description, 27);
+        let priority_col = get_column_string(&epic.priority.to_string(), 9);
+        let priority_color = get_priority_color(&priority_col);
         let status_col = get_column_string(&epic.status.to_string(), 13);
         let status_color = get_status_color(&status_col);
 
-        println!("{} {} {} {} {} {} {}",
+        println!("{} {} {} {} {} {} {} {} {}",
                                      id_col,
                                      "|".cyan(),
                                      name_col,
                                      "|".cyan(),
                                      desc_col,
                                      "|".cyan(),
+                                     priority_color,
+                                     "|".cyan(),
                                      status_color);
-        
-        println!();
 
-        println!("{}", "---------------------------- STORIES ----------------------------".cyan());
-        println!("{}", "     id     |               name               |      status      ".cyan());
+        println!("{} {}   {} {}", "owner:".cyan(), get_user_string(&epic.owner), "labels:".cyan(), get_labels_string(&epic.labels));
+        println!("{} {}   {} {}   {} {}", "created:".cyan(), get_time_string(&epic.created_at),
+                                          "updated:".cyan(), get_time_string(&epic.updated_at),
+                                          "due:".cyan(), get_overdue_color(&get_date_string(&epic.due_date), epic.is_overdue(today, &self.db.config.workflow)));
+
+        let progress = epic.progress(&db_state.stories, &self.db.config.workflow);
+
+        println!("{} {} {}", "progress:".cyan(), get_progress_bar(&progress, 20).green(), get_progress_string(&progress));
 
-        let stories = &db_state.stories;
+        println!();
 
-        for id in epic.stories.iter().sorted() {
-            let story = &stories[id];
-            let id_col = get_column_string(&id.to_string(), 11);
-            let name_col = get_column_string(&story.name, 32);
-            let status_col = get_column_string(&story.status.to_string(), 17);
+        println!("{}", "------------------------------------------- STORIES -------------------------------------------".cyan());
+        println!("{}", "  id  |  type |      name      |   assignee   |    due     | points |  priority |    status    ".cyan());
+
+        let sort_key = self.sort_key.get();
+        let mine = self.mine.get();
+        let current_user = self.db.config.current_user();
+        let label = self.label.borrow();
+
+        let stories = db_state.stories.iter()
+            .filter(|(id, _)| epic.stories.contains(id))
+            .filter(|(_, story)| !mine || story.assignee.as_ref() == Some(&current_user))
+            .filter(|(_, story)| label.as_ref().is_none_or(|label| story.labels.contains(label)));
+
+        for (id, story) in sort_key.sort(stories, |story| story.priority) {
+            let overdue = story.is_overdue(today, &self.db.config.workflow);
+            let id_col = get_column_string(&id.to_string(), 5);
+            let type_col = get_column_string(&story.issue_type.to_string(), 5);
+            let name_col = get_column_string(&story.name, 14);
+            let assignee_col = get_column_string(&get_user_string(&story.assignee), 12);
+            let due_col = get_column_string(&get_date_string(&story.due_date), 10);
+            let points_col = get_column_string(&story.story_points.map_or_else(|| "-".to_owned(), |points| points.to_string()), 6);
+            let priority_col = get_column_string(&story.priority.to_string(), 9);
+            let priority_color = get_priority_color(&priority_col);
+            let status_col = get_column_string(&story.status.to_string(), 13);
             let status_color = get_status_color(&status_col);
 
-            println!("{} {} {} {} {}",
+            println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
                                    id_col,
                                    "|".cyan(),
-                                   name_col,
+                                   get_issue_type_color(&type_col),
+                                   "|".cyan(),
+                                   get_overdue_color(&name_col, overdue),
+                                   "|".cyan(),
+                                   assignee_col,
+                                   "|".cyan(),
+                                   get_overdue_color(&due_col, overdue),
+                                   "|".cyan(),
+                                   points_col,
+                                   "|".cyan(),
+                                   priority_color,
                                    "|".cyan(),
                                    status_color);
         }
 
+        println!();
+
+        if let Some(label) = label.as_ref() {
+            println!("{} {}", "Showing stories labeled".cyan(), format!("#{}", label).yellow());
+            println!();
+        }
+
+        draw_comments(&db_state.comments_on(self.epic_id));
+
         println!();
         println!();
 
-        println!("{} {} {} {} {} {} {} {} {}",
+        println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
                                             "[p] previous".green(),
                                             "|".cyan(),
                                             "[u] update epic".yellow(),
                                             "|".cyan(),
+                                            "[e] edit epic".yellow(),
+                                            "|".cyan(),
+                                            "[a] assign owner".yellow(),
+                                            "|".cyan(),
                                             "[d] delete epic".red(),
                                             "|".cyan(),
                                             "[c] create story".blue(),
                                             "|".cyan(),
-                                            "[:id:] navigate to story".purple());
+                                            "[:id:] navigate to story".purple(),
+                                            "|".cyan(),
+                                            format!("[s] sort by {}", sort_key.toggled()).blue(),
+                                            "|".cyan(),
+                                            get_work_filter_label(mine).blue(),
+                                            "|".cyan(),
+                                            "[#label] filter by label".blue(),
+                                            "|".cyan(),
+                                            "[k] comment".blue(),
+                                            "|".cyan(),
+                                            "[h] history".blue(),
+                                            "|".cyan(),
+                                            "[b] board".blue(),
+                                            "|".cyan(),
+                                            "[z] undo".purple(),
+                                            "|".cyan(),
+                                            "[y] redo".purple());
 
         Ok(())
     }
@@ -421,7 +777,7 @@ impl Page for EpicDetail {
     /// use std::rc::Rc;
     ///
     /// let database = Rc::new(JiraDatabase::new());
-    /// let epic_detail_page = EpicDetail { epic_id: 1, db: database.clone() };
+    /// let epic_detail_page = EpicDetail::new(1, database.clone());
     ///
     /// // Assuming database has been populated with stories
     /// let result = epic_detail_page.handle_input("1");
@@ -437,9 +793,29 @@ impl Page for EpicDetail {
         match input {
             "p" => Ok(Some(Action::NavigateToPreviousPage)),
             "u" => Ok(Some(Action::UpdateEpicStatus { epic_id: self.epic_id })),
+            "e" => Ok(Some(Action::EditEpic { epic_id: self.epic_id })),
+            "a" => Ok(Some(Action::AssignEpic { epic_id: self.epic_id })),
             "d" => Ok(Some(Action::DeleteEpic { epic_id: self.epic_id })),
             "c" => Ok(Some(Action::CreateStory { epic_id: self.epic_id })),
+            "k" => Ok(Some(Action::AddComment { item_id: self.epic_id })),
+            "h" => Ok(Some(Action::NavigateToHistory { item_id: self.epic_id })),
+            "b" => Ok(Some(Action::NavigateToBoard { epic_id: Some(self.epic_id) })),
+            "s" => {
+                self.sort_key.set(self.sort_key.get().toggled());
+                Ok(None)
+            }
+            "w" => {
+                self.mine.set(!self.mine.get());
+                Ok(None)
+            }
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
             input => {
+                if let Some(label) = get_label_filter(input) {
+                    *self.label.borrow_mut() = label;
+                    return Ok(None);
+                }
+
                 if let Ok(story_id) = input.parse::<u32>() {
                     if stories.contains_key(&story_id) {
                         return Ok(Some(Action::NavigateToStoryDetail { epic_id: self.epic_id, story_id }));
@@ -521,7 +897,7 @@ impl Page for StoryDetail {
     /// Draws the contents of the StoryDetail page.
     ///
     /// This method prints detailed information about the Story, including its ID, name,
-    /// description, and status. It retrieves the relevant data from the JIRA database
+    /// description, status, sub-tasks, links and comments. It retrieves the relevant data from the JIRA database
     /// and formats it into a structured output on the command-line interface (CLI).
     ///
     /// # Errors
@@ -564,14 +940,86 @@ impl Page for StoryDetail {
                                      "|".cyan(),
                                      status_color);
 
+        if story.issue_type == IssueType::Bug {
+            println!("{} {}   {} {}   {} {}", "type:".cyan(), get_issue_type_color(&story.issue_type.to_string()),
+                                              "severity:".cyan(), story.severity.map_or_else(|| "-".to_owned(), |severity| severity.to_string()),
+                                              "affects:".cyan(), story.affected_version.as_deref().unwrap_or("-"));
+        } else {
+            println!("{} {}", "type:".cyan(), get_issue_type_color(&story.issue_type.to_string()));
+        }
+
+        println!("{} {}   {} {}   {} {}", "assignee:".cyan(), get_user_string(&story.assignee),
+                                          "labels:".cyan(), get_labels_string(&story.labels),
+                                          "points:".cyan(), story.story_points.map_or_else(|| "-".to_owned(), |points| points.to_string()));
+        println!("{} {}   {} {}   {} {}", "created:".cyan(), get_time_string(&story.created_at),
+                                          "updated:".cyan(), get_time_string(&story.updated_at),
+                                          "due:".cyan(), get_overdue_color(&get_date_string(&story.due_date), story.is_overdue(Local::now().date_naive(), &self.db.config.workflow)));
+
+        if let Some(steps_to_reproduce) = story.steps_to_reproduce.as_ref().filter(|_| story.issue_type == IssueType::Bug) {
+            println!("{}", "steps to reproduce:".cyan());
+
+            for line in steps_to_reproduce.lines() {
+                println!("    {}", line);
+            }
+        }
+
         println!();
-        println!();
 
-        println!("{} {} {} {} {}", "[p] previous".green(),
+        println!("{}", "------------------- SUB-TASKS --------------------".cyan());
+        println!("{}", "  id  |            name            |    status    ".cyan());
+
+        for (id, subtask) in story.subtasks.iter().filter_map(|id| db_state.subtasks.get(id).map(|subtask| (id, subtask))) {
+            let id_col = get_column_string(&id.to_string(), 5);
+            let name_col = get_column_string(&subtask.name, 26);
+            let status_col = get_column_string(&subtask.status.to_string(), 13);
+            let status_color = get_status_color(&status_col);
+
+            println!("{} {} {} {} {}",
+                                   id_col,
                                    "|".cyan(),
-                                   "[u] update story".yellow(), 
+                                   name_col,
                                    "|".cyan(),
-                                   "[d] delete story".red());
+                                   status_color);
+        }
+
+        println!();
+
+        draw_links(&db_state, self.story_id, &self.db.config.workflow);
+
+        println!();
+
+        draw_comments(&db_state.comments_on(self.story_id));
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {}", "[p] previous".green(),
+                                               "|".cyan(),
+                                               "[u] update story".yellow(),
+                                               "|".cyan(),
+                                               "[e] edit story".yellow(),
+                                               "|".cyan(),
+                                               "[a] assign".yellow(),
+                                               "|".cyan(),
+                                               "[m] move story".blue(),
+                                               "|".cyan(),
+                                               "[d] delete story".red(),
+                                               "|".cyan(),
+                                               "[c] create sub-task".blue(),
+                                               "|".cyan(),
+                                               "[:id:] navigate to sub-task".purple(),
+                                               "|".cyan(),
+                                               "[l] link".blue(),
+                                               "|".cyan(),
+                                               "[x] remove link".red(),
+                                               "|".cyan(),
+                                               "[k] comment".blue(),
+                                               "|".cyan(),
+                                               "[h] history".blue(),
+                                               "|".cyan(),
+                                               "[z] undo".purple(),
+                                               "|".cyan(),
+                                               "[y] redo".purple());
 
         Ok(())
     }
@@ -580,8 +1028,9 @@ impl Page for StoryDetail {
     ///
     /// This method interprets the user input and maps it to corresponding actions. If the input
     /// matches predefined commands such as navigating to the previous page, updating the story status,
-    /// or deleting the story, it returns the corresponding action. If the input does not match any
-    /// predefined command, it returns `None`.
+    /// or deleting the story, it returns the corresponding action. If the input represents the ID
+    /// of a sub-task of the story, it returns an action to navigate to the details of that
+    /// sub-task. If the input does not match any predefined command or sub-task ID, it returns `None`.
     ///
     /// # Arguments
     ///
@@ -603,9 +1052,28 @@ impl Page for StoryDetail {
     fn handle_input(&self, input: &str) -> Result<Option<Action>> {
         match input {
             "p" => Ok(Some(Action::NavigateToPreviousPage)),
-            "u" => Ok(Some(Action::UpdateStoryStatus { story_id: self.story_id })),
+            "u" => Ok(Some(Action::UpdateStoryStatus { story_id: self.story_id, status: None })),
+            "c" => Ok(Some(Action::CreateSubtask { story_id: self.story_id })),
+            "e" => Ok(Some(Action::EditStory { story_id: self.story_id })),
+            "a" => Ok(Some(Action::AssignStory { story_id: self.story_id })),
+            "m" => Ok(Some(Action::MoveStory { epic_id: self.epic_id, story_id: self.story_id })),
             "d" => Ok(Some(Action::DeleteStory { epic_id: self.epic_id, story_id: self.story_id })),
-            _ => Ok(None)
+            "k" => Ok(Some(Action::AddComment { item_id: self.story_id })),
+            "l" => Ok(Some(Action::AddLink { item_id: self.story_id })),
+            "x" => Ok(Some(Action::RemoveLink { item_id: self.story_id })),
+            "h" => Ok(Some(Action::NavigateToHistory { item_id: self.story_id })),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            input => {
+                if let Ok(subtask_id) = input.parse::<u32>() {
+                    let db_state = self.db.read_db()?;
+
+                    if db_state.stories.get(&self.story_id).is_some_and(|story| story.subtasks.contains(&subtask_id)) {
+                        return Ok(Some(Action::NavigateToSubtaskDetail { story_id: self.story_id, subtask_id }));
+                    }
+                }
+                Ok(None)
+            }
         }
     }
 
@@ -614,103 +1082,1087 @@ impl Page for StoryDetail {
     }
 }
 
-// -------------------------------------------------------------- UNIT TESTING
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{db::test_utils::MockDB};
-    use crate::models::{Epic, Story};
+/// Represents the detail page for a Sub-task in the user interface.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::SubtaskDetail;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let subtask_detail_page = SubtaskDetail { story_id: 2, subtask_id: 3, db: database.clone() };
+/// ```
+pub struct SubtaskDetail {
 
-    mod home_page {
-        use super::*;
+    /// The ID of the Story to which the Sub-task belongs.
+    pub story_id: u32,
 
-        #[test]
-        fn draw_page_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+    /// The ID of the Sub-task being displayed.
+    pub subtask_id: u32,
 
-            let page = HomePage { db };
-            assert_eq!(page.draw_page().is_ok(), true);
-        }
-        
-        #[test]
-        fn handle_input_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
 
-            let page = HomePage { db };
-            assert_eq!(page.handle_input("").is_ok(), true);
-        }
+impl Page for SubtaskDetail {
 
-        #[test]
-        fn handle_input_should_return_the_correct_actions() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+    /// Draws the contents of the SubtaskDetail page, i.e. the name and status of the Sub-task and
+    /// when it was created and last changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Sub-task with the specified ID is not found in the JIRA database.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let subtask = db_state.subtasks.get(&self.subtask_id).ok_or_else(|| anyhow!("could not find sub-task!".red().bold()))?;
 
-            let epic = Epic::new("".to_owned(), "".to_owned());
+        println!("{}", "-------------------- SUB-TASK --------------------".cyan());
+        println!("{}", "  id  |            name            |    status    ".cyan());
 
-            let epic_id = db.create_epic(epic).unwrap();
+        let id_col = get_column_string(&self.subtask_id.to_string(), 5);
+        let name_col = get_column_string(&subtask.name, 26);
+        let status_col = get_column_string(&subtask.status.to_string(), 13);
+        let status_color = get_status_color(&status_col);
 
-            let page = HomePage { db };
+        println!("{} {} {} {} {}",
+                                     id_col,
+                                     "|".cyan(),
+                                     name_col,
+                                     "|".cyan(),
+                                     status_color);
 
-            let q = "q";
-            let c = "c";
-            let valid_epic_id = epic_id.to_string();
-            let invalid_epic_id = "999";
-            let junk_input = "j983f2j";
-            let junk_input_with_valid_prefix = "q983f2j";
-            let input_with_trailing_white_spaces = "q\n";
+        println!("{} {}   {} {}   {} {}", "story:".cyan(), self.story_id,
+                                          "created:".cyan(), get_time_string(&subtask.created_at),
+                                          "updated:".cyan(), get_time_string(&subtask.updated_at));
 
-            assert_eq!(page.handle_input(q).unwrap(), Some(Action::Exit));
-            assert_eq!(page.handle_input(c).unwrap(), Some(Action::CreateEpic));
-            assert_eq!(page.handle_input(&valid_epic_id).unwrap(), Some(Action::NavigateToEpicDetail { epic_id: 1 }));
-            assert_eq!(page.handle_input(invalid_epic_id).unwrap(), None);
-            assert_eq!(page.handle_input(junk_input).unwrap(), None);
-            assert_eq!(page.handle_input(junk_input_with_valid_prefix).unwrap(), None);
-            assert_eq!(page.handle_input(input_with_trailing_white_spaces).unwrap(), None);
-        }
-    }
+        println!();
+        println!();
 
-    mod epic_detail_page {
-        use super::*;
+        println!("{} {} {} {} {} {} {} {} {} {} {}", "[p] previous".green(),
+                                               "|".cyan(),
+                                               "[u] update sub-task".yellow(),
+                                               "|".cyan(),
+                                               "[d] delete sub-task".red(),
+                                               "|".cyan(),
+                                               "[h] history".blue(),
+                                               "|".cyan(),
+                                               "[z] undo".purple(),
+                                               "|".cyan(),
+                                               "[y] redo".purple());
 
-        #[test]
-        fn draw_page_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
-            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+        Ok(())
+    }
 
-            let page = EpicDetail { epic_id, db };
+    /// Handles user input on the SubtaskDetail page.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        match input {
+            "p" => Ok(Some(Action::NavigateToPreviousPage)),
+            "u" => Ok(Some(Action::UpdateSubtaskStatus { subtask_id: self.subtask_id })),
+            "d" => Ok(Some(Action::DeleteSubtask { story_id: self.story_id, subtask_id: self.subtask_id })),
+            "h" => Ok(Some(Action::NavigateToHistory { item_id: self.subtask_id })),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            _ => Ok(None)
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the history page of an Epic, Story or Sub-task in the user interface.
+///
+/// The History page lists every change recorded in the change log for the given item, oldest
+/// first, with when it was made, by whom, and the old and new value of the changed field.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::HistoryPage;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let history_page = HistoryPage { item_id: 1, db: database.clone() };
+/// ```
+pub struct HistoryPage {
+
+    /// The ID of the Epic, Story or Sub-task whose history is being displayed.
+    pub item_id: u32,
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl Page for HistoryPage {
+
+    /// Draws the contents of the History page.
+    ///
+    /// Timestamps are shown in local time. Created and deleted items show their name as the
+    /// new or old value respectively.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let id = self.item_id.to_string();
+
+        println!("{}", "------------------------------------ HISTORY ------------------------------------".cyan());
+        println!("{}", "       time       |    actor    |    field    |               change               ".cyan());
+
+        let events = db_state.change_log.iter()
+            .filter(|event| event.id == id && ["epics", "stories", "subtasks"].contains(&event.collection.as_str()));
+
+        for event in events {
+            let time = event.timestamp.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string();
+            let change = format!("{} -> {}", get_value_string(&event.old_value), get_value_string(&event.new_value));
+
+            let time_col = get_column_string(&time, 17);
+            let actor_col = get_column_string(&event.actor, 12);
+            let field_col = get_column_string(&event.field, 12);
+            let change_col = get_column_string(&change, 35);
+
+            println!("{} {} {} {} {} {} {}",
+                                         time_col,
+                                         "|".cyan(),
+                                         actor_col,
+                                         "|".cyan(),
+                                         field_col,
+                                         "|".cyan(),
+                                         change_col);
+        }
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {}", "[p] previous".green(), "|".cyan(), "[z] undo".purple(), "|".cyan(), "[y] redo".purple());
+
+        Ok(())
+    }
+
+    /// Handles user input on the History page, where the only commands are going back, undo
+    /// and redo.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        match input {
+            "p" => Ok(Some(Action::NavigateToPreviousPage)),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            _ => Ok(None)
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the results of a search across the names, descriptions and comments of every Epic
+/// and Story in the user interface, reached with `/text` from the home page.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::SearchResults;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let search_results = SearchResults { query: "login".to_owned(), db: database.clone() };
+/// ```
+pub struct SearchResults {
+
+    /// The text searched for, matched ignoring case.
+    pub query: String,
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl Page for SearchResults {
+
+    /// Draws the contents of the Search Results page, i.e. one line per matching field with the
+    /// matched text highlighted.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let hits = db_state.search(&self.query);
+
+        println!("{}", "------------------------------------- SEARCH -------------------------------------".cyan());
+        println!("{}", "     id     |  type  |    field     |                    match                    ".cyan());
+
+        for hit in &hits {
+            let issue_type = match db_state.stories.get(&hit.item_id) {
+                Some(story) => story.issue_type.to_string(),
+                None => "EPIC".to_owned()
+            };
+            let (before, matched, after) = get_snippet(&hit.text, hit.matched.clone(), 44);
+
+            let id_col = get_column_string(&hit.item_id.to_string(), 11);
+            let type_col = get_column_string(&issue_type, 6);
+            let field_col = get_column_string(&hit.field.to_string(), 12);
+
+            println!("{} {} {} {} {} {} {}{}{}",
+                                           id_col,
+                                           "|".cyan(),
+                                           get_issue_type_color(&type_col),
+                                           "|".cyan(),
+                                           field_col,
+                                           "|".cyan(),
+                                           before,
+                                           matched.black().on_yellow(),
+                                           after);
+        }
+
+        println!();
+
+        match hits.len() {
+            0 => println!("{} {}", "No epic or story matches".cyan(), format!("\"{}\"", self.query).yellow()),
+            count => println!("{} {} {}", count.to_string().yellow(), "matches for".cyan(), format!("\"{}\"", self.query).yellow())
+        }
+
+        println!();
+
+        println!("{} {} {} {} {} {} {}", "[p] previous".green(),
+                                         "|".cyan(),
+                                         "[:id:] navigate to epic or story".yellow(),
+                                         "|".cyan(),
+                                         "[z] undo".purple(),
+                                         "|".cyan(),
+                                         "[y] redo".purple());
+
+        Ok(())
+    }
+
+    /// Handles user input on the Search Results page.
+    ///
+    /// Entering the ID of a matching Epic or Story navigates to its detail page.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are issues reading the JIRA database.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        match input {
+            "p" => return Ok(Some(Action::NavigateToPreviousPage)),
+            "z" => return Ok(Some(Action::Undo)),
+            "y" => return Ok(Some(Action::Redo)),
+            _ => {}
+        }
+
+        let db_state = self.db.read_db()?;
+
+        let Some(item_id) = input.parse::<u32>().ok().filter(|item_id| db_state.search(&self.query).iter().any(|hit| hit.item_id == *item_id)) else {
+            return Ok(None);
+        };
+
+        if db_state.epics.contains_key(&item_id) {
+            return Ok(Some(Action::NavigateToEpicDetail { epic_id: item_id }));
+        }
+
+        Ok(db_state.epic_of(item_id).map(|epic_id| Action::NavigateToStoryDetail { epic_id, story_id: item_id }))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the stories matching a query in the user interface, see `Query` for the language.
+///
+/// Entering text that is neither a command nor the ID of a story replaces the query.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::QueryPage;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let query_page = QueryPage::new("status = open ORDER BY priority DESC".to_owned(), database.clone());
+/// ```
+pub struct QueryPage {
+
+    /// The text of the query, which may not be valid.
+    pub query: RefCell<String>,
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl QueryPage {
+
+    /// Constructs a new `QueryPage` running the given query.
+    pub fn new(query: String, db: Rc<JiraDatabase>) -> Self {
+        Self { query: RefCell::new(query), db }
+    }
+}
+
+impl Page for QueryPage {
+
+    /// Draws the contents of the Query page, i.e. the stories matching the query, or the error
+    /// pointing at where the query is invalid.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let query = self.query.borrow();
+        let today = Local::now().date_naive();
+
+        println!("{}", "------------------------------------------ QUERY -------------------------------------------".cyan());
+        println!("{} {}", "query:".cyan(), query);
+        println!();
+
+        match Query::parse(&query, &self.db.config.workflow) {
+            Err(error) => println!("{}", error.to_string().red()),
+            Ok(parsed_query) => {
+                println!("{}", "  id  |  epic |  type |       name       |   assignee   | points |  priority |    status    ".cyan());
+
+                for (id, story) in parsed_query.run(&db_state) {
+                    let overdue = story.is_overdue(today, &self.db.config.workflow);
+                    let id_col = get_column_string(&id.to_string(), 5);
+                    let epic_col = get_column_string(&db_state.epic_of(id).map_or_else(|| "-".to_owned(), |epic_id| epic_id.to_string()), 5);
+                    let type_col = get_column_string(&story.issue_type.to_string(), 5);
+                    let name_col = get_column_string(&story.name, 16);
+                    let assignee_col = get_column_string(&get_user_string(&story.assignee), 12);
+                    let points_col = get_column_string(&story.story_points.map_or_else(|| "-".to_owned(), |points| points.to_string()), 6);
+                    let priority_col = get_column_string(&story.priority.to_string(), 9);
+                    let status_col = get_column_string(&story.status.to_string(), 13);
+
+                    println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
+                                           id_col,
+                                           "|".cyan(),
+                                           epic_col,
+                                           "|".cyan(),
+                                           get_issue_type_color(&type_col),
+                                           "|".cyan(),
+                                           get_overdue_color(&name_col, overdue),
+                                           "|".cyan(),
+                                           assignee_col,
+                                           "|".cyan(),
+                                           points_col,
+                                           "|".cyan(),
+                                           get_priority_color(&priority_col),
+                                           "|".cyan(),
+                                           get_status_color(&status_col));
+                }
+            }
+        }
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {} {} {} {} {} {} {}",
+                                "[p] previous".green(),
+                                "|".cyan(),
+                                "[:id:] navigate to story".yellow(),
+                                "|".cyan(),
+                                "[s] save as filter".yellow(),
+                                "|".cyan(),
+                                "[z] undo".purple(),
+                                "|".cyan(),
+                                "[y] redo".purple(),
+                                "|".cyan(),
+                                "[:query:] run another query, e.g. points > 2 ORDER BY priority DESC".blue());
+
+        Ok(())
+    }
+
+    /// Handles user input on the Query page.
+    ///
+    /// Entering the ID of a matching story navigates to it, `s` saves a valid query under a name,
+    /// and `z` and `y` undo and redo. Any other input that is not a number becomes the new query,
+    /// even if it is invalid, so the error can be shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are issues reading the JIRA database.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        match input {
+            "p" => return Ok(Some(Action::NavigateToPreviousPage)),
+            "z" => return Ok(Some(Action::Undo)),
+            "y" => return Ok(Some(Action::Redo)),
+            "s" => {
+                let query = self.query.borrow();
+                return Ok(Query::parse(&query, &self.db.config.workflow).is_ok().then(|| Action::SaveFilter { query: query.clone() }));
+            }
+            _ => {}
+        }
+
+        let Ok(story_id) = input.parse::<u32>() else {
+            *self.query.borrow_mut() = input.to_owned();
+            return Ok(None);
+        };
+
+        let db_state = self.db.read_db()?;
+        let matches = Query::parse(&self.query.borrow(), &self.db.config.workflow).is_ok_and(|query| query.run(&db_state).iter().any(|(id, _)| *id == story_id));
+
+        if !matches {
+            return Ok(None);
+        }
+
+        Ok(db_state.epic_of(story_id).map(|epic_id| Action::NavigateToStoryDetail { epic_id, story_id }))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Width of a column of the board, in characters.
+const BOARD_COLUMN_WIDTH: usize = 20;
+
+/// Represents the kanban board of the stories of an Epic, or of every Epic, in the user interface.
+///
+/// The board shows one column per status of the workflow, followed by a column for any other
+/// status a story is in, and moves a story to the next or previous column the workflow allows
+/// with `>id` or `<id`.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::BoardPage;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let board_page = BoardPage { epic_id: Some(1), db: database.clone() };
+/// ```
+pub struct BoardPage {
+
+    /// The ID of the Epic whose stories are shown, or `None` to show the stories of every Epic.
+    pub epic_id: Option<u32>,
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl BoardPage {
+
+    /// Returns the stories on the board, from the most to the least urgent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Epic of the board is not found in the JIRA database.
+    fn stories<'a>(&self, db_state: &'a DBState) -> Result<Vec<(u32, &'a Story)>> {
+        let epic_stories = match self.epic_id {
+            Some(epic_id) => Some(&db_state.epics.get(&epic_id).ok_or_else(|| anyhow!("could not find epic!".red().bold()))?.stories),
+            None => None
+        };
+
+        let stories = db_state.stories.iter()
+            .filter(|(id, _)| epic_stories.is_none_or(|epic_stories| epic_stories.contains(id)));
+
+        Ok(SortKey::Priority.sort(stories, |story| story.priority))
+    }
+
+    /// Returns the statuses of the columns of the board: those of the workflow, followed by any
+    /// other status one of the given stories is in.
+    fn columns(&self, stories: &[(u32, &Story)]) -> Vec<Status> {
+        let mut columns = self.db.config.workflow.statuses();
+
+        for (_, story) in stories {
+            if !columns.contains(&story.status) {
+                columns.push(story.status.clone());
+            }
+        }
+
+        columns
+    }
+}
+
+impl Page for BoardPage {
+
+    /// Draws the contents of the Board page, i.e. the stories as cards laid out in one column per
+    /// status. Overdue stories are highlighted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Epic of the board is not found in the JIRA database.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let stories = self.stories(&db_state)?;
+        let columns = self.columns(&stories);
+        let today = Local::now().date_naive();
+
+        let cards: Vec<Vec<&(u32, &Story)>> = columns.iter()
+            .map(|status| stories.iter().filter(|(_, story)| &story.status == status).collect())
+            .collect();
+
+        let scope = match self.epic_id.and_then(|epic_id| db_state.epics.get(&epic_id)) {
+            Some(epic) => epic.name.clone(),
+            None => "all epics".to_owned()
+        };
+        let width = columns.len() * (BOARD_COLUMN_WIDTH + 3) - 3;
+
+        println!("{}", format!("{:-^width$}", format!(" BOARD: {} ", scope), width = width).cyan());
+
+        let header: Vec<String> = columns.iter().zip(&cards)
+            .map(|(status, cards)| get_column_string(&format!(" {} ({})", status, cards.len()), BOARD_COLUMN_WIDTH))
+            .collect();
+
+        println!("{}", header.join(" | ").cyan());
+
+        let rows = cards.iter().map(Vec::len).max().unwrap_or(0);
+
+        for row in 0..rows {
+            let cells: Vec<String> = cards.iter()
+                .map(|cards| match cards.get(row) {
+                    Some((id, story)) => {
+                        let card_col = get_column_string(&format!(" {} {}", id, story.name), BOARD_COLUMN_WIDTH);
+                        get_overdue_color(&card_col, story.is_overdue(today, &self.db.config.workflow)).to_string()
+                    }
+                    None => get_column_string("", BOARD_COLUMN_WIDTH)
+                })
+                .collect();
+
+            println!("{}", cells.join(&" | ".cyan().to_string()));
+        }
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {} {} {} {} {} {} {}",
+                                            "[p] previous".green(),
+                                            "|".cyan(),
+                                            "[:id:] navigate to story".purple(),
+                                            "|".cyan(),
+                                            "[>id] move to next column".yellow(),
+                                            "|".cyan(),
+                                            "[<id] move to previous column".yellow(),
+                                            "|".cyan(),
+                                            "[z] undo".purple(),
+                                            "|".cyan(),
+                                            "[y] redo".purple());
+
+        Ok(())
+    }
+
+    /// Handles user input on the Board page.
+    ///
+    /// Entering `>id` or `<id` moves the story with that id to the nearest column after or before
+    /// its own whose status the workflow allows it to move to, skipping the columns it cannot
+    /// move to. Nothing happens if there is no such column.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are issues reading the JIRA database.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        let db_state = self.db.read_db()?;
+        let stories = self.stories(&db_state)?;
+        let find_story = |input: &str| input.parse::<u32>().ok()
+            .and_then(|story_id| stories.iter().find(|(id, _)| *id == story_id));
+
+        match input {
+            "p" => Ok(Some(Action::NavigateToPreviousPage)),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            input => {
+                let (forward, input) = match input.strip_prefix('>') {
+                    Some(input) => (Some(true), input),
+                    None => (input.strip_prefix('<').map(|_| false), input.trim_start_matches('<'))
+                };
+
+                let Some((story_id, story)) = find_story(input) else { return Ok(None) };
+
+                let Some(forward) = forward else {
+                    return Ok(db_state.epic_of(*story_id).map(|epic_id| Action::NavigateToStoryDetail { epic_id, story_id: *story_id }));
+                };
+
+                let columns = self.columns(&stories);
+                let column = columns.iter().position(|status| status == &story.status).unwrap_or(0);
+                let next_statuses = self.db.config.workflow.next_statuses(&story.status);
+
+                let next_status = if forward {
+                    columns[column + 1..].iter().find(|status| next_statuses.contains(status))
+                } else {
+                    columns[..column].iter().rev().find(|status| next_statuses.contains(status))
+                };
+
+                Ok(next_status.map(|status| Action::UpdateStoryStatus { story_id: *story_id, status: Some(status.clone()) }))
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the list of sprints in the user interface, reached from the home page.
+///
+/// The Sprint page lists every Sprint, the earliest one first, with its dates, the progress of its
+/// stories and where it is in its life cycle.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::SprintPage;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let sprint_page = SprintPage { db: database.clone() };
+/// ```
+pub struct SprintPage {
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl Page for SprintPage {
+
+    /// Draws the contents of the Sprint page, i.e. a table of every Sprint ordered by start date.
+    fn draw_page(&self) -> Result<()> {
+        println!("{}", "------------------------------------- SPRINTS -------------------------------------".cyan());
+        println!("{}", "  id  |         name         |   start    |    end     |   progress   |   state    ".cyan());
+
+        let db_state = self.db.read_db()?;
+
+        let mut sprints: Vec<(&u32, &Sprint)> = db_state.sprints.iter().collect();
+        sprints.sort_by_key(|(id, sprint)| (sprint.start_date, **id));
+
+        for (id, sprint) in sprints {
+            let id_col = get_column_string(&id.to_string(), 5);
+            let name_col = get_column_string(&sprint.name, 20);
+            let start_col = get_column_string(&sprint.start_date.to_string(), 10);
+            let end_col = get_column_string(&sprint.end_date.to_string(), 10);
+            let progress_col = get_column_string(&get_progress_string(&sprint.progress(&db_state.stories, &self.db.config.workflow)), 12);
+            let state_col = get_column_string(&sprint.state.to_string(), 11);
+
+            println!("{} {} {} {} {} {} {} {} {} {} {}",
+                                    id_col,
+                                    "|".cyan(),
+                                    name_col,
+                                    "|".cyan(),
+                                    start_col,
+                                    "|".cyan(),
+                                    end_col,
+                                    "|".cyan(),
+                                    progress_col,
+                                    "|".cyan(),
+                                    get_sprint_state_color(&state_col));
+        }
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {} {} {} {} {}", "[p] previous".green(),
+                                               "|".cyan(),
+                                               "[c] create sprint".green(),
+                                               "|".cyan(),
+                                               "[:id:] navigate to sprint".yellow(),
+                                               "|".cyan(),
+                                               "[z] undo".purple(),
+                                               "|".cyan(),
+                                               "[y] redo".purple());
+
+        Ok(())
+    }
+
+    /// Handles user input on the Sprint page.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are issues reading the JIRA database.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        let sprints = self.db.read_db()?.sprints;
+
+        match input {
+            "p" => Ok(Some(Action::NavigateToPreviousPage)),
+            "c" => Ok(Some(Action::CreateSprint)),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            input => {
+                if let Ok(sprint_id) = input.parse::<u32>() {
+                    if sprints.contains_key(&sprint_id) {
+                        return Ok(Some(Action::NavigateToSprintDetail { sprint_id }));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the detail page of a Sprint in the user interface.
+///
+/// The SprintDetail page shows the goal and dates of a Sprint and lists its stories, from where
+/// stories can be added to or removed from the Sprint, and the Sprint started or completed.
+///
+/// # Example
+///
+/// ```
+/// use crate::ui::pages::SprintDetail;
+/// use crate::JiraDatabase;
+/// use std::rc::Rc;
+///
+/// let database = Rc::new(JiraDatabase::new());
+/// let sprint_detail_page = SprintDetail { sprint_id: 1, db: database.clone() };
+/// ```
+pub struct SprintDetail {
+
+    /// The ID of the Sprint being displayed.
+    pub sprint_id: u32,
+
+    /// Reference-counted pointer to the JIRA database.
+    pub db: Rc<JiraDatabase>
+}
+
+impl Page for SprintDetail {
+
+    /// Draws the contents of the SprintDetail page, i.e. the name, dates, state, goal and progress
+    /// of the Sprint followed by its stories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Sprint with the specified ID is not found in the JIRA database.
+    fn draw_page(&self) -> Result<()> {
+        let db_state = self.db.read_db()?;
+        let sprint = db_state.sprints.get(&self.sprint_id).ok_or_else(|| anyhow!("could not find sprint!".red().bold()))?;
+
+        println!("{}", "------------------------------ SPRINT ------------------------------".cyan());
+        println!("{}", "  id  |         name         |   start    |    end     |   state    ".cyan());
+
+        let id_col = get_column_string(&self.sprint_id.to_string(), 5);
+        let name_col = get_column_string(&sprint.name, 20);
+        let start_col = get_column_string(&sprint.start_date.to_string(), 10);
+        let end_col = get_column_string(&sprint.end_date.to_string(), 10);
+        let state_col = get_column_string(&sprint.state.to_string(), 11);
+
+        println!("{} {} {} {} {} {} {} {} {}",
+                                     id_col,
+                                     "|".cyan(),
+                                     name_col,
+                                     "|".cyan(),
+                                     start_col,
+                                     "|".cyan(),
+                                     end_col,
+                                     "|".cyan(),
+                                     get_sprint_state_color(&state_col));
+
+        println!("{} {}", "goal:".cyan(), if sprint.goal.is_empty() { "-" } else { &sprint.goal });
+
+        let progress = sprint.progress(&db_state.stories, &self.db.config.workflow);
+
+        println!("{} {} {}", "progress:".cyan(), get_progress_bar(&progress, 20).green(), get_progress_string(&progress));
+
+        println!();
+
+        println!("{}", "--------------------------------- STORIES ----------------------------------".cyan());
+        println!("{}", "  id  |  type |         name         |   assignee   | points |    status    ".cyan());
+
+        for story_id in &sprint.stories {
+            let Some(story) = db_state.stories.get(story_id) else { continue };
+
+            let id_col = get_column_string(&story_id.to_string(), 5);
+            let type_col = get_column_string(&story.issue_type.to_string(), 5);
+            let name_col = get_column_string(&story.name, 20);
+            let assignee_col = get_column_string(&get_user_string(&story.assignee), 12);
+            let points_col = get_column_string(&story.story_points.map_or_else(|| "-".to_owned(), |points| points.to_string()), 6);
+            let status_col = get_column_string(&story.status.to_string(), 13);
+            let status_color = get_status_color(&status_col);
+
+            println!("{} {} {} {} {} {} {} {} {} {} {}",
+                                   id_col,
+                                   "|".cyan(),
+                                   get_issue_type_color(&type_col),
+                                   "|".cyan(),
+                                   name_col,
+                                   "|".cyan(),
+                                   assignee_col,
+                                   "|".cyan(),
+                                   points_col,
+                                   "|".cyan(),
+                                   status_color);
+        }
+
+        println!();
+        println!();
+
+        println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
+                                            "[p] previous".green(),
+                                            "|".cyan(),
+                                            "[a] add story".yellow(),
+                                            "|".cyan(),
+                                            "[r] remove story".red(),
+                                            "|".cyan(),
+                                            "[b] start sprint".blue(),
+                                            "|".cyan(),
+                                            "[f] complete sprint".blue(),
+                                            "|".cyan(),
+                                            "[:id:] navigate to story".purple(),
+                                            "|".cyan(),
+                                            "[z] undo".purple(),
+                                            "|".cyan(),
+                                            "[y] redo".purple());
+
+        Ok(())
+    }
+
+    /// Handles user input on the SprintDetail page.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The user input to be handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are issues reading the JIRA database.
+    fn handle_input(&self, input: &str) -> Result<Option<Action>> {
+        let db_state = self.db.read_db()?;
+
+        match input {
+            "p" => Ok(Some(Action::NavigateToPreviousPage)),
+            "a" => Ok(Some(Action::AddStoryToSprint { sprint_id: self.sprint_id })),
+            "r" => Ok(Some(Action::RemoveStoryFromSprint { sprint_id: self.sprint_id })),
+            "b" => Ok(Some(Action::StartSprint { sprint_id: self.sprint_id })),
+            "f" => Ok(Some(Action::CompleteSprint { sprint_id: self.sprint_id })),
+            "z" => Ok(Some(Action::Undo)),
+            "y" => Ok(Some(Action::Redo)),
+            input => {
+                if let Ok(story_id) = input.parse::<u32>() {
+                    let in_sprint = db_state.sprints.get(&self.sprint_id).is_some_and(|sprint| sprint.stories.contains(&story_id));
+
+                    if let Some(epic_id) = db_state.epic_of(story_id).filter(|_| in_sprint) {
+                        return Ok(Some(Action::NavigateToStoryDetail { epic_id, story_id }));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// -------------------------------------------------------------- UNIT TESTING
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, db::test_utils::MockDB};
+    use std::collections::HashMap;
+    use crate::models::{Epic, Priority, Status, Story, Subtask};
+    use chrono::NaiveDate;
+
+    mod home_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = HomePage::new(db);
+            assert_eq!(page.draw_page().is_ok(), true);
+        }
+
+        #[test]
+        fn handle_input_should_toggle_sort_key() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            db.create_epic(Epic { priority: Priority::High, ..Epic::new("".to_owned(), "".to_owned()) }).unwrap();
+
+            let page = HomePage::new(db);
+
+            assert_eq!(page.handle_input("s").unwrap(), None);
+            assert_eq!(page.sort_key.get(), SortKey::Priority);
+            assert!(page.draw_page().is_ok());
+
+            assert_eq!(page.handle_input("s").unwrap(), None);
+            assert_eq!(page.sort_key.get(), SortKey::Id);
+        }
+
+        #[test]
+        fn handle_input_should_run_saved_filters() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            db.save_filter("open stories", "status = open").unwrap();
+            db.save_filter("Big stories", "points > 5").unwrap();
+
+            let page = HomePage::new(db);
+            assert!(page.draw_page().is_ok());
+
+            assert_eq!(page.handle_input("v1").unwrap(), Some(Action::NavigateToQuery { query: "points > 5".to_owned() }));
+            assert_eq!(page.handle_input("v2").unwrap(), Some(Action::NavigateToQuery { query: "status = open".to_owned() }));
+            assert_eq!(page.handle_input("v0").unwrap(), None);
+            assert_eq!(page.handle_input("v3").unwrap(), None);
+        }
+
+        #[test]
+        fn handle_input_should_filter_by_label() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            db.create_epic(Epic { labels: vec!["backend".to_owned()], ..Epic::new("".to_owned(), "".to_owned()) }).unwrap();
+
+            let page = HomePage::new(db);
+
+            assert_eq!(page.handle_input("#Backend").unwrap(), None);
+            assert_eq!(*page.label.borrow(), Some("backend".to_owned()));
+            assert!(page.draw_page().is_ok());
+
+            assert_eq!(page.handle_input("#").unwrap(), None);
+            assert_eq!(*page.label.borrow(), None);
+        }
+
+        #[test]
+        fn handle_input_should_toggle_my_work() {
+            let config = Config { user: Some("alice".to_owned()), ..Config::default() };
+            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()), config });
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.assign_story(story_id, Some("alice")).unwrap();
+
+            let page = HomePage::new(db);
+
+            assert_eq!(page.handle_input("w").unwrap(), None);
+            assert!(page.mine.get());
+            assert!(page.draw_page().is_ok());
+
+            assert_eq!(page.handle_input("w").unwrap(), None);
+            assert!(!page.mine.get());
+        }
+        
+        #[test]
+        fn handle_input_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = HomePage::new(db);
+            assert_eq!(page.handle_input("").is_ok(), true);
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic = Epic::new("".to_owned(), "".to_owned());
+
+            let epic_id = db.create_epic(epic).unwrap();
+
+            let page = HomePage::new(db);
+
+            let q = "q";
+            let c = "c";
+            let z = "z";
+            let y = "y";
+            let valid_epic_id = epic_id.to_string();
+            let invalid_epic_id = "999";
+            let junk_input = "j983f2j";
+            let junk_input_with_valid_prefix = "q983f2j";
+            let input_with_trailing_white_spaces = "q\n";
+
+            assert_eq!(page.handle_input(q).unwrap(), Some(Action::Exit));
+            assert_eq!(page.handle_input(c).unwrap(), Some(Action::CreateEpic));
+            assert_eq!(page.handle_input("r").unwrap(), Some(Action::NavigateToSprints));
+            assert_eq!(page.handle_input("b").unwrap(), Some(Action::NavigateToBoard { epic_id: None }));
+            assert_eq!(page.handle_input("/ login page").unwrap(), Some(Action::NavigateToSearch { query: "login page".to_owned() }));
+            assert_eq!(page.handle_input("/").unwrap(), None);
+            assert_eq!(page.handle_input("j").unwrap(), Some(Action::NavigateToQuery { query: String::new() }));
+            assert_eq!(page.handle_input("v1").unwrap(), None);
+            assert_eq!(page.handle_input(z).unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input(y).unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input(&valid_epic_id).unwrap(), Some(Action::NavigateToEpicDetail { epic_id: 1 }));
+            assert_eq!(page.handle_input(invalid_epic_id).unwrap(), None);
+            assert_eq!(page.handle_input(junk_input).unwrap(), None);
+            assert_eq!(page.handle_input(junk_input_with_valid_prefix).unwrap(), None);
+            assert_eq!(page.handle_input(input_with_trailing_white_spaces).unwrap(), None);
+        }
+    }
+
+    mod epic_detail_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+
+            let page = EpicDetail::new(epic_id, db);
             assert_eq!(page.draw_page().is_ok(), true);
         }
 
         #[test]
         fn handle_input_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
 
-            let page = EpicDetail { epic_id, db };
+            let page = EpicDetail::new(epic_id, db);
             assert_eq!(page.handle_input("").is_ok(), true);
         }
 
+        #[test]
+        fn handle_input_should_toggle_my_work() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.assign_story(story_id, Some("bob")).unwrap();
+
+            let page = EpicDetail::new(epic_id, db);
+
+            assert_eq!(page.handle_input("w").unwrap(), None);
+            assert!(page.mine.get());
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn handle_input_should_filter_by_label() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            db.create_story(Story { labels: vec!["ui".to_owned()], ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+
+            let page = EpicDetail::new(epic_id, db);
+
+            assert_eq!(page.handle_input("#ui").unwrap(), None);
+            assert_eq!(*page.label.borrow(), Some("ui".to_owned()));
+            assert!(page.draw_page().is_ok());
+        }
+
         #[test]
         fn draw_page_should_throw_error_for_invalid_epic_id() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
-            let page = EpicDetail { epic_id: 999, db };
+            let page = EpicDetail::new(999, db);
             assert_eq!(page.draw_page().is_err(), true);
         }
 
         #[test]
         fn handle_input_should_return_the_correct_actions() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
             let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
 
-            let page = EpicDetail { epic_id, db };
+            let page = EpicDetail::new(epic_id, db);
 
             let p = "p";
             let u = "u";
+            let e = "e";
+            let a = "a";
             let d = "d";
             let c = "c";
+            let k = "k";
+            let h = "h";
+            let z = "z";
+            let y = "y";
             let invalid_story_id = "999";
             let junk_input = "j983f2j";
             let junk_input_with_valid_prefix = "p983f2j";
@@ -718,8 +2170,15 @@ mod tests {
 
             assert_eq!(page.handle_input(p).unwrap(), Some(Action::NavigateToPreviousPage));
             assert_eq!(page.handle_input(u).unwrap(), Some(Action::UpdateEpicStatus { epic_id: 1 }));
+            assert_eq!(page.handle_input(e).unwrap(), Some(Action::EditEpic { epic_id: 1 }));
+            assert_eq!(page.handle_input(a).unwrap(), Some(Action::AssignEpic { epic_id: 1 }));
             assert_eq!(page.handle_input(d).unwrap(), Some(Action::DeleteEpic { epic_id: 1 }));
             assert_eq!(page.handle_input(c).unwrap(), Some(Action::CreateStory { epic_id: 1 }));
+            assert_eq!(page.handle_input(k).unwrap(), Some(Action::AddComment { item_id: 1 }));
+            assert_eq!(page.handle_input(h).unwrap(), Some(Action::NavigateToHistory { item_id: 1 }));
+            assert_eq!(page.handle_input("b").unwrap(), Some(Action::NavigateToBoard { epic_id: Some(1) }));
+            assert_eq!(page.handle_input(z).unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input(y).unwrap(), Some(Action::Redo));
             assert_eq!(page.handle_input(&story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id: 1, story_id: 2 }));
             assert_eq!(page.handle_input(invalid_story_id).unwrap(), None);
             assert_eq!(page.handle_input(junk_input).unwrap(), None);
@@ -733,7 +2192,7 @@ mod tests {
 
         #[test]
         fn draw_page_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
             let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
@@ -742,9 +2201,49 @@ mod tests {
             assert_eq!(page.draw_page().is_ok(), true);
         }
 
+        #[test]
+        fn draw_page_should_show_comments() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.add_comment(story_id, "first line\nsecond line").unwrap();
+            let comment_id = db.add_comment(story_id, "typo").unwrap();
+            db.edit_comment(comment_id, "fixed").unwrap();
+
+            let page = StoryDetail { epic_id, story_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn draw_page_should_show_links() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let blocker_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.add_link(story_id, LinkType::IsBlockedBy, blocker_id).unwrap();
+            db.add_link(story_id, LinkType::RelatesTo, epic_id).unwrap();
+
+            let page = StoryDetail { epic_id, story_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn draw_page_should_show_bug_fields() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let bug = Story { issue_type: IssueType::Bug, steps_to_reproduce: Some("open\nclick".to_owned()), ..Story::new("".to_owned(), "".to_owned()) };
+            let story_id = db.create_story(bug, epic_id).unwrap();
+
+            let page = StoryDetail { epic_id, story_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
         #[test]
         fn handle_input_should_not_throw_error() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
             let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
@@ -755,7 +2254,7 @@ mod tests {
 
         #[test]
         fn draw_page_should_throw_error_for_invalid_story_id() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
             let _ = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
@@ -766,7 +2265,7 @@ mod tests {
 
         #[test]
         fn handle_input_should_return_the_correct_actions() {
-            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()) });
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
 
             let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
             let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
@@ -775,19 +2274,360 @@ mod tests {
 
             let p = "p";
             let u = "u";
+            let e = "e";
+            let a = "a";
+            let m = "m";
             let d = "d";
+            let k = "k";
+            let l = "l";
+            let x = "x";
+            let h = "h";
+            let z = "z";
+            let y = "y";
+            let c = "c";
             let some_number = "1";
             let junk_input = "j983f2j";
             let junk_input_with_valid_prefix = "p983f2j";
             let input_with_trailing_white_spaces = "p\n";
 
             assert_eq!(page.handle_input(p).unwrap(), Some(Action::NavigateToPreviousPage));
-            assert_eq!(page.handle_input(u).unwrap(), Some(Action::UpdateStoryStatus { story_id }));
+            assert_eq!(page.handle_input(u).unwrap(), Some(Action::UpdateStoryStatus { story_id, status: None }));
+            assert_eq!(page.handle_input(c).unwrap(), Some(Action::CreateSubtask { story_id }));
+            assert_eq!(page.handle_input(e).unwrap(), Some(Action::EditStory { story_id }));
+            assert_eq!(page.handle_input(a).unwrap(), Some(Action::AssignStory { story_id }));
+            assert_eq!(page.handle_input(m).unwrap(), Some(Action::MoveStory { epic_id, story_id }));
             assert_eq!(page.handle_input(d).unwrap(), Some(Action::DeleteStory { epic_id, story_id }));
+            assert_eq!(page.handle_input(k).unwrap(), Some(Action::AddComment { item_id: story_id }));
+            assert_eq!(page.handle_input(l).unwrap(), Some(Action::AddLink { item_id: story_id }));
+            assert_eq!(page.handle_input(x).unwrap(), Some(Action::RemoveLink { item_id: story_id }));
+            assert_eq!(page.handle_input(h).unwrap(), Some(Action::NavigateToHistory { item_id: story_id }));
+            assert_eq!(page.handle_input(z).unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input(y).unwrap(), Some(Action::Redo));
             assert_eq!(page.handle_input(some_number).unwrap(), None);
             assert_eq!(page.handle_input(junk_input).unwrap(), None);
             assert_eq!(page.handle_input(junk_input_with_valid_prefix).unwrap(), None);
             assert_eq!(page.handle_input(input_with_trailing_white_spaces).unwrap(), None);
         } 
     }
-}
\ No newline at end of file
+
+    mod subtask_detail_page {
+        use super::*;
+
+        #[test]
+        fn story_detail_should_navigate_to_subtasks() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let subtask_id = db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+            let other_subtask_id = db.create_subtask(Subtask::new("".to_owned()), other_story_id).unwrap();
+
+            let page = StoryDetail { epic_id, story_id, db };
+
+            assert!(page.draw_page().is_ok());
+            assert_eq!(page.handle_input(&subtask_id.to_string()).unwrap(), Some(Action::NavigateToSubtaskDetail { story_id, subtask_id }));
+            assert_eq!(page.handle_input(&other_subtask_id.to_string()).unwrap(), None);
+        }
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let subtask_id = db.create_subtask(Subtask::new("".to_owned()), story_id).unwrap();
+
+            let page = SubtaskDetail { story_id, subtask_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn draw_page_should_throw_error_for_invalid_subtask_id() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = SubtaskDetail { story_id: 1, subtask_id: 999, db };
+            assert!(page.draw_page().is_err());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = SubtaskDetail { story_id: 2, subtask_id: 3, db };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("u").unwrap(), Some(Action::UpdateSubtaskStatus { subtask_id: 3 }));
+            assert_eq!(page.handle_input("d").unwrap(), Some(Action::DeleteSubtask { story_id: 2, subtask_id: 3 }));
+            assert_eq!(page.handle_input("h").unwrap(), Some(Action::NavigateToHistory { item_id: 3 }));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input("3").unwrap(), None);
+            assert_eq!(page.handle_input("p\n").unwrap(), None);
+        }
+    }
+
+    mod history_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            db.update_epic_status(epic_id, Status::Closed).unwrap();
+
+            let page = HistoryPage { item_id: epic_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = HistoryPage { item_id: 1, db };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input("h").unwrap(), None);
+            assert_eq!(page.handle_input("p\n").unwrap(), None);
+        }
+    }
+
+    mod search_results {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("Accounts".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("Login page".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.add_comment(story_id, "Use the new login API").unwrap();
+
+            let page = SearchResults { query: "login".to_owned(), db: db.clone() };
+            assert!(page.draw_page().is_ok());
+
+            let page = SearchResults { query: "nothing".to_owned(), db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("Accounts".to_owned(), "Login and logout".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("Login page".to_owned(), "".to_owned()), epic_id).unwrap();
+            let other_story_id = db.create_story(Story::new("Profile page".to_owned(), "".to_owned()), epic_id).unwrap();
+
+            let page = SearchResults { query: "LOGIN".to_owned(), db };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input(&epic_id.to_string()).unwrap(), Some(Action::NavigateToEpicDetail { epic_id }));
+            assert_eq!(page.handle_input(&story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id, story_id }));
+            assert_eq!(page.handle_input(&other_story_id.to_string()).unwrap(), None);
+            assert_eq!(page.handle_input("999").unwrap(), None);
+            assert_eq!(page.handle_input("p\n").unwrap(), None);
+        }
+    }
+
+    mod query_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            db.create_story(Story { story_points: Some(3), ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+
+            let page = QueryPage::new("points > 2 ORDER BY priority DESC".to_owned(), db.clone());
+            assert!(page.draw_page().is_ok());
+
+            let page = QueryPage::new("points > two".to_owned(), db);
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story { story_points: Some(3), ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+            let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+
+            let page = QueryPage::new("points > 2".to_owned(), db);
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("s").unwrap(), Some(Action::SaveFilter { query: "points > 2".to_owned() }));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(*page.query.borrow(), "points > 2".to_owned());
+            assert_eq!(page.handle_input(&story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id, story_id }));
+            assert_eq!(page.handle_input(&other_story_id.to_string()).unwrap(), None);
+            assert_eq!(page.handle_input("999").unwrap(), None);
+
+            assert_eq!(page.handle_input("points IS EMPTY").unwrap(), None);
+            assert_eq!(*page.query.borrow(), "points IS EMPTY".to_owned());
+            assert_eq!(page.handle_input(&other_story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id, story_id: other_story_id }));
+
+            assert_eq!(page.handle_input("points >").unwrap(), None);
+            assert_eq!(page.handle_input("s").unwrap(), None);
+        }
+    }
+
+    mod board_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            db.update_story_status(story_id, Status::InProgress).unwrap();
+
+            let page = BoardPage { epic_id: Some(epic_id), db: db.clone() };
+            assert!(page.draw_page().is_ok());
+
+            let page = BoardPage { epic_id: None, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn draw_page_should_throw_error_for_invalid_epic_id() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = BoardPage { epic_id: Some(999), db };
+            assert!(page.draw_page().is_err());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let other_epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let closed_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), other_epic_id).unwrap();
+            db.update_story_status(closed_story_id, Status::Closed).unwrap();
+
+            let page = BoardPage { epic_id: Some(epic_id), db: db.clone() };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input(&story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id, story_id }));
+            assert_eq!(page.handle_input(&format!(">{}", story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id, status: Some(Status::InProgress) }));
+            assert_eq!(page.handle_input(&format!("<{}", story_id)).unwrap(), None);
+            assert_eq!(page.handle_input(&format!("<{}", closed_story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id: closed_story_id, status: Some(Status::Resolved) }));
+            assert_eq!(page.handle_input(&format!(">{}", closed_story_id)).unwrap(), None);
+            assert_eq!(page.handle_input(&format!(">{}", other_story_id)).unwrap(), None);
+            assert_eq!(page.handle_input("><1").unwrap(), None);
+            assert_eq!(page.handle_input("999").unwrap(), None);
+
+            let page = BoardPage { epic_id: None, db };
+            assert_eq!(page.handle_input(&format!(">{}", other_story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id: other_story_id, status: Some(Status::InProgress) }));
+        }
+
+        #[test]
+        fn handle_input_should_skip_columns_the_workflow_does_not_allow() {
+            let workflow = Workflow {
+                transitions: HashMap::from([("Open".to_owned(), vec!["Resolved".to_owned()]),
+                                            ("Resolved".to_owned(), vec!["Open".to_owned()]),
+                                            ("Closed".to_owned(), vec!["In Progress".to_owned()])]),
+                ..Workflow::default()
+            };
+            let db = Rc::new(JiraDatabase { database: Box::new(MockDB::new()), config: Config { workflow, ..Config::default() } });
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let open_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let resolved_story_id = db.create_story(Story { status: Status::Resolved, ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+            let closed_story_id = db.create_story(Story { status: Status::Closed, ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+
+            let page = BoardPage { epic_id: Some(epic_id), db };
+
+            assert_eq!(page.handle_input(&format!(">{}", open_story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id: open_story_id, status: Some(Status::Resolved) }));
+            assert_eq!(page.handle_input(&format!("<{}", resolved_story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id: resolved_story_id, status: Some(Status::Open) }));
+            assert_eq!(page.handle_input(&format!(">{}", resolved_story_id)).unwrap(), None);
+            assert_eq!(page.handle_input(&format!("<{}", closed_story_id)).unwrap(), Some(Action::UpdateStoryStatus { story_id: closed_story_id, status: Some(Status::InProgress) }));
+        }
+    }
+
+    mod sprint_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+            db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), date, date)).unwrap();
+
+            let page = SprintPage { db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+            let sprint_id = db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), date, date)).unwrap();
+
+            let page = SprintPage { db };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("c").unwrap(), Some(Action::CreateSprint));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input(&sprint_id.to_string()).unwrap(), Some(Action::NavigateToSprintDetail { sprint_id }));
+            assert_eq!(page.handle_input("999").unwrap(), None);
+            assert_eq!(page.handle_input("p\n").unwrap(), None);
+        }
+    }
+
+    mod sprint_detail_page {
+        use super::*;
+
+        #[test]
+        fn draw_page_should_not_throw_error() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story { story_points: Some(3), ..Story::new("".to_owned(), "".to_owned()) }, epic_id).unwrap();
+            let sprint_id = db.create_sprint(Sprint::new("Sprint 1".to_owned(), "Ship it".to_owned(), date, date)).unwrap();
+            db.add_story_to_sprint(sprint_id, story_id).unwrap();
+
+            let page = SprintDetail { sprint_id, db };
+            assert!(page.draw_page().is_ok());
+        }
+
+        #[test]
+        fn draw_page_should_throw_error_for_invalid_sprint_id() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+
+            let page = SprintDetail { sprint_id: 999, db };
+            assert!(page.draw_page().is_err());
+        }
+
+        #[test]
+        fn handle_input_should_return_the_correct_actions() {
+            let db = Rc::new(JiraDatabase::from_database(Box::new(MockDB::new())));
+            let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+            let epic_id = db.create_epic(Epic::new("".to_owned(), "".to_owned())).unwrap();
+            let story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let other_story_id = db.create_story(Story::new("".to_owned(), "".to_owned()), epic_id).unwrap();
+            let sprint_id = db.create_sprint(Sprint::new("Sprint 1".to_owned(), "".to_owned(), date, date)).unwrap();
+            db.add_story_to_sprint(sprint_id, story_id).unwrap();
+
+            let page = SprintDetail { sprint_id, db };
+
+            assert_eq!(page.handle_input("p").unwrap(), Some(Action::NavigateToPreviousPage));
+            assert_eq!(page.handle_input("a").unwrap(), Some(Action::AddStoryToSprint { sprint_id }));
+            assert_eq!(page.handle_input("r").unwrap(), Some(Action::RemoveStoryFromSprint { sprint_id }));
+            assert_eq!(page.handle_input("b").unwrap(), Some(Action::StartSprint { sprint_id }));
+            assert_eq!(page.handle_input("f").unwrap(), Some(Action::CompleteSprint { sprint_id }));
+            assert_eq!(page.handle_input("z").unwrap(), Some(Action::Undo));
+            assert_eq!(page.handle_input("y").unwrap(), Some(Action::Redo));
+            assert_eq!(page.handle_input(&story_id.to_string()).unwrap(), Some(Action::NavigateToStoryDetail { epic_id, story_id }));
+            assert_eq!(page.handle_input(&other_story_id.to_string()).unwrap(), None);
+            assert_eq!(page.handle_input("p\n").unwrap(), None);
+        }
+    }
+}
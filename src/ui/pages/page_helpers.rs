@@ -1,5 +1,10 @@
 use ellipse::Ellipse;
 
+use std::ops::Range;
+
+use crate::models::Progress;
+use serde_json::Value;
+
 /// Generates a formatted string for displaying text in a column with a specified width.
 ///
 /// This function takes a `text` string and a `width` usize as input parameters. It formats
@@ -61,6 +66,141 @@ pub fn get_column_string(text: &str, width: usize) -> String {
     }
 }
 
+/// Formats a value recorded in the change log for display.
+///
+/// Strings are shown without quotes and a missing value is shown as `-`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::page_helpers::get_value_string;
+///
+/// assert_eq!(get_value_string(&Some(Value::from("OPEN"))), "OPEN");
+/// assert_eq!(get_value_string(&None), "-");
+/// ```
+pub fn get_value_string(value: &Option<Value>) -> String {
+    match value {
+        None => "-".to_owned(),
+        Some(Value::String(text)) => text.clone(),
+        Some(value) => value.to_string()
+    }
+}
+
+/// Formats the labels of an Epic or Story for display, e.g. `#backend #ui`.
+///
+/// Items without labels are shown as `-`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::page_helpers::get_labels_string;
+///
+/// assert_eq!(get_labels_string(&["backend".to_owned(), "ui".to_owned()]), "#backend #ui");
+/// ```
+pub fn get_labels_string(labels: &[String]) -> String {
+    if labels.is_empty() {
+        return "-".to_owned();
+    }
+
+    labels.iter().map(|label| format!("#{}", label)).collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the input selecting the label the items of a page are filtered on.
+///
+/// # Returns
+///
+/// Returns `None` if the input is not a label filter, i.e. does not start with `#`. Otherwise
+/// returns `Some` containing the normalized label, or `Some(None)` for a lone `#`, which clears
+/// the filter.
+///
+/// # Examples
+///
+/// ```
+/// use crate::page_helpers::get_label_filter;
+///
+/// assert_eq!(get_label_filter("#Backend"), Some(Some("backend".to_owned())));
+/// assert_eq!(get_label_filter("#"), Some(None));
+/// assert_eq!(get_label_filter("1"), None);
+/// ```
+pub fn get_label_filter(input: &str) -> Option<Option<String>> {
+    let label = input.strip_prefix('#')?.trim().to_lowercase();
+
+    if label.is_empty() {
+        return Some(None);
+    }
+
+    Some(Some(label))
+}
+
+/// Formats the progress of an Epic as completed out of total points, with the percentage.
+///
+/// Epics without any points are shown as `-`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::page_helpers::get_progress_string;
+///
+/// assert_eq!(get_progress_string(&Progress { total_points: 8, completed_points: 3 }), "3/8 (37%)");
+/// ```
+pub fn get_progress_string(progress: &Progress) -> String {
+    match progress.percent() {
+        Some(percent) => format!("{}/{} ({}%)", progress.completed_points, progress.total_points, percent),
+        None => "-".to_owned()
+    }
+}
+
+/// Draws the progress of an Epic as a bar of the given width, e.g. `[###-------]`.
+pub fn get_progress_bar(progress: &Progress, width: usize) -> String {
+    let filled = width * progress.percent().unwrap_or(0) as usize / 100;
+
+    format!("[{}{}]", "#".repeat(filled), "-".repeat(width - filled))
+}
+
+/// Cuts the text of a search hit down to a one-line snippet of about `width` characters around
+/// the match, with an ellipsis wherever text was left out.
+///
+/// # Arguments
+///
+/// * `text` - The text the match was found in.
+/// * `matched` - The byte range of the match within `text`.
+/// * `width` - The number of characters the snippet should fit in.
+///
+/// # Returns
+///
+/// The snippet split into the text before the match, the match and the text after it, so the
+/// match can be highlighted.
+///
+/// # Examples
+///
+/// ```
+/// use crate::page_helpers::get_snippet;
+///
+/// let (before, matched, after) = get_snippet("Add the new login page\nto the app", 12..17, 30);
+/// assert_eq!(format!("{}[{}]{}", before, matched, after), "...he new [login] page to the...");
+/// ```
+pub fn get_snippet(text: &str, matched: Range<usize>, width: usize) -> (String, String, String) {
+    let one_line = |text: &str| text.replace(['\n', '\r', '\t'], " ");
+    let context = width / 3;
+
+    let before: Vec<char> = one_line(&text[..matched.start]).chars().collect();
+    let before = match before.len() > context {
+        true => format!("...{}", before[before.len() + 3 - context..].iter().collect::<String>()),
+        false => before.into_iter().collect()
+    };
+
+    let matched_text = one_line(&text[matched.clone()]);
+    let remaining = width.saturating_sub(before.chars().count() + matched_text.chars().count());
+
+    let after: Vec<char> = one_line(&text[matched.end..]).chars().collect();
+    let after = match after.len() > remaining {
+        true => format!("{}...", after[..remaining.saturating_sub(3)].iter().collect::<String>()),
+        false => after.into_iter().collect()
+    };
+
+    (before, matched_text, after)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +239,49 @@ mod tests {
         assert_eq!(get_column_string(text3, width), "testme".to_owned());
         assert_eq!(get_column_string(text4, width), "tes...".to_owned());
     } 
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_labels_string() {
+        assert_eq!(get_labels_string(&[]), "-".to_owned());
+        assert_eq!(get_labels_string(&["backend".to_owned(), "ui".to_owned()]), "#backend #ui".to_owned());
+    }
+
+    #[test]
+    fn test_get_label_filter() {
+        assert_eq!(get_label_filter("#Backend"), Some(Some("backend".to_owned())));
+        assert_eq!(get_label_filter("#"), Some(None));
+        assert_eq!(get_label_filter("backend"), None);
+    }
+
+    #[test]
+    fn test_get_progress_string() {
+        assert_eq!(get_progress_string(&Progress::default()), "-".to_owned());
+        assert_eq!(get_progress_string(&Progress { total_points: 8, completed_points: 3 }), "3/8 (37%)".to_owned());
+    }
+
+    #[test]
+    fn test_get_progress_bar() {
+        assert_eq!(get_progress_bar(&Progress::default(), 4), "[----]".to_owned());
+        assert_eq!(get_progress_bar(&Progress { total_points: 4, completed_points: 3 }, 4), "[###-]".to_owned());
+        assert_eq!(get_progress_bar(&Progress { total_points: 4, completed_points: 4 }, 4), "[####]".to_owned());
+    }
+
+    #[test]
+    fn test_get_value_string() {
+        assert_eq!(get_value_string(&None), "-".to_owned());
+        assert_eq!(get_value_string(&Some(Value::from("OPEN"))), "OPEN".to_owned());
+        assert_eq!(get_value_string(&Some(serde_json::json!([1, 2]))), "[1,2]".to_owned());
+    }
+
+    #[test]
+    fn test_get_snippet() {
+        let snippet = |text: &str, matched: Range<usize>, width| {
+            let (before, matched, after) = get_snippet(text, matched, width);
+            format!("{}[{}]{}", before, matched, after)
+        };
+
+        assert_eq!(snippet("Login page", 0..5, 30), "[Login] page".to_owned());
+        assert_eq!(snippet("Add the new login page\nto the app", 12..17, 30), "...he new [login] page to the...".to_owned());
+        assert_eq!(snippet("Add the new login page", 12..17, 40), "Add the new [login] page".to_owned());
+    }
+}
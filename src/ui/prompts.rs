@@ -1,5 +1,21 @@
-use crate::{models::{Epic, Story, Status}, io_utils::get_user_input};
-use colored::Colorize;
+use crate::{models::{Epic, EpicPatch, IssueType, Link, LinkType, Priority, Severity, Sprint, Story, StoryPatch, Status, Subtask}, io_utils::get_user_input};
+use chrono::{Days, Local, NaiveDate};
+use colored::{ColoredString, Colorize};
+
+/// Closure choosing a username among the given usernames, see `Prompts::assign`.
+pub type AssignPrompt = dyn Fn(&[String]) -> Option<String>;
+
+/// Closure choosing a status among the given statuses, see `Prompts::update_status`.
+pub type StatusPrompt = dyn Fn(&[Status]) -> Option<Status>;
+
+/// Closure choosing one of the given links, see `Prompts::unlink`.
+pub type UnlinkPrompt = dyn Fn(&[Link]) -> Option<Link>;
+
+/// Closure confirming a change despite the given blockers, see `Prompts::resolve_blocked`.
+pub type ResolveBlockedPrompt = dyn Fn(&[u32]) -> bool;
+
+/// Closure confirming the completion of a sprint, see `Prompts::complete_sprint`.
+pub type CompleteSprintPrompt = dyn Fn(&[u32], Option<u32>) -> bool;
 
 /// Contains closures for prompting user input related to Epics and Stories.
 ///
@@ -24,10 +40,24 @@ use colored::Colorize;
 ///         // Prompt user for confirmation
 ///         true
 ///     }),
-///     update_status: Box::new(|| {
+///     update_status: Box::new(|_next_statuses| {
 ///         // Prompt user to select a new status
 ///         Some(Status::InProgress)
 ///     }),
+///     edit_epic: Box::new(|_epic| EpicPatch::default()),
+///     edit_story: Box::new(|_story| StoryPatch::default()),
+///     move_story: Box::new(|| Some(2)),
+///     assign: Box::new(|_usernames| Some("alice".to_owned())),
+///     comment: Box::new(|| Some("Looks good".to_owned())),
+///     create_subtask: Box::new(|| Subtask::new("Write tests".to_owned())),
+///     delete_subtask: Box::new(|| true),
+///     link: Box::new(|| Some((LinkType::Blocks, 3))),
+///     unlink: Box::new(|links| links.first().copied()),
+///     resolve_blocked: Box::new(|_blockers| false),
+///     create_sprint: Box::new(|| None),
+///     sprint_story: Box::new(|| Some(2)),
+///     complete_sprint: Box::new(|_incomplete_stories, _next_sprint_id| true),
+///     save_filter: Box::new(|| None),
 /// };
 /// ```
 pub struct Prompts {
@@ -43,8 +73,54 @@ pub struct Prompts {
     /// Closure for confirming deletion of a Story.
     pub delete_story: Box<dyn Fn() -> bool>,
 
-    /// Closure for updating the status of an Epic or Story.
-    pub update_status: Box<dyn Fn() -> Option<Status>>
+    /// Closure for updating the status of an Epic, Story or Sub-task, given the statuses the
+    /// workflow allows it to move to.
+    pub update_status: Box<StatusPrompt>,
+
+    /// Closure for editing the name and description of an Epic, given its current values.
+    pub edit_epic: Box<dyn Fn(&Epic) -> EpicPatch>,
+
+    /// Closure for editing the name and description of a Story, given its current values.
+    pub edit_story: Box<dyn Fn(&Story) -> StoryPatch>,
+
+    /// Closure for choosing the id of the Epic a Story is moved to.
+    pub move_story: Box<dyn Fn() -> Option<u32>>,
+
+    /// Closure for choosing the user a Story is assigned to or an Epic is owned by, given the
+    /// usernames of the known users. Returns `None` to unassign.
+    pub assign: Box<AssignPrompt>,
+
+    /// Closure for writing a comment on an Epic or Story. Returns `None` if nothing was written.
+    pub comment: Box<dyn Fn() -> Option<String>>,
+
+    /// Closure for creating a new Sub-task.
+    pub create_subtask: Box<dyn Fn() -> Subtask>,
+
+    /// Closure for confirming deletion of a Sub-task.
+    pub delete_subtask: Box<dyn Fn() -> bool>,
+
+    /// Closure for choosing the type of a new link and the id of the item to link to.
+    pub link: Box<dyn Fn() -> Option<(LinkType, u32)>>,
+
+    /// Closure for choosing the link to remove among the links of an item.
+    pub unlink: Box<UnlinkPrompt>,
+
+    /// Closure for confirming that a Story is resolved or closed although it is still blocked by
+    /// the items with the given IDs.
+    pub resolve_blocked: Box<ResolveBlockedPrompt>,
+
+    /// Closure for creating a new Sprint. Returns `None` if the user entered an invalid date.
+    pub create_sprint: Box<dyn Fn() -> Option<Sprint>>,
+
+    /// Closure for choosing the id of the Story added to or removed from a Sprint.
+    pub sprint_story: Box<dyn Fn() -> Option<u32>>,
+
+    /// Closure for confirming that a Sprint is completed, given the IDs of its incomplete stories
+    /// and the ID of the Sprint they roll into, if any.
+    pub complete_sprint: Box<CompleteSprintPrompt>,
+
+    /// Closure for naming a query to save. Returns `None` if no name was entered.
+    pub save_filter: Box<dyn Fn() -> Option<String>>
 }
 
 /// Constructs a new `Prompts` instance.
@@ -67,19 +143,34 @@ impl Prompts {
             create_story: Box::new(create_story_prompt),
             delete_epic: Box::new(delete_epic_prompt),
             delete_story: Box::new(delete_story_prompt),
-            update_status: Box::new(update_status_prompt)
+            update_status: Box::new(update_status_prompt),
+            edit_epic: Box::new(edit_epic_prompt),
+            edit_story: Box::new(edit_story_prompt),
+            move_story: Box::new(move_story_prompt),
+            assign: Box::new(assign_prompt),
+            comment: Box::new(comment_prompt),
+            create_subtask: Box::new(create_subtask_prompt),
+            delete_subtask: Box::new(delete_subtask_prompt),
+            link: Box::new(link_prompt),
+            unlink: Box::new(unlink_prompt),
+            resolve_blocked: Box::new(resolve_blocked_prompt),
+            create_sprint: Box::new(create_sprint_prompt),
+            sprint_story: Box::new(sprint_story_prompt),
+            complete_sprint: Box::new(complete_sprint_prompt),
+            save_filter: Box::new(save_filter_prompt)
         }
     }
 }
 
 /// Prompts the user to create a new Epic.
 ///
-/// This function displays prompts to the user to input the name and description of a new Epic.
-/// It then constructs and returns an `Epic` instance with the provided name and description.
+/// This function displays prompts to the user to input the name, description, priority, labels
+/// and due date of a new Epic. It then constructs and returns an `Epic` instance with the
+/// provided values.
 ///
 /// # Returns
 ///
-/// An `Epic` instance with the provided name and description.
+/// An `Epic` instance with the provided name, description, priority, labels and due date.
 ///
 /// # Examples
 ///
@@ -100,19 +191,31 @@ fn create_epic_prompt() -> Epic {
 
     let epic_desc = get_user_input();
 
-    let epic = Epic::new(epic_name.trim().to_owned(), epic_desc.trim().to_owned());
+    let priority = priority_prompt(Priority::default());
+
+    let labels = labels_prompt(&[]);
+
+    let due_date = due_date_prompt(None);
 
-    epic
+    Epic {
+        priority: priority.unwrap_or_default(),
+        labels: labels.unwrap_or_default(),
+        due_date: due_date.flatten(),
+        ..Epic::new(epic_name.trim().to_owned(), epic_desc.trim().to_owned())
+    }
 }
 
 /// Prompts the user to create a new Story.
 ///
-/// This function displays prompts to the user to input the name and description of a new Story.
-/// It then constructs and returns a `Story` instance with the provided name and description.
+/// This function displays prompts to the user to input the name, description, issue type,
+/// priority, labels, due date and story points of a new Story, followed by the steps to
+/// reproduce, severity and affected version if the Story is a bug. It then constructs and returns
+/// a `Story` instance with the provided values.
 ///
 /// # Returns
 ///
-/// A `Story` instance with the provided name and description.
+/// A `Story` instance with the provided name, description, issue type, priority, labels, due date,
+/// story points and bug fields.
 ///
 /// # Examples
 ///
@@ -133,9 +236,145 @@ fn create_story_prompt() -> Story {
 
     let story_desc = get_user_input();
 
+    let issue_type = issue_type_prompt(IssueType::default()).unwrap_or_default();
+
+    let priority = priority_prompt(Priority::default());
+
+    let labels = labels_prompt(&[]);
+
+    let due_date = due_date_prompt(None);
+
+    let story_points = story_points_prompt(None);
+
     let story = Story::new(story_name.trim().to_owned(), story_desc.trim().to_owned());
 
-    story
+    let bug_fields = match issue_type {
+        IssueType::Bug => bug_fields_prompt(&story),
+        _ => StoryPatch::default()
+    };
+
+    Story {
+        issue_type,
+        priority: priority.unwrap_or_default(),
+        labels: labels.unwrap_or_default(),
+        due_date: due_date.flatten(),
+        story_points: story_points.flatten(),
+        steps_to_reproduce: bug_fields.steps_to_reproduce.flatten(),
+        severity: bug_fields.severity.flatten(),
+        affected_version: bug_fields.affected_version.flatten(),
+        ..story
+    }
+}
+
+/// Prompts the user for the name of a new Sub-task.
+///
+/// # Returns
+///
+/// A `Subtask` instance with the provided name.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::create_subtask_prompt;
+///
+/// let subtask = create_subtask_prompt();
+/// ```
+fn create_subtask_prompt() -> Subtask {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", "Sub-task Name:".yellow());
+
+    let subtask_name = get_user_input();
+
+    Subtask::new(subtask_name.trim().to_owned())
+}
+
+/// Prompts the user to create a new Sprint.
+///
+/// This function displays prompts to the user to input the name, goal, start date and end date
+/// of a new Sprint. The start date defaults to today and the end date to two weeks later.
+///
+/// # Returns
+///
+/// Returns `Some(Sprint)` with the provided values, or `None` if the user entered an invalid date.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::create_sprint_prompt;
+///
+/// let sprint = create_sprint_prompt();
+/// ```
+fn create_sprint_prompt() -> Option<Sprint> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", "Sprint Name:".yellow());
+
+    let sprint_name = get_user_input();
+
+    println!("{} ", "Sprint Goal:".yellow());
+
+    let sprint_goal = get_user_input();
+
+    let start_date = date_prompt("Start Date", Local::now().date_naive())?;
+
+    let end_date = date_prompt("End Date", start_date.checked_add_days(Days::new(13))?)?;
+
+    Some(Sprint::new(sprint_name.trim().to_owned(), sprint_goal.trim().to_owned(), start_date, end_date))
+}
+
+/// Prompts the user for the id of the Story to add to or remove from a Sprint.
+///
+/// # Returns
+///
+/// Returns `Some(u32)` containing the entered id, or `None` if the user entered an invalid id.
+fn sprint_story_prompt() -> Option<u32> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", "Story (id):".yellow());
+
+    get_user_input().trim().parse::<u32>().ok()
+}
+
+/// Asks the user to confirm completing a Sprint, telling where its incomplete stories go.
+///
+/// # Arguments
+///
+/// * `incomplete_stories` - The IDs of the stories of the Sprint that are not done.
+/// * `next_sprint_id` - The ID of the Sprint they roll into, or `None` if they go back to the backlog.
+///
+/// # Returns
+///
+/// Returns `true` if the user confirms by entering "Y", otherwise returns `false`.
+fn complete_sprint_prompt(incomplete_stories: &[u32], next_sprint_id: Option<u32>) -> bool {
+    println!("{}", "----------------------------".green());
+
+    if !incomplete_stories.is_empty() {
+        let stories: Vec<String> = incomplete_stories.iter().map(|id| format!("#{}", id)).collect();
+        let destination = next_sprint_id.map_or_else(|| "the backlog".to_owned(), |sprint_id| format!("sprint {}", sprint_id));
+
+        println!("{}", format!("Stories {} are not done and will move to {}.", stories.join(", "), destination).yellow());
+    }
+
+    println!("{} ", "Are you sure you want to complete this sprint? [Y/n]:".red().bold());
+
+    let input = get_user_input();
+
+    input.trim().eq("Y")
+}
+
+/// Prompts the user for the name a query is saved under. Saving under the name of an existing
+/// filter replaces its query.
+///
+/// # Returns
+///
+/// Returns `Some(String)` containing the entered name, or `None` if the user entered nothing.
+fn save_filter_prompt() -> Option<String> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} {}", "Filter name:".yellow(), "(leave empty to cancel)".dimmed());
+
+    non_empty(&get_user_input())
 }
 
 /// Prompts the user to confirm deletion of an Epic.
@@ -200,15 +439,56 @@ fn delete_story_prompt() -> bool {
     false
 }
 
-/// Prompts the user to select a new status for an Epic or a Story.
+/// Prompts the user to confirm deletion of a Sub-task.
 ///
-/// This function displays a prompt to the user to select a new status from a list of options.
-/// It then reads the user input and returns an `Option<Status>` representing the selected status.
+/// # Returns
+///
+/// Returns `true` if the user confirms deletion by entering "Y", otherwise returns `false`.
+fn delete_subtask_prompt() -> bool {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", "Are you sure you want to delete this sub-task? [Y/n]:".red().bold());
+
+    let input = get_user_input();
+
+    input.trim().eq("Y")
+}
+
+/// Warns the user that a Story is still blocked and asks to confirm resolving or closing it anyway.
+///
+/// # Arguments
+///
+/// * `blockers` - The IDs of the items still blocking the Story.
 ///
 /// # Returns
 ///
-/// Returns `Some(Status)` representing the selected status if the user input is a valid status option,
-/// otherwise returns `None`.
+/// Returns `true` if the user confirms by entering "Y", otherwise returns `false`.
+fn resolve_blocked_prompt(blockers: &[u32]) -> bool {
+    let blockers: Vec<String> = blockers.iter().map(|id| format!("#{}", id)).collect();
+
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", format!("This story is still blocked by {}. Are you sure you want to resolve it? [Y/n]:", blockers.join(", ")).red().bold());
+
+    let input = get_user_input();
+
+    input.trim().eq("Y")
+}
+
+/// Prompts the user to select a new status for an Epic, a Story or a Sub-task.
+///
+/// This function displays a prompt to the user to select a new status from the statuses the
+/// workflow allows, either by number or by name. It then reads the user input and returns an
+/// `Option<Status>` representing the selected status.
+///
+/// # Arguments
+///
+/// * `next_statuses` - The statuses the item can move to, see `config::Workflow::next_statuses`.
+///
+/// # Returns
+///
+/// Returns `Some(Status)` representing the selected status if the user input is one of
+/// `next_statuses`, otherwise returns `None`.
 ///
 /// # Examples
 ///
@@ -216,38 +496,548 @@ fn delete_story_prompt() -> bool {
 /// use crate::ui::prompts::update_status_prompt;
 /// use crate::models::Status;
 ///
-/// let new_status = update_status_prompt();
+/// let new_status = update_status_prompt(&[Status::InProgress, Status::Closed]);
 /// ```
-fn update_status_prompt() -> Option<Status> {
+fn update_status_prompt(next_statuses: &[Status]) -> Option<Status> {
     println!("{}", "----------------------------".green());
 
-    println!("New Status ({}, {}, {}, {}): ",
-             "1 - OPEN".green(),
-             "2 - IN-PROGRESS".yellow(),
-             "3 - RESOLVED".blue(),
-             "4 - CLOSED".red());
+    if next_statuses.is_empty() {
+        println!("{} ", "The workflow does not allow changing this status. Press enter to continue".red());
 
-    let status = get_user_input();
+        get_user_input();
 
-    let status = status.trim().parse::<u8>();
+        return None;
+    }
 
-    if let Ok(status) = status {
-        match status {
-            1 => {
-                return Some(Status::Open);
-            }
-            2 => {
-                return Some(Status::InProgress);
-            }
-            3 => {
-                return Some(Status::Resolved);
-            }
-            4 => {
-                return Some(Status::Closed);
-            }
-            _ => return None
-        }
+    let options: Vec<String> = next_statuses.iter()
+        .enumerate()
+        .map(|(i, status)| get_status_option(i + 1, status).to_string())
+        .collect();
+
+    println!("New Status ({}): ", options.join(", "));
+
+    let input = get_user_input();
+
+    let status = match input.trim().parse::<usize>() {
+        Ok(number) => next_statuses.get(number.wrapping_sub(1)).cloned(),
+        Err(_) => input.parse().ok()
+    }?;
+
+    next_statuses.contains(&status).then_some(status)
+}
+
+/// Formats a numbered option of `update_status_prompt`, colored by status.
+fn get_status_option(number: usize, status: &Status) -> ColoredString {
+    let option = format!("{} - {}", number, status);
+
+    match status {
+        Status::Open => option.green(),
+        Status::InProgress => option.yellow(),
+        Status::Resolved => option.blue(),
+        Status::Closed => option.red(),
+        Status::Custom(_) => option.purple()
+    }
+}
+
+/// Prompts the user to edit the name, description, priority, labels and due date of an Epic.
+///
+/// The current values are shown next to each prompt, and leaving an answer empty keeps the
+/// current value.
+///
+/// # Arguments
+///
+/// * `epic` - The Epic being edited.
+///
+/// # Returns
+///
+/// An `EpicPatch` containing the fields the user changed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::edit_epic_prompt;
+/// use crate::models::Epic;
+///
+/// let patch = edit_epic_prompt(&Epic::new("Name".to_string(), "Description".to_string()));
+/// ```
+fn edit_epic_prompt(epic: &Epic) -> EpicPatch {
+    println!("{}", "----------------------------".green());
+
+    println!("{} {}", "Epic Name".yellow(), format!("(leave empty to keep \"{}\"):", epic.name).dimmed());
+
+    let epic_name = get_user_input();
+
+    println!("{} {}", "Epic Description".yellow(), format!("(leave empty to keep \"{}\"):", epic.description).dimmed());
+
+    let epic_desc = get_user_input();
+
+    let priority = priority_prompt(epic.priority);
+
+    let labels = labels_prompt(&epic.labels);
+
+    let due_date = due_date_prompt(epic.due_date);
+
+    EpicPatch {
+        name: non_empty(&epic_name),
+        description: non_empty(&epic_desc),
+        priority,
+        labels,
+        due_date
+    }
+}
+
+/// Prompts the user to edit the name, description, issue type, priority, labels, due date and
+/// story points of a Story, as well as the bug fields if it is a bug.
+///
+/// The current values are shown next to each prompt, and leaving an answer empty keeps the
+/// current value.
+///
+/// # Arguments
+///
+/// * `story` - The Story being edited.
+///
+/// # Returns
+///
+/// A `StoryPatch` containing the fields the user changed.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::edit_story_prompt;
+/// use crate::models::Story;
+///
+/// let patch = edit_story_prompt(&Story::new("Name".to_string(), "Description".to_string()));
+/// ```
+fn edit_story_prompt(story: &Story) -> StoryPatch {
+    println!("{}", "----------------------------".green());
+
+    println!("{} {}", "Story Name".yellow(), format!("(leave empty to keep \"{}\"):", story.name).dimmed());
+
+    let story_name = get_user_input();
+
+    println!("{} {}", "Story Description".yellow(), format!("(leave empty to keep \"{}\"):", story.description).dimmed());
+
+    let story_desc = get_user_input();
+
+    let issue_type = issue_type_prompt(story.issue_type);
+
+    let priority = priority_prompt(story.priority);
+
+    let labels = labels_prompt(&story.labels);
+
+    let due_date = due_date_prompt(story.due_date);
+
+    let story_points = story_points_prompt(story.story_points);
+
+    let bug_fields = match issue_type.unwrap_or(story.issue_type) {
+        IssueType::Bug => bug_fields_prompt(story),
+        _ => StoryPatch::default()
+    };
+
+    StoryPatch {
+        name: non_empty(&story_name),
+        description: non_empty(&story_desc),
+        priority,
+        labels,
+        due_date,
+        story_points,
+        issue_type,
+        ..bug_fields
+    }
+}
+
+/// Prompts the user for the id of the Epic to move a Story to.
+///
+/// # Returns
+///
+/// Returns `Some(u32)` containing the entered Epic id if the input is a valid number,
+/// otherwise returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::move_story_prompt;
+///
+/// let target_epic_id = move_story_prompt();
+/// ```
+fn move_story_prompt() -> Option<u32> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ", "Move to Epic (id):".yellow());
+
+    let epic_id = get_user_input();
+
+    epic_id.trim().parse::<u32>().ok()
+}
+
+/// Prompts the user for the username of the user to assign.
+///
+/// The known users are listed and can be picked by number, but any other username is accepted
+/// and will be added to the users of the database.
+///
+/// # Arguments
+///
+/// * `usernames` - The usernames of the known users.
+///
+/// # Returns
+///
+/// Returns `Some(String)` containing the chosen username, or `None` if the user entered nothing,
+/// meaning that the item is unassigned.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::assign_prompt;
+///
+/// let assignee = assign_prompt(&["alice".to_owned(), "bob".to_owned()]);
+/// ```
+fn assign_prompt(usernames: &[String]) -> Option<String> {
+    let mut usernames = usernames.to_vec();
+    usernames.sort();
+
+    println!("{}", "----------------------------".green());
+
+    for (i, username) in usernames.iter().enumerate() {
+        println!("{} - {}", i + 1, username);
+    }
+
+    println!("{} {}", "Assign to (number or username)".yellow(), "(leave empty to unassign):".dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    match input.parse::<usize>() {
+        Ok(number) if number >= 1 && number <= usernames.len() => Some(usernames[number - 1].clone()),
+        _ => Some(input)
+    }
+}
+
+/// Prompts the user for the text of a comment.
+///
+/// # Returns
+///
+/// Returns `Some(String)` containing the comment, or `None` if the user entered nothing.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::comment_prompt;
+///
+/// let comment = comment_prompt();
+/// ```
+fn comment_prompt() -> Option<String> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} {}", "Comment:".yellow(), "(leave empty to cancel)".dimmed());
+
+    non_empty(&get_user_input())
+}
+
+/// Prompts the user for the type of a new link, either by number or by name, and the id of the
+/// Epic or Story to link to.
+///
+/// # Returns
+///
+/// Returns `Some((LinkType, u32))` if the user entered a valid link type and id, otherwise
+/// returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// use crate::ui::prompts::link_prompt;
+///
+/// if let Some((link_type, target_id)) = link_prompt() {
+///     println!("{} #{}", link_type, target_id);
+/// }
+/// ```
+fn link_prompt() -> Option<(LinkType, u32)> {
+    println!("{}", "----------------------------".green());
+
+    println!("{} ({}, {}, {}, {}, {}):",
+             "Link Type".yellow(),
+             "1 - blocks".red(),
+             "2 - is blocked by".red(),
+             "3 - relates to".blue(),
+             "4 - duplicates".purple(),
+             "5 - is duplicated by".purple());
+
+    let input = get_user_input();
+
+    let link_type = match input.trim().parse::<usize>() {
+        Ok(number) => LinkType::ALL.get(number.wrapping_sub(1)).copied(),
+        Err(_) => input.parse().ok()
+    }?;
+
+    println!("{} ", "Link to Epic or Story (id):".yellow());
+
+    let target_id = get_user_input().trim().parse::<u32>().ok()?;
+
+    Some((link_type, target_id))
+}
+
+/// Prompts the user to choose the link to remove among the given links.
+///
+/// # Arguments
+///
+/// * `links` - The links of the item.
+///
+/// # Returns
+///
+/// Returns `Some(Link)` containing the chosen link, or `None` if the user entered nothing or an
+/// invalid number.
+fn unlink_prompt(links: &[Link]) -> Option<Link> {
+    println!("{}", "----------------------------".green());
+
+    for (i, link) in links.iter().enumerate() {
+        println!("{} - {} #{}", i + 1, link.link_type, link.item_id);
+    }
+
+    println!("{} {}", "Link to remove (number)".yellow(), "(leave empty to cancel):".dimmed());
+
+    let number = non_empty(&get_user_input())?.parse::<usize>().ok()?;
+
+    links.get(number.wrapping_sub(1)).copied()
+}
+
+/// Prompts the user to choose a priority, either by number or by name.
+///
+/// # Arguments
+///
+/// * `current` - The priority kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Priority)` if the user entered a valid priority, otherwise returns `None`.
+fn priority_prompt(current: Priority) -> Option<Priority> {
+    println!("{} ({}, {}, {}, {}, {}) {}",
+             "Priority".yellow(),
+             "1 - HIGHEST".red().bold(),
+             "2 - HIGH".red(),
+             "3 - MEDIUM".yellow(),
+             "4 - LOW".green(),
+             "5 - LOWEST".blue(),
+             format!("(leave empty to keep {}):", current).dimmed());
+
+    let input = get_user_input();
+
+    match input.trim().parse::<usize>() {
+        Ok(number) => Priority::ALL.get(number.wrapping_sub(1)).copied(),
+        Err(_) => input.parse().ok()
+    }
+}
+
+/// Prompts the user to choose the type of a Story, either by number or by name.
+///
+/// # Arguments
+///
+/// * `current` - The issue type kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(IssueType)` if the user entered a valid issue type, otherwise returns `None`.
+fn issue_type_prompt(current: IssueType) -> Option<IssueType> {
+    println!("{} ({}, {}, {}, {}) {}",
+             "Type".yellow(),
+             "1 - STORY".green(),
+             "2 - TASK".blue(),
+             "3 - BUG".red(),
+             "4 - SPIKE".purple(),
+             format!("(leave empty to keep {}):", current).dimmed());
+
+    let input = get_user_input();
+
+    match input.trim().parse::<usize>() {
+        Ok(number) => IssueType::ALL.get(number.wrapping_sub(1)).copied(),
+        Err(_) => input.parse().ok()
+    }
+}
+
+/// Prompts the user for the fields specific to bugs: the steps to reproduce, the severity and
+/// the affected version.
+///
+/// # Arguments
+///
+/// * `story` - The Story whose current bug fields are kept if the user enters nothing.
+///
+/// # Returns
+///
+/// A `StoryPatch` in which only the bug fields may be set.
+fn bug_fields_prompt(story: &Story) -> StoryPatch {
+    let steps_to_reproduce = text_field_prompt("Steps to Reproduce", story.steps_to_reproduce.as_deref());
+
+    let severity = severity_prompt(story.severity);
+
+    let affected_version = text_field_prompt("Affected Version", story.affected_version.as_deref());
+
+    StoryPatch { steps_to_reproduce, severity, affected_version, ..StoryPatch::default() }
+}
+
+/// Prompts the user to choose the severity of a bug, either by number or by name.
+///
+/// # Arguments
+///
+/// * `current` - The severity kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Some(Severity))` if the user entered a valid severity, `Some(None)` if the user
+/// entered `-` to remove the severity, or `None` if the user entered nothing or an invalid severity.
+fn severity_prompt(current: Option<Severity>) -> Option<Option<Severity>> {
+    let current = current.map_or_else(|| "none".to_owned(), |severity| severity.to_string());
+
+    println!("{} ({}, {}, {}, {}, {}) {}",
+             "Severity".yellow(),
+             "1 - BLOCKER".red().bold(),
+             "2 - CRITICAL".red(),
+             "3 - MAJOR".yellow(),
+             "4 - MINOR".green(),
+             "5 - TRIVIAL".blue(),
+             format!("(leave empty to keep {}, - to remove):", current).dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    if input == "-" {
+        return Some(None);
+    }
+
+    match input.parse::<usize>() {
+        Ok(number) => Severity::ALL.get(number.wrapping_sub(1)).copied().map(Some),
+        Err(_) => input.parse().ok().map(Some)
+    }
+}
+
+/// Prompts the user for an optional free text field of a Story.
+///
+/// # Arguments
+///
+/// * `name` - The name of the field, shown as the prompt.
+/// * `current` - The value kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Some(String))` containing the entered text, `Some(None)` if the user entered `-`
+/// to remove the value, or `None` if the user entered nothing.
+fn text_field_prompt(name: &str, current: Option<&str>) -> Option<Option<String>> {
+    println!("{} {}", name.yellow(),
+             format!("(leave empty to keep \"{}\", - to remove):", current.unwrap_or_default()).dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    if input == "-" {
+        return Some(None);
     }
 
-    None
-}
\ No newline at end of file
+    Some(Some(input))
+}
+
+/// Prompts the user for the labels of an Epic or Story, separated by commas or spaces.
+///
+/// # Arguments
+///
+/// * `current` - The labels kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Vec<String>)` containing the entered labels, which is empty if the user entered
+/// `-` to remove all labels, or `None` if the user entered nothing.
+fn labels_prompt(current: &[String]) -> Option<Vec<String>> {
+    println!("{} {}", "Labels (e.g. backend, ui)".yellow(),
+             format!("(leave empty to keep \"{}\", - to remove all):", current.join(", ")).dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    if input == "-" {
+        return Some(vec![]);
+    }
+
+    Some(parse_labels(&input))
+}
+
+/// Prompts the user for the due date of an Epic or Story, in the `YYYY-MM-DD` format.
+///
+/// # Arguments
+///
+/// * `current` - The due date kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Some(NaiveDate))` containing the entered date, `Some(None)` if the user entered
+/// `-` to remove the due date, or `None` if the user entered nothing or an invalid date.
+fn due_date_prompt(current: Option<NaiveDate>) -> Option<Option<NaiveDate>> {
+    let current = current.map_or_else(|| "none".to_owned(), |date| date.to_string());
+
+    println!("{} {}", "Due Date (YYYY-MM-DD)".yellow(),
+             format!("(leave empty to keep {}, - to remove):", current).dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    if input == "-" {
+        return Some(None);
+    }
+
+    NaiveDate::parse_from_str(&input, "%Y-%m-%d").ok().map(Some)
+}
+
+/// Prompts the user for a date in the `YYYY-MM-DD` format.
+///
+/// # Arguments
+///
+/// * `name` - The name of the date, e.g. "Start Date".
+/// * `default` - The date used if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(NaiveDate)` containing the entered or default date, or `None` if the user
+/// entered an invalid date.
+fn date_prompt(name: &str, default: NaiveDate) -> Option<NaiveDate> {
+    println!("{} {}", format!("{} (YYYY-MM-DD)", name).yellow(),
+             format!("(leave empty for {}):", default).dimmed());
+
+    match non_empty(&get_user_input()) {
+        Some(input) => NaiveDate::parse_from_str(&input, "%Y-%m-%d").ok(),
+        None => Some(default)
+    }
+}
+
+/// Prompts the user for the estimate of a Story, in story points.
+///
+/// # Arguments
+///
+/// * `current` - The estimate kept if the user enters nothing, shown next to the prompt.
+///
+/// # Returns
+///
+/// Returns `Some(Some(u32))` containing the entered points, `Some(None)` if the user entered `-`
+/// to remove the estimate, or `None` if the user entered nothing or an invalid number.
+fn story_points_prompt(current: Option<u32>) -> Option<Option<u32>> {
+    let current = current.map_or_else(|| "none".to_owned(), |points| points.to_string());
+
+    println!("{} {}", "Story Points".yellow(),
+             format!("(leave empty to keep {}, - to remove):", current).dimmed());
+
+    let input = non_empty(&get_user_input())?;
+
+    if input == "-" {
+        return Some(None);
+    }
+
+    input.parse::<u32>().ok().map(Some)
+}
+
+/// Splits a list of labels separated by commas and/or whitespace.
+fn parse_labels(input: &str) -> Vec<String> {
+    input.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|label| !label.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Trims the user input, returning `None` if nothing was entered.
+fn non_empty(input: &str) -> Option<String> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return None;
+    }
+
+    Some(input.to_owned())
+}
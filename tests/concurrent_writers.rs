@@ -0,0 +1,58 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Child, Command, Output, Stdio};
+
+/// Runs the CLI against the JSON database at `db_path`, from `dir` so that no configuration
+/// file is picked up.
+fn jira_cli(dir: &Path, db_path: &Path, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_jira-cli"));
+    command.current_dir(dir).arg("--db").arg(format!("json:{}", db_path.display())).args(args);
+    command
+}
+
+/// Returns the output of a successful run of the CLI.
+fn stdout(output: Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Several CLI processes creating stories at the same time must each see the changes of the
+/// others, as every write is made under the lock of the database file.
+#[test]
+fn concurrent_processes_should_not_lose_changes() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let db_path = tmpdir.path().join("db.json");
+    fs::write(&db_path, r#"{ "last_item_id": 0, "epics": {}, "stories": {} }"#).unwrap();
+
+    let epic_id = stdout(jira_cli(tmpdir.path(), &db_path, &["epic", "create", "--name", "Backend"]).output().unwrap());
+    let epic_id = epic_id.trim();
+
+    let writers = 12;
+
+    // Every writer is started before waiting for any of them, so their writes overlap
+    let children: Vec<Child> = (0..writers)
+        .map(|i| {
+            jira_cli(tmpdir.path(), &db_path, &["story", "create", "--epic", epic_id, "--name", &format!("story {}", i)])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap()
+        })
+        .collect();
+
+    for child in children {
+        stdout(child.wait_with_output().unwrap());
+    }
+
+    let stories: serde_json::Value = serde_json::from_str(&stdout(
+        jira_cli(tmpdir.path(), &db_path, &["story", "list", "--epic", epic_id, "--output", "json"]).output().unwrap()
+    )).unwrap();
+
+    let mut names: Vec<&str> = stories.as_array().unwrap().iter().map(|story| story["name"].as_str().unwrap()).collect();
+    names.sort();
+
+    let mut expected: Vec<String> = (0..writers).map(|i| format!("story {}", i)).collect();
+    expected.sort();
+
+    assert_eq!(names, expected);
+}